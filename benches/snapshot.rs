@@ -0,0 +1,84 @@
+// Times the snapshots of the engine, which the undo, the simulations and searching bots take
+// before every action they might take back. Run with 'cargo bench'.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use wartycoon::game::ai::BotStrategy;
+use wartycoon::game::engine::GameEngine;
+use wartycoon::game::rng::GameRng;
+use wartycoon::game::settings::{GameSettings, InputEnd};
+use wartycoon::game::types::{actions::Actions, board::GamePlan, player::Player};
+use wartycoon::game::victory::VictoryCondition;
+
+const ITERATIONS: u32 = 10_000;
+
+// bots playing the engine into the middle of a game, so the snapshots carry a busy battlefield
+fn engine(players: usize, (width, height): (usize, usize)) -> GameEngine {
+    let strategies = [BotStrategy::Economic, BotStrategy::Aggressive];
+    let players = (0..players)
+        .map(|index| Player::new_bot(&format!("BOT {}", index + 1), strategies[index % 2]))
+        .collect();
+    let settings = GameSettings {
+        rounds: 20,
+        conquest_round: 1,
+        fog_of_war: false,
+        privacy_screen: false,
+        bot_time_limit: 60_000,
+        turn_time_limit: None,
+        victory: VictoryCondition::MostFields,
+        on_input_end: InputEnd::default(),
+    };
+    let mut engine = GameEngine::new(
+        players,
+        GamePlan::new(width, height),
+        settings,
+        GameRng::new(42),
+    );
+
+    while engine.round() <= 10 && !engine.is_over() {
+        let turn = (engine.round(), engine.current_player().nick.clone());
+        engine.start_turn();
+
+        while !engine.is_over()
+            && (engine.round(), &engine.current_player().nick) == (turn.0, &turn.1)
+        {
+            let action = engine.bot_action().unwrap_or(Actions::EndTurn);
+            if engine.apply(action).is_err() {
+                let _ = engine.apply(Actions::EndTurn);
+            }
+        }
+    }
+
+    engine
+}
+
+// run a measured step many times, printing the average time of one
+fn bench(name: &str, mut step: impl FnMut()) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        step();
+    }
+    let average: Duration = start.elapsed() / ITERATIONS;
+
+    println!("{:<40}{:>12.2?}", name, average);
+}
+
+fn main() {
+    for (players, size) in [(2, (2, 1)), (4, (5, 5)), (6, (10, 10))] {
+        let mut engine = engine(players, size);
+        let snapshot = engine.snapshot();
+        let label = format!("{} players, {}x{}", players, size.0, size.1);
+
+        println!("\n{}", label);
+        bench("snapshot", || {
+            black_box(engine.snapshot());
+        });
+        bench("restore", || engine.restore(black_box(snapshot.clone())));
+        bench("harvest and undo", || {
+            engine.restore(snapshot.clone());
+            let _ = black_box(engine.apply(Actions::Harvest));
+            let _ = black_box(engine.apply(Actions::Undo));
+        });
+    }
+}
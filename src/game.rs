@@ -1,25 +1,88 @@
-// There is a possibility, that a custom game mode will be implemented.
-// This game mode would allow for multiple fields also printing the map
-// In a nice and formatted way.
+// The custom game mode allows for battlefields with multiple fields,
+// their dimensions are picked at the start of the game.
 
+pub mod ai;
+pub mod audit;
+pub mod campaign;
+pub mod colors;
+mod commands;
+pub mod commentator;
+pub mod config;
+pub mod decision_timer;
+#[cfg(feature = "dev")]
+mod dev_console;
+pub mod economy;
+pub mod engine;
+pub mod events;
+pub mod hall_of_fame;
+pub mod history;
+pub mod inbox;
+mod layout;
 mod player_action;
+pub mod replay;
+pub mod results;
+pub mod revolts;
+pub mod rng;
+pub mod save;
+pub mod server;
+pub mod settings;
 pub mod sleep_intervals;
+pub mod stats;
+mod sync;
+pub mod telemetry;
+pub mod tutorial;
 pub mod types;
 mod user_input;
+pub mod version;
+pub mod victory;
 
-use player_action::{confirm_action, get_player_action};
+use ai::BotStrategy;
+use colors::error_label;
+use decision_timer::DecisionTimer;
+use engine::GameEngine;
+use events::{broadcast, collect_messages, GameEvent, Observer};
+use player_action::{confirm_action, get_player_action, get_trade_answer};
 
 // input handling
-use user_input::get_line;
+use user_input::{
+    expect_input_end, get_line, input_closed, load_script, parse_confirmation, start_turn_timer,
+    stop_turn_timer, turn_expired,
+};
 
 // game notifications
 pub mod notifications;
-use notifications::{notify_players_turn, print_round_action};
+use notifications::{
+    clear_screen, notify_players_turn, print_action_error, print_early_finish_offer,
+    print_kingdom_news, print_next_skirmish_offer, print_privacy_screen, print_rematch_offer,
+    print_round_action, print_scoreboard,
+};
 
 // sleep intervals
-use sleep_intervals::{game_sleep_half_second, game_sleep_second};
+use sleep_intervals::Pacing;
 
-use types::{actions::Actions, board::GamePlan, player::Player};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use revolts::field_limit;
+use save::{save_game, SavedGame, DEFAULT_SAVE_FILE};
+use settings::{GameSettings, InputEnd};
+use victory::VictoryCondition;
+
+use types::{
+    actions::Actions,
+    board::GamePlan,
+    items::ItemType,
+    limits::{
+        ACTION_POINTS_PER_TURN, DEFAULT_ECONOMIC_VICTORY_GOLD, DEFAULT_PLAN_HEIGHT,
+        DEFAULT_PLAN_WIDTH, MAX_PLAN_HEIGHT, MAX_PLAN_WIDTH,
+    },
+    player::Player,
+    plurals::plural_suffix,
+    properties::HasValue,
+    rules::rules,
+    trade::TradeOffer,
+    value_types::{FighterPower, Quantity},
+};
 
 // **********************************************************
 // *                                                        *
@@ -29,43 +92,79 @@ use types::{actions::Actions, board::GamePlan, player::Player};
 // *                                                        *
 // **********************************************************
 
+/// Switch the game into the scripted mode
+///
+/// All the input is read from a script file, so whole games can be replayed
+/// without anyone at the keyboard, best played with the pauses turned off.
+///
+/// Params
+/// ---
+/// - path: path of the script file, one input line per line
+///
+/// Returns
+/// ---
+/// - Ok(()) if the script was loaded
+/// - Err(String) if the script could not be read
+pub fn use_script(path: &str) -> Result<(), String> {
+    load_script(path)
+}
+
+/// Check whether the game has ended with the input, the turn of a human player
+/// then saved the game instead of playing on
+///
+/// Params
+/// ---
+/// - settings: settings of the game
+pub fn saved_at_input_end(settings: &GameSettings) -> bool {
+    input_closed() && settings.on_input_end == InputEnd::Save
+}
+
 /// Create specified number of players
 ///
 /// Params
 /// ---
 /// - num_of_players: specified number of players to create
+/// - pacing: how long the game pauses after every created player
 ///
 /// Returns
 /// ---
 /// - vector of created players
-pub fn create_players(num_of_players: usize) -> Vec<Player> {
+pub fn create_players(num_of_players: usize, pacing: Pacing) -> Vec<Player> {
     // new vector of players
     let mut players: Vec<Player> = Vec::new();
 
     // to create a desired number of players
-    (0..num_of_players).into_iter().for_each(|n| {
+    (0..num_of_players).for_each(|n| {
         // loop here to be able to correct mistakes
         loop {
             // ask player to input the name
-            println!("\nPlease put in a name of PLAYER {}:", &n + 1);
+            println!(
+                "\nPlease put in a name of PLAYER {}:\n(type 'BOT' to let the computer play instead)",
+                &n + 1
+            );
             // get and trim the line
             let line = get_line();
             let line = line.trim();
 
             // try to create the player
-            match create_player(line, &players) {
+            let player = match line {
+                "BOT" | "Bot" | "bot" => create_bot(n + 1, &players),
+                line => create_player(line, &players),
+            };
+
+            match player {
                 // no duplicates, player has been created.
                 Ok(player) => {
+                    println!("\nPlayer {} has been successfully created!", player.nick);
                     players.push(player);
-                    println!("\nPlayer {} has been successfully created!", line);
                     // stop the loop
-                    game_sleep_half_second();
+                    pacing.short_pause();
                     break;
                 }
                 // the player exists, loops again
                 Err(error) => {
-                    println!("\nERROR: {}\n", error);
-                    game_sleep_half_second();
+                    println!("\n{} {}\n", error_label(), error);
+                    pacing.short_pause();
                 }
             }
         }
@@ -75,13 +174,15 @@ pub fn create_players(num_of_players: usize) -> Vec<Player> {
     players
 }
 
-/// Evaluate the game and print the winner.
+/// Evaluate the game and print the winner, followed by the scoreboard.
 ///
 /// Params
 /// ---
 /// - game_plan: reference to the game plan
-pub fn evaluate_game(game_plan: &GamePlan) {
+/// - players: all players of the game
+pub fn evaluate_game(game_plan: &GamePlan, players: &[Player]) {
     game_plan.evaluate();
+    print_scoreboard(players, game_plan);
 }
 
 /// Generate game plan with desired width and height
@@ -90,17 +191,18 @@ pub fn evaluate_game(game_plan: &GamePlan) {
 /// ---
 /// - width: width of the game plan
 /// - height: height of the game plan
+/// - pacing: how long the game pauses before announcing the plan
 ///
 /// Returns
 /// ---
 /// - GamePlan: fresh instance of a game plan
-pub fn generate_game_plan(width: usize, height: usize) -> GamePlan {
+pub fn generate_game_plan(width: usize, height: usize, pacing: Pacing) -> GamePlan {
     // create a new game plan
     let plan = GamePlan::new(width, height);
     // obtain plan's dimensions
     let dimensions = plan.get_dimensions();
 
-    game_sleep_second();
+    pacing.pause();
     // print plan creation
     println!(
         "\nGame plan with dimensions {} has been created.\n",
@@ -111,6 +213,46 @@ pub fn generate_game_plan(width: usize, height: usize) -> GamePlan {
     plan
 }
 
+/// Get the dimensions of the battlefield from player
+///
+/// Returns
+/// ---
+/// - (width, height): dimensions of the battlefield
+pub fn get_plan_dimensions() -> (usize, usize) {
+    // input loop in case of a wrong input
+    loop {
+        println!(
+            "\nPlease specify the battlefield dimensions as 'width height':\n(press enter to play on the default {} x {} battlefield, at most {} x {})",
+            DEFAULT_PLAN_WIDTH, DEFAULT_PLAN_HEIGHT, MAX_PLAN_WIDTH, MAX_PLAN_HEIGHT
+        );
+        // get the line & trim it
+        let line = get_line();
+        let line = line.trim();
+
+        if line.is_empty() {
+            return (DEFAULT_PLAN_WIDTH, DEFAULT_PLAN_HEIGHT);
+        }
+
+        let dimensions: Vec<Option<usize>> = line
+            .split_whitespace()
+            .map(|dimension| dimension.parse::<usize>().ok())
+            .collect();
+
+        match dimensions.as_slice() {
+            [Some(width), Some(height)]
+                if (1..=MAX_PLAN_WIDTH).contains(width)
+                    && (1..=MAX_PLAN_HEIGHT).contains(height) =>
+            {
+                return (*width, *height)
+            }
+            _ => println!(
+                "\nIncorrect dimensions: {}. Please put two whole numbers, width from 1 to {} and height from 1 to {}!\n",
+                line, MAX_PLAN_WIDTH, MAX_PLAN_HEIGHT
+            ),
+        }
+    }
+}
+
 /// Get the number of rounds from player
 ///
 /// Returns
@@ -131,69 +273,567 @@ pub fn get_number_of_rounds() -> usize {
                 println!("\nNumber of game rounds will be: {}\n", &result);
                 return result;
             }
-            Ok(result) => println!(  // correct format but fewer rounds than needed
+            Ok(result) => println!(
+                // correct format but fewer rounds than needed
                 "\nCannot play a game with {} rounds! Match too short\n",
                 &result
             ),
             Err(..) => {
-                println!(  // incorrect format (either a negative number or could not parse the input)
-                "\nIncorrect format: {}. Please put a positive whole number above 9!\n",
-                line
-            )
+                println!(
+                    // incorrect format (either a negative number or could not parse the input)
+                    "\nIncorrect format: {}. Please put a positive whole number above 9!\n",
+                    line
+                )
             }
         };
     }
 }
 
-/// Play a round for a player
+/// Get the round in which conquering unlocks from player
+///
+/// Rounds before it form the exploration phase, when only economy actions are allowed.
+///
+/// Params
+/// ---
+/// - rounds: number of rounds to be played
+///
+/// Returns
+/// ---
+/// - usize: first round in which fields can be conquered
+pub fn get_conquest_round(rounds: usize) -> usize {
+    // input loop in case of a wrong input
+    loop {
+        println!(
+            "\nIn which round should conquering unlock?\n(press enter to allow it right away, or put a whole number from 1 to {})",
+            rounds
+        );
+        // get the line & trim it
+        let line = get_line();
+        let line = line.trim();
+
+        match line {
+            "" => return 1,
+            line => match line.parse::<usize>() {
+                Ok(result) if (1..=rounds).contains(&result) => {
+                    println!("\nFields can be conquered from round {}.\n", result);
+                    return result;
+                }
+                _ => println!(
+                    "\nIncorrect round: {}. Please put a whole number from 1 to {}!\n",
+                    line, rounds
+                ),
+            },
+        }
+    }
+}
+
+/// Ask player whether to play in the fog of war
+///
+/// Returns
+/// ---
+/// - true: if opponents' units should only be visible on fields where the player has units
+/// - false: if the whole battlefield should be visible
+pub fn get_fog_of_war() -> bool {
+    // input loop in case of a wrong input
+    loop {
+        println!("\nDo you wish to play in the fog of war?\n(type 'yes', 'y' or '1' to hide opponents' units on fields without your units, or press enter to see everything)");
+        // get the line & trim it
+        let line = get_line();
+        let line = line.trim();
+
+        match line {
+            "" => return false,
+            line => match parse_confirmation(line) {
+                Some(fog_of_war) => return fog_of_war,
+                None => println!("\nPlease type 'yes', 'y' or '1' for the fog of war, 'no', 'n' or '2' without it.\n"),
+            },
+        }
+    }
+}
+
+/// Ask player how the game should be won
+///
+/// Returns
+/// ---
+/// - VictoryCondition: the chosen condition, the most fields by default
+pub fn get_victory_condition() -> VictoryCondition {
+    // input loop in case of a wrong input
+    loop {
+        println!("\nHow should the game be won?\n(possible options: '1' or 'FIELDS' for the most fields after the last round,\n'2' or 'DOMINATION' for leading every field at the end of a round,\n'3' or 'ECONOMIC' for gathering a treasure of gold,\n'4' or 'SURVIVAL' for being the last player with an army;\npress enter to play for the most fields)");
+        // get the line & trim it
+        let line = get_line();
+        let line = line.trim();
+
+        match line {
+            "" | "1" | "FIELDS" | "Fields" | "fields" => return VictoryCondition::MostFields,
+            "2" | "DOMINATION" | "Domination" | "domination" => {
+                return VictoryCondition::Domination
+            }
+            "3" | "ECONOMIC" | "Economic" | "economic" => {
+                return VictoryCondition::Economic(get_victory_gold())
+            }
+            "4" | "SURVIVAL" | "Survival" | "survival" => return VictoryCondition::LastStanding,
+            _ => println!("\nUnknown victory condition, please pick one of the listed options.\n"),
+        }
+    }
+}
+
+/// Get the gold needed for an economic victory from player
+///
+/// Returns
+/// ---
+/// - Quantity: gold a player has to hold at the end of a round to win
+fn get_victory_gold() -> Quantity {
+    // input loop in case of a wrong input
+    loop {
+        println!(
+            "\nHow much gold wins the game?\n(press enter for {} gold)",
+            DEFAULT_ECONOMIC_VICTORY_GOLD
+        );
+        // get the line & trim it
+        let line = get_line();
+        let line = line.trim();
+
+        match line {
+            "" => return DEFAULT_ECONOMIC_VICTORY_GOLD,
+            line => match line.parse::<Quantity>() {
+                Ok(gold) if gold > 0 => return gold,
+                _ => println!(
+                    "\nIncorrect format: {}. Please put a positive whole number!\n",
+                    line
+                ),
+            },
+        }
+    }
+}
+
+/// Ask players sharing the keyboard whether to hide their turns from each other
+///
+/// Params
+/// ---
+/// - players: players of the game, only asked if at least two of them are human
+///
+/// Returns
+/// ---
+/// - true: if the screen should be cleared before every human player's turn
+/// - false: if the turns should stay visible
+pub fn get_privacy_screen(players: &[Player]) -> bool {
+    if players
+        .iter()
+        .filter(|player| player.bot().is_none())
+        .count()
+        < 2
+    {
+        return false;
+    }
+
+    // input loop in case of a wrong input
+    loop {
+        println!("\nDo you wish to pass the keyboard between turns?\n(type 'yes', 'y' or '1' to clear the screen before every turn, or press enter to keep the turns visible)");
+        // get the line & trim it
+        let line = get_line();
+        let line = line.trim();
+
+        match line {
+            "" => return false,
+            line => match parse_confirmation(line) {
+                Some(privacy_screen) => return privacy_screen,
+                None => println!("\nPlease type 'yes', 'y' or '1' to pass the keyboard, 'no', 'n' or '2' to keep the turns visible.\n"),
+            },
+        }
+    }
+}
+
+/// Offer players to end the game early, if its outcome is already decided
+///
+/// Params
+/// ---
+/// - players: all players of the game
+/// - game_plan: reference to the game plan
+/// - rounds_left: how many rounds remain to be played
+///
+/// Returns
+/// ---
+/// - None: if the outcome can still change, no offer was made
+/// - Some(true): if players agreed to end the game right away
+/// - Some(false): if players chose to play the remaining rounds
+pub fn offer_early_finish(
+    players: &[Player],
+    game_plan: &GamePlan,
+    rounds_left: usize,
+) -> Option<bool> {
+    let (leader, locked_fields) = decided_outcome(players, game_plan, rounds_left)?;
+
+    print_early_finish_offer(
+        &leader,
+        locked_fields,
+        game_plan.number_of_fields(),
+        rounds_left,
+    );
+
+    // input loop in case of a wrong input
+    loop {
+        // get the line & trim it
+        let line = get_line();
+        let line = line.trim();
+
+        match line {
+            "" => return Some(false),
+            line => match parse_confirmation(line) {
+                Some(finish) => return Some(finish),
+                None => println!("\nPlease type 'yes', 'y' or '1' to end the game, 'no', 'n' or '2' to continue.\n"),
+            },
+        }
+    }
+}
+
+/// Offer players a rematch at the end of the game
+///
+/// Params
+/// ---
+/// - players: players of the finished game
+///
+/// Returns
+/// ---
+/// - Some(players): fresh players with the same nicks, if players want to play again
+/// - None: if the players are done playing
+pub fn offer_rematch(players: &[Player]) -> Option<Vec<Player>> {
+    let nicks: Vec<String> = players.iter().map(|player| player.nick.clone()).collect();
+    print_rematch_offer(&nicks);
+
+    // input loop in case of a wrong input
+    loop {
+        // get the line & trim it
+        let line = get_line();
+        let line = line.trim();
+
+        match line {
+            "" => return None,
+            line => match parse_confirmation(line) {
+                Some(true) => return Some(players.iter().map(Player::rematch).collect()),
+                Some(false) => return None,
+                None => println!(
+                    "\nPlease type 'yes', 'y' or '1' to play again, 'no', 'n' or '2' to quit.\n"
+                ),
+            },
+        }
+    }
+}
+
+/// Get the nick of the player of a campaign
+///
+/// Returns
+/// ---
+/// - nick of the player, bots' nicks are taken
+pub fn get_campaign_nick() -> String {
+    // input loop in case of a wrong input
+    loop {
+        println!("\nPlease put in your name:");
+        // get the line & trim it
+        let line = get_line();
+        let line = line.trim();
+
+        match line {
+            "" => println!("\nThe name cannot be empty!\n"),
+            line if line.to_uppercase().starts_with("BOT") => {
+                println!("\nNames starting with 'BOT' belong to the opponents of the campaign!\n")
+            }
+            line => return line.into(),
+        }
+    }
+}
+
+/// Offer the player of a campaign the next skirmish
+///
+/// Params
+/// ---
+/// - stage: the next stage of the campaign
+///
+/// Returns
+/// ---
+/// - true: if the player marches on
+/// - false: if the player ends the campaign
+pub fn offer_next_skirmish(stage: usize) -> bool {
+    print_next_skirmish_offer(stage);
+
+    // input loop in case of a wrong input
+    loop {
+        // get the line & trim it
+        let line = get_line();
+        let line = line.trim();
+
+        match line {
+            "" => return false,
+            line => match parse_confirmation(line) {
+                Some(next) => return next,
+                None => println!(
+                    "\nPlease type 'yes', 'y' or '1' to march on, 'no', 'n' or '2' to end the campaign.\n"
+                ),
+            },
+        }
+    }
+}
+
+/// Play the turn of the player whose turn it is
+/// The player performs actions until their action points run out or they end the turn,
+/// the engine checks and performs the actions and passes the turn to the next player.
 /// Returns an information if the player chose to finish the game after the end of the round
 ///
 /// Params
 /// ---
-/// - player: mutable reference to player who currently plays
-///           their turn, to be able to modify their internal state
-/// - game_plan: mutable reference to be able to affect a game plan (conquer a field)
-/// - current_round: number for displaying which round it is
+/// - engine: the running game, it is the turn of the player
+/// - observers: registered observers, notified about the performed actions
+/// - decision_timer: records how long the player takes to decide
+/// - pacing: how long the game pauses after the notifications
 ///
 /// Returns
 /// ---
 /// - false: if player chose to quit the game
 /// - true: otherwise (after player correctly played their turn)
-pub fn play_round(player: &mut Player, game_plan: &mut GamePlan, current_round: usize) -> bool {
+pub fn play_round(
+    engine: &mut GameEngine,
+    observers: &mut [Box<dyn Observer>],
+    decision_timer: &mut DecisionTimer,
+    pacing: Pacing,
+) -> bool {
+    // from the first turn on, the end of the input does not end the program
+    expect_input_end();
+
+    let settings = engine.settings().clone();
+    let current_round = engine.round();
+    let nick = engine.current_player().nick.clone();
+    let turn = turn_of(engine);
+
+    // the game can be saved at the start of the turn, before the kingdom grows
+    let save_point = engine.save_point();
+
+    // the previous player's turn is hidden until the next player takes the keyboard
+    if settings.privacy_screen
+        && engine.current_player().bot().is_none()
+        && !turn_skipped(current_round)
+    {
+        print_privacy_screen(&nick);
+        get_line();
+        clear_screen();
+    }
+
     // notify player it's their turn
-    notify_players_turn(player, current_round);
+    notify_players_turn(engine.current_player(), current_round);
+
+    // messages about the player's fields arrived since their last turn
+    let mut news: Vec<String> = collect_messages(observers, &nick)
+        .iter()
+        .map(|message| format!("║{:^78}║", message))
+        .collect();
+
+    // fields scouted before fade back into the fog, bases produce new peasants,
+    // farms yield crops and the army is paid from the gold
+    if let Some(kingdom_news) = engine.start_turn() {
+        news.insert(0, kingdom_news);
+    }
+
+    if !news.is_empty() {
+        print_kingdom_news(
+            &news.join("\n"),
+            engine.current_player(),
+            current_round,
+            pacing,
+        );
+    }
+
+    if turn_skipped(current_round) {
+        let _ = engine.apply(Actions::EndTurn);
+        return true;
+    }
+
+    // nobody is left at the keyboard of a human player whose input has ended
+    if engine.current_player().bot().is_none()
+        && input_closed()
+        && !hand_over_at_input_end(
+            engine.current_player_and_plan_mut().0,
+            &settings,
+            &save_point,
+        )
+    {
+        return false;
+    }
+
+    // offers of the other players are answered before the first action
+    answer_offers(engine, observers, pacing);
 
-    // print the user's status
-    player.status(current_round, game_plan, "at the start of");
+    // computer controlled players choose their action on their own
+    if engine.current_player().bot().is_some() {
+        play_bot_turn(engine, observers, pacing);
+        return true;
+    }
+
+    // time spent choosing actions, excluding the printed notifications
+    let mut thinking_time = Duration::ZERO;
+
+    // the last performed action, so that the observers learn which one has been taken back
+    let mut last_action: Option<Actions> = None;
+
+    // players who stall for too long lose the rest of their turn
+    if let Some(limit) = settings.turn_time_limit {
+        println!(
+            "\n{}, you have {} second{} for your turn.\n",
+            nick,
+            limit,
+            plural_suffix(limit as Quantity, "s")
+        );
+    }
+    start_turn_timer(settings.turn_time_limit.map(Duration::from_secs));
 
     // loop for action confirmation and checking whether the operation was successful
     loop {
-        let action = get_player_action(player, game_plan, current_round);
+        let decision_start = Instant::now();
+        let action_points = engine.action_points();
+        let action = get_player_action(engine);
+
+        // the input has ended during the turn, a bot taking over plays it unless it has begun
+        if input_closed() {
+            stop_turn_timer();
+            if !hand_over_at_input_end(
+                engine.current_player_and_plan_mut().0,
+                &settings,
+                &save_point,
+            ) {
+                return false;
+            }
+            match action_points == ACTION_POINTS_PER_TURN {
+                true => play_bot_turn(engine, observers, pacing),
+                false => {
+                    let _ = engine.apply(Actions::EndTurn);
+                }
+            }
+            return true;
+        }
+
+        // a player out of time passes the turn, or ends it if some points were already spent
+        let (action, confirmed) = match turn_expired() {
+            true => {
+                println!("\nTime is up, {}!\n", nick);
+                match action_points == ACTION_POINTS_PER_TURN {
+                    true => (Actions::Pass, true),
+                    false => (Actions::EndTurn, true),
+                }
+            }
+            false => {
+                let confirmed = confirm_action(&action);
+                (action, confirmed)
+            }
+        };
+        thinking_time += decision_start.elapsed();
 
         // if the action was not confirmed, continue with choosing an action
         // == starting the loop again
-        if !confirm_action(&action) {
+        if !confirmed {
             continue;
         }
 
-        // check if the user wants to end the game
+        // check if the user wants to end the game, the rest of the round is played
         if action == Actions::Quit {
+            stop_turn_timer();
+            let _ = engine.apply(Actions::EndTurn);
             return false;
         }
 
-        match player.perform_action(action, game_plan) {
+        // the remaining action points are given up
+        if action == Actions::EndTurn {
+            let _ = engine.apply(Actions::EndTurn);
+            end_private_turn(&settings, pacing);
+            return true;
+        }
+
+        // the last action is taken back, along with the action points it took
+        if action == Actions::Undo {
+            match (engine.apply(Actions::Undo), last_action.take()) {
+                (Ok(_), Some(undone)) => {
+                    println!("\n{} has been taken back.\n", undone);
+                    broadcast(
+                        observers,
+                        GameEvent::ActionUndone {
+                            player: engine.current_player(),
+                            action: &undone,
+                            game_plan: engine.game_plan(),
+                        },
+                    );
+                }
+                (Ok(notification), None) => println!("\n{}\n", notification),
+                (Err(error), _) => println!("\n{} {}\n", error_label(), error),
+            }
+            pacing.short_pause();
+            continue;
+        }
+
+        // saving does not take up the turn
+        if let Actions::Save(path) = &action {
+            // the save point does not contain the actions of this turn
+            if action_points < ACTION_POINTS_PER_TURN {
+                println!(
+                    "\n{} The game can only be saved before your first action of the turn.\n",
+                    error_label()
+                );
+                pacing.short_pause();
+                continue;
+            }
+
+            match save_game(&save_point, path) {
+                Ok(notification) => println!("\n{}\n", notification),
+                Err(error) => println!("\n{} {}\n", error_label(), error),
+            }
+            pacing.short_pause();
+            continue;
+        }
+
+        match engine.apply(action.clone()) {
             // action was a success
             Ok(notification) => {
+                decision_timer.record(&nick, thinking_time);
+                thinking_time = Duration::ZERO;
+                last_action = Some(action.clone());
+
+                // the turn may have passed already, the status is the one of the player
+                let player = player_of(engine, &nick);
+
                 // print action confirmation & user status afterwards
-                print_round_action(&notification, player, game_plan, current_round, true);
-                game_sleep_half_second();
-                return true;
+                print_round_action(
+                    &notification,
+                    player,
+                    engine.game_plan(),
+                    current_round,
+                    true,
+                    pacing,
+                );
+
+                // let the observers react to the action
+                broadcast(
+                    observers,
+                    GameEvent::ActionPerformed {
+                        player,
+                        action: &action,
+                        game_plan: engine.game_plan(),
+                    },
+                );
+
+                pacing.short_pause();
+
+                // the action points have run out
+                if turn_of(engine) != turn {
+                    end_private_turn(&settings, pacing);
+                    return true;
+                }
             }
             // action was a failure
             Err(notification) => {
                 // don't print user status after action rejection
-                print_round_action(&notification, player, game_plan, current_round, false);
-                game_sleep_half_second();
+                print_action_error(
+                    &notification,
+                    engine.current_player(),
+                    current_round,
+                    pacing,
+                );
+                pacing.short_pause();
             }
         };
     }
@@ -207,6 +847,79 @@ pub fn play_round(player: &mut Player, game_plan: &mut GamePlan, current_round:
 // *                                                        *
 // **********************************************************
 
+/// Let the player answer the trade offers made to them since their last turn
+///
+/// Accepted offers complete the trade on both sides right away,
+/// offers the player cannot pay for are declined.
+///
+/// Params
+/// ---
+/// - engine: the running game, it is the turn of the player
+/// - observers: registered observers, notified about the answers
+/// - pacing: how long the game pauses after the notifications
+fn answer_offers(engine: &mut GameEngine, observers: &mut [Box<dyn Observer>], pacing: Pacing) {
+    let round = engine.round();
+    let offers: Vec<TradeOffer> = engine
+        .players()
+        .iter()
+        .flat_map(|player| player.offers())
+        .filter(|offer| offer.to == engine.current_player().nick)
+        .cloned()
+        .collect();
+
+    for offer in offers {
+        // bots only take offers which do not make them poorer
+        let accept = match engine.current_player().bot() {
+            Some(_) => offer.favorable_for(engine.current_player()),
+            None => get_trade_answer(&offer),
+        };
+
+        let answer = |accept| Actions::AnswerTrade {
+            from: offer.from.clone(),
+            accept,
+        };
+        let accepted = accept
+            && match engine.apply(answer(true)) {
+                Ok(notification) => {
+                    print_round_action(
+                        &notification,
+                        engine.current_player(),
+                        engine.game_plan(),
+                        round,
+                        false,
+                        pacing,
+                    );
+                    true
+                }
+                Err(notification) => {
+                    print_action_error(&notification, engine.current_player(), round, pacing);
+                    false
+                }
+            };
+
+        // the offering player gets the resources back
+        if !accepted {
+            let _ = engine.apply(answer(false));
+        }
+        if !accept {
+            println!(
+                "\n{} declined the trade offer of {}.\n",
+                engine.current_player().nick,
+                offer.from
+            );
+        }
+
+        broadcast(
+            observers,
+            GameEvent::ActionPerformed {
+                player: engine.current_player(),
+                action: &answer(accepted),
+                game_plan: engine.game_plan(),
+            },
+        );
+    }
+}
+
 /// Create a player with specified nick
 ///
 /// Params
@@ -230,3 +943,275 @@ fn create_player(player_nick: &str, players: &[Player]) -> Result<Player, String
     // player could be created!
     Ok(Player::new(player_nick))
 }
+
+/// Create a computer controlled player with a strategy picked by the user
+///
+/// Params
+/// ---
+/// - player_number: number of the player slot (used for the bot's nick)
+/// - players: vector of existing players of this game
+///
+/// Returns
+/// ---
+/// - Ok(player) if the bot could be created
+/// - Err(string) containing details why the bot could not be created
+fn create_bot(player_number: usize, players: &[Player]) -> Result<Player, String> {
+    let strategy = loop {
+        println!(
+            "\nWhich strategy should the bot follow?\n(possible options: '1' or '{}', '2' or '{}')",
+            BotStrategy::Economic,
+            BotStrategy::Aggressive
+        );
+
+        let line = get_line();
+        let line = line.trim();
+
+        match line {
+            "1" | "ECONOMIC" | "Economic" | "economic" => break BotStrategy::Economic,
+            "2" | "AGGRESSIVE" | "Aggressive" | "aggressive" => break BotStrategy::Aggressive,
+            _ => println!("\nUnknown strategy, please pick one of the listed options.\n"),
+        }
+    };
+
+    let nick = format!("BOT {}", player_number);
+    create_player(&nick, players)?;
+
+    Ok(Player::new_bot(&nick, strategy))
+}
+
+/// Let a bot choose and perform its actions for the round, until its turn passes
+///
+/// Params
+/// ---
+/// - engine: the running game, it is the turn of the bot
+/// - observers: registered observers, notified about the performed action
+/// - pacing: how long the game pauses after every action
+fn play_bot_turn(engine: &mut GameEngine, observers: &mut [Box<dyn Observer>], pacing: Pacing) {
+    let round = engine.round();
+    let nick = engine.current_player().nick.clone();
+    let turn = turn_of(engine);
+
+    while turn_of(engine) == turn {
+        let Some(action) = engine.bot_action() else {
+            break;
+        };
+
+        // bots harvest when their choice fails, and end the turn when not even harvesting works
+        let performed = [action, Actions::Harvest, Actions::EndTurn]
+            .into_iter()
+            .find_map(|action| {
+                engine
+                    .apply(action.clone())
+                    .ok()
+                    .map(|notification| (action, notification))
+            });
+        let Some((action, notification)) = performed else {
+            break;
+        };
+        if action == Actions::EndTurn {
+            break;
+        }
+
+        let player = player_of(engine, &nick);
+        println!("\n{} decides to: {}\n", nick, action);
+        print_round_action(
+            &notification,
+            player,
+            engine.game_plan(),
+            round,
+            true,
+            pacing,
+        );
+
+        broadcast(
+            observers,
+            GameEvent::ActionPerformed {
+                player,
+                action: &action,
+                game_plan: engine.game_plan(),
+            },
+        );
+
+        pacing.short_pause();
+    }
+}
+
+/// Tell the turns of the game apart, the turn passes once the round or the player changes
+///
+/// Params
+/// ---
+/// - engine: the running game
+///
+/// Returns
+/// ---
+/// - (round, nick) of the current turn, the round is past the last one once the game is over
+fn turn_of(engine: &GameEngine) -> (usize, String) {
+    match engine.is_over() {
+        true => (usize::MAX, String::new()),
+        false => (engine.round(), engine.current_player().nick.clone()),
+    }
+}
+
+/// Find a player of the game by their nick, the turn may have passed to somebody else already
+///
+/// Params
+/// ---
+/// - engine: the running game
+/// - nick: nick of the player
+fn player_of<'a>(engine: &'a GameEngine, nick: &str) -> &'a Player {
+    engine
+        .players()
+        .iter()
+        .find(|player| player.nick == nick)
+        .unwrap_or(engine.current_player())
+}
+
+/// Check whether turns of a round are skipped with the developer console
+///
+/// Params
+/// ---
+/// - round: which round it is
+#[cfg(feature = "dev")]
+fn turn_skipped(round: usize) -> bool {
+    dev_console::skipping(round)
+}
+
+/// Turns can only be skipped with the developer console
+#[cfg(not(feature = "dev"))]
+fn turn_skipped(_round: usize) -> bool {
+    false
+}
+
+/// Stop the timer of a finished turn of a human player
+/// and hide the turn from the next player, if the keyboard is passed
+///
+/// Params
+/// ---
+/// - settings: settings of the game
+/// - pacing: how long the game pauses before hiding the turn
+fn end_private_turn(settings: &GameSettings, pacing: Pacing) {
+    stop_turn_timer();
+
+    if settings.privacy_screen {
+        pacing.pause();
+        clear_screen();
+    }
+}
+
+/// Deal with a human player whose input has ended, as the settings say
+///
+/// Params
+/// ---
+/// - player: the player whose input has ended
+/// - settings: settings of the game
+/// - save_point: state of the game at the start of the player's turn
+///
+/// Returns
+/// ---
+/// - true: if a bot has taken over the player
+/// - false: if the game has been saved and ends
+fn hand_over_at_input_end(
+    player: &mut Player,
+    settings: &GameSettings,
+    save_point: &SavedGame,
+) -> bool {
+    match settings.on_input_end {
+        InputEnd::Bot => {
+            player.hand_to_bot(BotStrategy::Economic);
+            println!(
+                "\nThe input has ended, {} is played by an {} BOT from now on.\n",
+                player.nick,
+                BotStrategy::Economic
+            );
+            true
+        }
+        InputEnd::Save => {
+            match save_game(save_point, DEFAULT_SAVE_FILE) {
+                Ok(notification) => println!("\nThe input has ended. {}\n", notification),
+                Err(error) => println!("\n{} {}\n", error_label(), error),
+            }
+            false
+        }
+    }
+}
+
+/// Find a player whose victory can no longer be prevented
+///
+/// Params
+/// ---
+/// - players: all players of the game
+/// - game_plan: reference to the game plan
+/// - rounds_left: how many rounds remain to be played
+///
+/// Returns
+/// ---
+/// - Some((nick, locked_fields)): the projected winner and the number of fields
+///   no opponent can contest anymore
+/// - None: if the game outcome can still change
+fn decided_outcome(
+    players: &[Player],
+    game_plan: &GamePlan,
+    rounds_left: usize,
+) -> Option<(String, usize)> {
+    // trades and the market pass gold between the players, so any of them could
+    // end up with all the gold of the game, including the reserve of the market
+    let gold_in_game = game_plan.economy().reserves().1
+        + players
+            .iter()
+            .map(|player| player.max_gold(rounds_left))
+            .sum::<Quantity>();
+
+    // the most power each player can still bring to a field
+    let reinforcements: HashMap<String, FighterPower> = players
+        .iter()
+        .map(|player| {
+            (
+                player.nick.clone(),
+                player.max_reinforcement_power(game_plan, gold_in_game, rounds_left),
+            )
+        })
+        .collect();
+
+    // a healing potion can be bought by anybody who can get its gold,
+    // the wood is counted as available, so that no field is taken for locked too early
+    let potion_gold = ItemType::HealingPotion.value().1;
+    let healed: HashSet<String> = players
+        .iter()
+        .filter(|player| {
+            player.items(ItemType::HealingPotion) > 0
+                || (rounds_left > 0 && potion_gold <= gold_in_game)
+        })
+        .map(|player| player.nick.clone())
+        .collect();
+
+    let total_fields = game_plan.number_of_fields();
+    let mut locked = game_plan.locked_fields(&reinforcements, &healed);
+
+    // fields above the revolt limit can still turn neutral, so they are not locked;
+    // a player is safe only if they cannot lead more fields than their bases keep,
+    // even after winning every field not locked by the others
+    if rules().revolt_chance > 0 {
+        loop {
+            let exposed = players.iter().find(|player| {
+                let locked_by_others: usize = locked
+                    .iter()
+                    .filter(|(nick, _)| **nick != player.nick)
+                    .map(|(_, fields)| fields)
+                    .sum();
+
+                locked.contains_key(&player.nick)
+                    && total_fields - locked_by_others > field_limit(player)
+            });
+
+            match exposed {
+                Some(player) => locked.remove(&player.nick),
+                None => break,
+            };
+        }
+    }
+
+    // the leader has to hold more locked fields than all the others could ever win
+    locked
+        .into_iter()
+        .find(|(_, locked)| *locked > total_fields - *locked)
+}
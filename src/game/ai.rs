@@ -0,0 +1,226 @@
+// Computer controlled players.
+// Every bot follows a strategy which picks its action for the round.
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::Display;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use super::types::{
+    actions::Actions,
+    board::GamePlan,
+    buildings::BuildingType,
+    player::Player,
+    properties::HasPower,
+    troops::UnitType,
+    value_types::{FighterPower, Quantity},
+};
+
+// economic bots do not split their armies into tiny stacks
+const ECONOMIC_MINIMAL_TRAINING: Quantity = 10;
+// economic bots only attack every few rounds
+const ECONOMIC_ATTACK_INTERVAL: usize = 3;
+
+/// Strategy deciding on the actions of a bot
+pub trait Strategy {
+    /// Choose an action for the current round
+    ///
+    /// Params
+    /// ---
+    /// - player: the bot's player
+    /// - plan: current state of the battlefield
+    /// - round: which round is currently
+    ///
+    /// Returns
+    /// ---
+    /// - action the bot wants to perform
+    fn choose_action(&self, player: &Player, plan: &GamePlan, round: usize) -> Actions;
+}
+
+/// Strategies a bot can be created with
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum BotStrategy {
+    Economic,
+    Aggressive,
+}
+
+impl BotStrategy {
+    /// Return the strategy implementation
+    pub fn strategy(&self) -> Box<dyn Strategy> {
+        match self {
+            BotStrategy::Economic => Box::new(EconomicStrategy),
+            BotStrategy::Aggressive => Box::new(AggressiveStrategy),
+        }
+    }
+
+    /// Choose an action of the bot, giving up when the strategy takes too long
+    ///
+    /// The strategy runs in its own thread, so a strategy stuck in a loop
+    /// cannot hang the game. Such a thread is left behind to finish on its own.
+    ///
+    /// Params
+    /// ---
+    /// - player: the bot's player
+    /// - plan: battlefield as the bot sees it
+    /// - round: which round is currently
+    /// - time_limit: how long the strategy may think
+    ///
+    /// Returns
+    /// ---
+    /// - Some(action): action chosen in time
+    /// - None: if the strategy ran out of time or crashed
+    pub fn choose_action_within(
+        &self,
+        player: &Player,
+        plan: &GamePlan,
+        round: usize,
+        time_limit: Duration,
+    ) -> Option<Actions> {
+        let strategy = *self;
+        let player = player.clone();
+        let plan = plan.clone();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let action = strategy.strategy().choose_action(&player, &plan, round);
+            // the game does not wait for late answers anymore
+            let _ = sender.send(action);
+        });
+
+        receiver.recv_timeout(time_limit).ok()
+    }
+}
+
+/// Used for displaying the strategy
+impl Display for BotStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BotStrategy::Economic => write!(f, "ECONOMIC"),
+            BotStrategy::Aggressive => write!(f, "AGGRESSIVE"),
+        }
+    }
+}
+
+/// Grows the economy first and attacks only occasionally
+pub struct EconomicStrategy;
+
+/// Builds a single base and attacks whenever it has units
+pub struct AggressiveStrategy;
+
+impl Strategy for EconomicStrategy {
+    fn choose_action(&self, player: &Player, plan: &GamePlan, round: usize) -> Actions {
+        // more bases mean more capacity and more peasants
+        if player.build_max_buildings(BuildingType::Base) > 0
+            && player.current_fighters_capacity() < ECONOMIC_MINIMAL_TRAINING
+        {
+            return Actions::Build(BuildingType::Base, 1);
+        }
+
+        if round.is_multiple_of(ECONOMIC_ATTACK_INTERVAL) {
+            if let Some(action) = conquer_action(player, plan) {
+                return action;
+            }
+        }
+
+        // upgrading peasants is the cheapest way to get units
+        let recruits = player.recruit_max_units(UnitType::Archer);
+        if recruits > 0 {
+            return Actions::Recruit(UnitType::Archer, recruits);
+        }
+
+        let trainable = max_trainable(player, UnitType::Archer);
+        if trainable >= ECONOMIC_MINIMAL_TRAINING {
+            return Actions::Train(UnitType::Archer, trainable);
+        }
+
+        Actions::Harvest
+    }
+}
+
+impl Strategy for AggressiveStrategy {
+    fn choose_action(&self, player: &Player, plan: &GamePlan, _round: usize) -> Actions {
+        // all units are sent to the battlefield right away
+        if let Some(action) = conquer_action(player, plan) {
+            return action;
+        }
+
+        // a base is only needed to be able to train
+        if player.current_fighters_capacity() == 0
+            && player.build_max_buildings(BuildingType::Base) > 0
+        {
+            return Actions::Build(BuildingType::Base, 1);
+        }
+
+        let trainable = max_trainable(player, UnitType::Archer);
+        if trainable > 0 {
+            return Actions::Train(UnitType::Archer, trainable);
+        }
+
+        let recruits = player.recruit_max_units(UnitType::Archer);
+        if recruits > 0 {
+            return Actions::Recruit(UnitType::Archer, recruits);
+        }
+
+        Actions::Harvest
+    }
+}
+
+/// Compute how many units of given type the player can train right now
+///
+/// Params
+/// ---
+/// - player: the bot's player
+/// - unit_type: type of the unit
+///
+/// Returns
+/// ---
+/// - number of units the player can afford and fit into the bases
+fn max_trainable(player: &Player, unit_type: UnitType) -> Quantity {
+    player
+        .train_max_units(unit_type)
+        .min(player.current_fighters_capacity())
+}
+
+/// Send the strongest available stack to the field which is the easiest to take over
+///
+/// Params
+/// ---
+/// - player: the bot's player
+/// - plan: current state of the battlefield
+///
+/// Returns
+/// ---
+/// - Some(conquer action): if the player has units to send
+/// - None: otherwise
+fn conquer_action(player: &Player, plan: &GamePlan) -> Option<Actions> {
+    // the stack with the highest power is sent
+    let (unit_type, quantity) = UnitType::all()
+        .into_iter()
+        .map(|unit_type| (unit_type, player.send_max_units(unit_type)))
+        .filter(|(_, quantity)| *quantity > 0)
+        .max_by(|(type_a, quantity_a), (type_b, quantity_b)| {
+            let power_a = type_a.power() * *quantity_a as FighterPower;
+            let power_b = type_b.power() * *quantity_b as FighterPower;
+            power_a.total_cmp(&power_b)
+        })?;
+
+    // prefer fields the player does not lead, needing the least power to take over
+    let (x, y) = (0..plan.width())
+        .flat_map(|x| (0..plan.height()).map(move |y| (x, y)))
+        .filter_map(|(x, y)| {
+            let field = plan.field(x, y)?;
+            let needed = field.power_needed_to_lead(&player.nick);
+            Some(((x, y), needed))
+        })
+        .min_by(|(_, needed_a), (_, needed_b)| {
+            // fields which are already led come last
+            let key_a = if *needed_a > 0.0 { *needed_a } else { f64::MAX };
+            let key_b = if *needed_b > 0.0 { *needed_b } else { f64::MAX };
+            key_a.total_cmp(&key_b)
+        })
+        .map(|(coordinates, _)| coordinates)?;
+
+    Some(Actions::Conquer(x, y, unit_type, quantity))
+}
@@ -0,0 +1,130 @@
+// Audit of the game state, for checking that separately running games stay in sync.
+// With '--audit', every performed action is followed by a line with the action in the compact
+// notation and a short checksum of the state, f.e. 'AUDIT 3 Alice C 1 0 A 10 #4f2a9c1e'.
+// Two games fed the same moves print the same lines, the first differing line shows
+// where they went apart.
+
+use serde::Serialize;
+
+use super::events::{GameEvent, Observer};
+use super::types::{actions::Actions, board::GamePlan, player::Player};
+
+// FNV-1a parameters of the 64 bit variant
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Observer printing the checksum of the state after every action
+pub struct Auditor {
+    round: usize,
+    // checksum of every player as they were after their last action, in the order they acted first
+    players: Vec<(String, u64)>,
+}
+
+impl Auditor {
+    /// Create an auditor which has not seen any action yet
+    pub fn new() -> Self {
+        Self {
+            round: 0,
+            players: Vec::new(),
+        }
+    }
+
+    /// Remember the state of a player and describe the state of the whole game
+    ///
+    /// Params
+    /// ---
+    /// - player: the player who has just acted
+    /// - action: the action, in the compact notation
+    /// - game_plan: battlefield after the action
+    ///
+    /// Returns
+    /// ---
+    /// - String: the audit line
+    fn audit(&mut self, player: &Player, action: &str, game_plan: &GamePlan) -> String {
+        let player_checksum = checksum(player);
+        match self
+            .players
+            .iter_mut()
+            .find(|(nick, _)| *nick == player.nick)
+        {
+            Some((_, known)) => *known = player_checksum,
+            None => self.players.push((player.nick.clone(), player_checksum)),
+        }
+
+        // the players are combined by their nicks, so the order they acted in does not matter
+        let mut players = self.players.clone();
+        players.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let state = players
+            .iter()
+            .fold(checksum(game_plan), |state, (nick, player_checksum)| {
+                fnv1a(state, format!("{}{}", nick, player_checksum).as_bytes())
+            });
+
+        // the lower half is enough to tell the states apart by eye
+        format!(
+            "AUDIT {} {} {} #{:08x}",
+            self.round, player.nick, action, state as u32
+        )
+    }
+}
+
+impl Default for Auditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Observer for Auditor {
+    fn notify(&mut self, event: &GameEvent) -> Option<String> {
+        match event {
+            GameEvent::RoundStarted { round, .. } => {
+                self.round = *round;
+                None
+            }
+            GameEvent::ActionPerformed {
+                player,
+                action,
+                game_plan,
+            } => Some(self.audit(player, &action.notation(), game_plan)),
+            GameEvent::ActionUndone {
+                player,
+                action,
+                game_plan,
+            } => Some(self.audit(
+                player,
+                &format!("{} {}", Actions::Undo.notation(), action.notation()),
+                game_plan,
+            )),
+        }
+    }
+}
+
+/// Compute the checksum of a part of the game state
+///
+/// The state is serialized into a JSON value first, its objects keep their keys sorted,
+/// so equal states have equal checksums in every running game.
+///
+/// Params
+/// ---
+/// - state: the part of the state, f.e. a player
+///
+/// Returns
+/// ---
+/// - 64 bit checksum of the state, 0 if it could not be serialized
+pub fn checksum(state: &impl Serialize) -> u64 {
+    serde_json::to_value(state)
+        .map(|value| fnv1a(FNV_OFFSET, value.to_string().as_bytes()))
+        .unwrap_or(0)
+}
+
+/// Continue a FNV-1a hash with more bytes
+///
+/// Params
+/// ---
+/// - hash: hash of the bytes so far
+/// - bytes: bytes to add
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
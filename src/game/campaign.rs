@@ -0,0 +1,313 @@
+// Single player campaign of escalating skirmishes.
+// Every skirmish of a campaign is generated from its seed and its stage, so the same
+// seed always leads through the same chain: the battlefields grow, more and angrier
+// bots join in, and half of the units which survive a won skirmish march on to the next.
+// The best stage reached with every seed is kept in the profile file.
+
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+
+use super::ai::BotStrategy;
+use super::rng::GameRng;
+use super::settings::{GameSettings, InputEnd};
+use super::types::{
+    board::GamePlan,
+    limits::{DEFAULT_BOT_TIME_LIMIT, MAX_PLAN_HEIGHT, MAX_PLAN_WIDTH},
+    player::Player,
+    troops::UnitType,
+    value_types::{Quantity, ResourceValue},
+};
+use super::victory::VictoryCondition;
+
+// file the progression of the campaigns is kept in
+pub const DEFAULT_PROFILE_FILE: &str = "wartycoon_profile.json";
+
+// at most this many bots fight in one skirmish
+const MAX_BOTS: usize = 3;
+
+// at most this many units of every type are carried over to the next skirmish
+const MAX_CORE_UNITS: Quantity = 20;
+
+/// One generated skirmish of a campaign
+pub struct Skirmish {
+    /// number of the skirmish in the campaign, starting with 1
+    pub stage: usize,
+    /// width of the battlefield
+    pub width: usize,
+    /// height of the battlefield
+    pub height: usize,
+    /// settings of the skirmish
+    pub settings: GameSettings,
+    /// strategies of the bots, one bot per strategy
+    pub bots: Vec<BotStrategy>,
+    /// (wood, gold) every bot starts with on top of the usual supplies
+    pub bot_supplies: ResourceValue,
+    /// seed of the skirmish's generator
+    pub seed: u64,
+}
+
+impl Skirmish {
+    /// Generate a skirmish of a campaign, the same one for the same seed and stage
+    ///
+    /// Params
+    /// ---
+    /// - seed: seed of the campaign
+    /// - stage: number of the skirmish in the campaign, starting with 1
+    ///
+    /// Returns
+    /// ---
+    /// - the generated skirmish
+    pub fn generate(seed: u64, stage: usize) -> Self {
+        let stage = stage.max(1);
+        let mut rng = GameRng::new(seed ^ (stage as u64).wrapping_mul(0x9e3779b97f4a7c15));
+
+        // the battlefield grows every other stage, give or take a row or a column
+        let side = 1 + stage / 2;
+        let width = (side + rng.below(2)).min(MAX_PLAN_WIDTH);
+        let height = (side + rng.below(2)).min(MAX_PLAN_HEIGHT);
+
+        // another bot joins every other stage, later ones are more likely aggressive
+        let aggression = (20 * stage).min(80);
+        let bots = (0..(1 + (stage - 1) / 2).min(MAX_BOTS))
+            .map(|_| match rng.below(100) < aggression {
+                true => BotStrategy::Aggressive,
+                false => BotStrategy::Economic,
+            })
+            .collect();
+
+        let rounds = 10 + 2 * (stage - 1);
+        let settings = GameSettings {
+            rounds,
+            conquest_round: 1,
+            // the fog only comes down once the player is used to the campaign
+            fog_of_war: stage >= 3 && rng.below(2) == 0,
+            privacy_screen: false,
+            bot_time_limit: DEFAULT_BOT_TIME_LIMIT,
+            turn_time_limit: None,
+            victory: VictoryCondition::MostFields,
+            on_input_end: InputEnd::default(),
+        };
+
+        let head_start = (stage - 1) as Quantity;
+
+        Skirmish {
+            stage,
+            width,
+            height,
+            settings,
+            bots,
+            bot_supplies: (100 * head_start, 50 * head_start),
+            seed: rng.next_u64(),
+        }
+    }
+
+    /// Create the players of the skirmish, the human player comes first
+    ///
+    /// Params
+    /// ---
+    /// - nick: nick of the human player
+    /// - core: (unit type, quantity) of the units carried over from the previous skirmish
+    ///
+    /// Returns
+    /// ---
+    /// - players of the skirmish
+    pub fn players(&self, nick: &str, core: &[(UnitType, Quantity)]) -> Vec<Player> {
+        let mut player = Player::new(nick);
+        for (unit_type, quantity) in core {
+            player.receive_units(*unit_type, *quantity);
+        }
+
+        let bots = self.bots.iter().enumerate().map(|(index, strategy)| {
+            let mut bot = Player::new_bot(&format!("BOT {}", index + 2), *strategy);
+            bot.receive_resources(self.bot_supplies);
+            bot
+        });
+
+        std::iter::once(player).chain(bots).collect()
+    }
+
+    /// Describe the skirmish before it starts
+    ///
+    /// Returns
+    /// ---
+    /// - lines describing the battlefield, the opponents and the settings
+    pub fn describe(&self) -> Vec<String> {
+        let opponents: Vec<String> = self
+            .bots
+            .iter()
+            .map(|strategy| format!("{} BOT", strategy))
+            .collect();
+
+        let mut lines = vec![
+            format!(
+                "{} x {} battlefield, {} rounds",
+                self.width, self.height, self.settings.rounds
+            ),
+            format!("Opponents: {}", opponents.join(", ")),
+        ];
+
+        if self.bot_supplies != (0, 0) {
+            lines.push(format!(
+                "The bots start with {} extra wood and {} extra gold.",
+                self.bot_supplies.0, self.bot_supplies.1
+            ));
+        }
+        if self.settings.fog_of_war {
+            lines.push(String::from("The fog of war covers the battlefield."));
+        }
+
+        lines
+    }
+}
+
+/// Pick the units of a player which march on to the next skirmish
+///
+/// Half of the units of every type survive the march, wherever they were.
+///
+/// Params
+/// ---
+/// - player: the player who won the skirmish
+/// - game_plan: battlefield of the won skirmish
+///
+/// Returns
+/// ---
+/// - (unit type, quantity) of the carried over units, types without units are left out
+pub fn core_units(player: &Player, game_plan: &GamePlan) -> Vec<(UnitType, Quantity)> {
+    UnitType::all()
+        .into_iter()
+        .map(|unit_type| {
+            let total = player.units_owned(game_plan, unit_type);
+
+            (unit_type, (total / 2).min(MAX_CORE_UNITS))
+        })
+        .filter(|(_, quantity)| *quantity > 0)
+        .collect()
+}
+
+/// Progression of one campaign
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CampaignRecord {
+    /// seed of the campaign
+    pub seed: u64,
+    /// nick of the player
+    pub nick: String,
+    /// the furthest stage won, 0 if none was
+    pub best_stage: usize,
+    /// how many times the campaign has been started
+    pub attempts: usize,
+}
+
+/// Progression of all the campaigns played on this computer
+#[derive(Default, Serialize, Deserialize)]
+pub struct Profile {
+    #[serde(default)]
+    campaigns: Vec<CampaignRecord>,
+}
+
+impl Profile {
+    /// Load the profile, a missing file means that no campaign has been played yet
+    ///
+    /// Params
+    /// ---
+    /// - path: path of the profile file
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(Profile) with the progression of the campaigns
+    /// - Err(String) if the file exists but could not be read
+    pub fn load(path: &str) -> Result<Profile, String> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Profile::default())
+            }
+            Err(error) => return Err(format!("Cannot read {}: {}", path, error)),
+        };
+
+        serde_json::from_str(&contents)
+            .map_err(|error| format!("{} is not a valid profile: {}", path, error))
+    }
+
+    /// Write the profile into a file
+    ///
+    /// Params
+    /// ---
+    /// - path: path of the profile file
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) containing a confirmation message
+    /// - Err(String) if the file could not be written
+    pub fn save(&self, path: &str) -> Result<String, String> {
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|error| format!("Cannot serialize the profile: {}", error))?;
+
+        fs::write(path, contents).map_err(|error| format!("Cannot write {}: {}", path, error))?;
+
+        Ok(format!("The campaign progress has been saved to {}.", path))
+    }
+
+    /// Return the progression of a campaign, if it has been played before
+    ///
+    /// Params
+    /// ---
+    /// - seed: seed of the campaign
+    /// - nick: nick of the player
+    pub fn record(&self, seed: u64, nick: &str) -> Option<&CampaignRecord> {
+        self.campaigns
+            .iter()
+            .find(|record| record.seed == seed && record.nick == nick)
+    }
+
+    /// Count another attempt at a campaign
+    ///
+    /// Params
+    /// ---
+    /// - seed: seed of the campaign
+    /// - nick: nick of the player
+    pub fn start_campaign(&mut self, seed: u64, nick: &str) {
+        self.record_mut(seed, nick).attempts += 1;
+    }
+
+    /// Record a won stage of a campaign
+    ///
+    /// Params
+    /// ---
+    /// - seed: seed of the campaign
+    /// - nick: nick of the player
+    /// - stage: the won stage
+    ///
+    /// Returns
+    /// ---
+    /// - true: if no stage this far has been won with the seed before
+    pub fn record_stage(&mut self, seed: u64, nick: &str, stage: usize) -> bool {
+        let record = self.record_mut(seed, nick);
+        let new_best = stage > record.best_stage;
+        record.best_stage = record.best_stage.max(stage);
+
+        new_best
+    }
+
+    /// Return the progression of a campaign, creating it when it is played for the first time
+    fn record_mut(&mut self, seed: u64, nick: &str) -> &mut CampaignRecord {
+        let index = match self
+            .campaigns
+            .iter()
+            .position(|record| record.seed == seed && record.nick == nick)
+        {
+            Some(index) => index,
+            None => {
+                self.campaigns.push(CampaignRecord {
+                    seed,
+                    nick: nick.into(),
+                    best_stage: 0,
+                    attempts: 0,
+                });
+                self.campaigns.len() - 1
+            }
+        };
+
+        &mut self.campaigns[index]
+    }
+}
@@ -0,0 +1,149 @@
+// Colors of the terminal output.
+// Players, errors and successes are told apart by ANSI colors, the output stays
+// plain when it does not go to a terminal, with '--no-color' or with the NO_COLOR
+// environment variable set (https://no-color.org).
+
+use std::env;
+use std::io::{stdout, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use super::types::limits::MAP_NICK_LENGTH;
+
+// colors are off until the frontend turns them on, f.e. the server never uses them
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+// nicks of the players in the order of their turns, every player gets the color of their position
+static PLAYERS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Colors used in the output
+#[derive(Clone, Copy)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+// colors of the players, in the order of their turns
+const PLAYER_COLORS: [Color; 4] = [Color::Cyan, Color::Magenta, Color::Yellow, Color::Blue];
+
+impl Color {
+    /// ANSI code of the color
+    fn code(&self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+            Color::Blue => "34",
+            Color::Magenta => "35",
+            Color::Cyan => "36",
+        }
+    }
+}
+
+/// Decide whether the output is colored
+///
+/// Params
+/// ---
+/// - no_color: whether the player asked for a plain output with '--no-color'
+pub fn init_colors(no_color: bool) {
+    let no_color_variable = env::var("NO_COLOR").is_ok_and(|value| !value.is_empty());
+
+    ENABLED.store(
+        !no_color && !no_color_variable && stdout().is_terminal(),
+        Ordering::Relaxed,
+    );
+}
+
+/// Assign colors to the players of a game
+///
+/// Params
+/// ---
+/// - nicks: nicks of the players in the order of their turns
+pub fn register_players(nicks: &[String]) {
+    *PLAYERS.lock().unwrap_or_else(|error| error.into_inner()) = nicks.to_vec();
+}
+
+/// Color a text, every line on its own, so that the color does not leak into other output
+///
+/// Params
+/// ---
+/// - text: text to color
+/// - color: color of the text
+///
+/// Returns
+/// ---
+/// - colored text, or the text unchanged when colors are off
+pub fn paint(text: &str, color: Color) -> String {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return text.to_string();
+    }
+
+    text.split('\n')
+        .map(|line| match line.is_empty() {
+            true => String::new(),
+            false => format!("\x1b[{}m{}\x1b[0m", color.code(), line),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Color a text with the color of a player
+///
+/// Params
+/// ---
+/// - text: text to color
+/// - nick: nick of the player
+///
+/// Returns
+/// ---
+/// - colored text, or the text unchanged for unknown players
+pub fn paint_player(text: &str, nick: &str) -> String {
+    match player_color(|known| known == nick) {
+        Some(color) => paint(text, color),
+        None => text.to_string(),
+    }
+}
+
+/// Color a cell of the battlefield map with the color of the player shown in it
+///
+/// Params
+/// ---
+/// - cell: padded content of the cell
+/// - description: description of the field in the cell, f.e. 'Alice 12.5' or '~Alice 3.8'
+///
+/// Returns
+/// ---
+/// - colored cell, or the cell unchanged if it shows no player
+pub fn paint_map_cell(cell: &str, description: &str) -> String {
+    let description = description.trim_start_matches('~');
+
+    // nicks are shortened in the map and followed by the power
+    let color = player_color(|known| {
+        let shortened: String = known.chars().take(MAP_NICK_LENGTH).collect();
+        description.starts_with(&format!("{} ", shortened))
+    });
+
+    match color {
+        Some(color) => paint(cell, color),
+        None => cell.to_string(),
+    }
+}
+
+/// Label of error messages, in red
+pub fn error_label() -> String {
+    paint("ERROR:", Color::Red)
+}
+
+/// Find the color of the first registered player matching a condition
+fn player_color(matches: impl Fn(&str) -> bool) -> Option<Color> {
+    PLAYERS
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .iter()
+        .position(|known| matches(known))
+        .map(|position| PLAYER_COLORS[position % PLAYER_COLORS.len()])
+}
@@ -0,0 +1,271 @@
+// Registry of the commands players can type during their turn.
+// Both the input parsing and the help screen are driven by it,
+// so a new command only has to be registered here.
+
+/// Commands available during a turn
+#[derive(PartialEq, Clone, Copy)]
+pub enum Command {
+    Build,
+    Harvest,
+    Train,
+    Conquer,
+    Quit,
+    Help,
+    Stats,
+    Rules,
+    Analyze,
+    Simulate,
+    Name,
+    Stance,
+    Recruit,
+    Save,
+    Map,
+    Template,
+    Army,
+    Scout,
+    Upgrade,
+    End,
+    Recall,
+    Undo,
+    Pass,
+    Buy,
+    Use,
+    Trade,
+    Exchange,
+    Fortify,
+    History,
+}
+
+/// Words which invoke a command, and its description for the help screen
+pub struct CommandBinding {
+    pub command: Command,
+    pub number: &'static str,
+    pub aliases: &'static [&'static str],
+    pub description: &'static str,
+}
+
+// all the registered commands, in the order they are listed in the help
+pub const COMMANDS: [CommandBinding; 29] = [
+    CommandBinding {
+        command: Command::Build,
+        number: "1",
+        aliases: &["build", "Build", "BUILD"],
+        description: "to build bases, farms or markets,\n  then hit enter, type the building (for example 'FARM')\n  and specify how many buildings to build (enter builds one)",
+    },
+    CommandBinding {
+        command: Command::Harvest,
+        number: "2",
+        aliases: &["harvest", "Harvest", "HARVEST"],
+        description: "to harvest resources",
+    },
+    CommandBinding {
+        command: Command::Train,
+        number: "3",
+        aliases: &["train", "Train", "TRAIN"],
+        description: "to train units,\n  hit enter and then type unit type (for example 'ARCHER')\n  hit enter and specify the number of units you wish to train",
+    },
+    CommandBinding {
+        command: Command::Conquer,
+        number: "4",
+        aliases: &["conquer", "Conquer", "CONQUER"],
+        description: "to send troops to conquer a field,\n  optionally followed by field coordinates (for example 'conquer 1 0'),\n  then hit enter and specify type (same as in train),\n  hit enter and put a desired number of troops",
+    },
+    CommandBinding {
+        command: Command::Quit,
+        number: "5",
+        aliases: &["q", "Q", "quit", "Quit", "QUIT"],
+        description: "to quit the game",
+    },
+    CommandBinding {
+        command: Command::Help,
+        number: "6",
+        aliases: &["h", "H", "help", "Help", "HELP"],
+        description: "to display this help",
+    },
+    CommandBinding {
+        command: Command::Stats,
+        number: "7",
+        aliases: &["stats", "Stats", "STATS", "statistics", "Statistics", "STATISTICS"],
+        description: "\n  to display current player's statistics",
+    },
+    CommandBinding {
+        command: Command::Rules,
+        number: "8",
+        aliases: &["rules", "Rules", "RULES"],
+        description: "to display game rules.",
+    },
+    CommandBinding {
+        command: Command::Analyze,
+        number: "9",
+        aliases: &["analyze", "Analyze", "ANALYZE"],
+        description: "followed by field coordinates (for example 'analyze 0 0')\n  to compare your power on a field with your opponents'",
+    },
+    CommandBinding {
+        command: Command::Simulate,
+        number: "10",
+        aliases: &["simulate", "Simulate", "SIMULATE"],
+        description: "to try out an action (and a guessed\n  response of an opponent) without affecting the real game",
+    },
+    CommandBinding {
+        command: Command::Name,
+        number: "11",
+        aliases: &["name", "Name", "NAME"],
+        description: "followed by field coordinates and a name\n  (for example 'name 0 0 The Ford') to label a field, leave the name out to remove it",
+    },
+    CommandBinding {
+        command: Command::Stance,
+        number: "12",
+        aliases: &["stance", "Stance", "STANCE"],
+        description: "to change the stance of your units on a field,\n  then hit enter and type 'GARRISON' or 'RAIDING'",
+    },
+    CommandBinding {
+        command: Command::Recruit,
+        number: "13",
+        aliases: &["recruit", "Recruit", "RECRUIT"],
+        description: "to upgrade peasants into units for a reduced cost,\n  then hit enter and specify type and number of peasants (same as in train)",
+    },
+    CommandBinding {
+        command: Command::Save,
+        number: "14",
+        aliases: &["save", "Save", "SAVE"],
+        description: "to save the game at the start of your turn (before your first action),\n  optionally followed by a file name (for example 'save my_game.json')",
+    },
+    CommandBinding {
+        command: Command::Map,
+        number: "15",
+        aliases: &["map", "Map", "MAP"],
+        description: "to display the whole battlefield with leaders of the fields",
+    },
+    CommandBinding {
+        command: Command::Template,
+        number: "16",
+        aliases: &["template", "Template", "TEMPLATE"],
+        description: "followed by a name and units (for example 'template raid 20 archer 10 warrior')\n  to save an army template, leave the units out to remove it, or the name to list templates",
+    },
+    CommandBinding {
+        command: Command::Army,
+        number: "17",
+        aliases: &["army", "Army", "ARMY"],
+        description: "followed by a template name (for example 'army raid')\n  to train the whole saved army in one action",
+    },
+    CommandBinding {
+        command: Command::Scout,
+        number: "18",
+        aliases: &["scout", "Scout", "SCOUT"],
+        description: "optionally followed by field coordinates (for example 'scout 1 0')\n  to reveal a field in the fog of war until the end of your next turn",
+    },
+    CommandBinding {
+        command: Command::Upgrade,
+        number: "19",
+        aliases: &["upgrade", "Upgrade", "UPGRADE"],
+        description: "to upgrade your building with the lowest level,\n  bases gain capacity and farms yield more crops",
+    },
+    CommandBinding {
+        command: Command::End,
+        number: "20",
+        aliases: &["end", "End", "END"],
+        description: "to end your turn, leaving the remaining action points unused",
+    },
+    CommandBinding {
+        command: Command::Recall,
+        number: "21",
+        aliases: &["recall", "Recall", "RECALL"],
+        description: "to bring units back from a field into your bases,\n  optionally followed by field coordinates (for example 'recall 1 0'),\n  then specify type and number of units (same as in conquer)",
+    },
+    CommandBinding {
+        command: Command::Undo,
+        number: "22",
+        aliases: &["undo", "Undo", "UNDO"],
+        description: "to take back your last action, once per turn, while the turn lasts",
+    },
+    CommandBinding {
+        command: Command::Pass,
+        number: "23",
+        aliases: &["pass", "Pass", "PASS"],
+        description: "to give up your whole turn for a few resources,\n  only before your first action of the turn",
+    },
+    CommandBinding {
+        command: Command::Buy,
+        number: "24",
+        aliases: &["buy", "Buy", "BUY"],
+        description: "to buy siege ladders, fire arrows or healing potions for your battles,\n  then hit enter, type the item and specify how many to buy (enter buys one)",
+    },
+    CommandBinding {
+        command: Command::Use,
+        number: "25",
+        aliases: &["use", "Use", "USE"],
+        description: "to use an item on a field with your units until the end of the round,\n  optionally followed by field coordinates (for example 'use 1 0')",
+    },
+    CommandBinding {
+        command: Command::Trade,
+        number: "26",
+        aliases: &["trade", "Trade", "TRADE"],
+        description: "to offer wood and gold to another player for theirs,\n  then pick the player and type the wood and gold to give and to get (for example '100 50'),\n  the player answers the offer at the start of their next turn",
+    },
+    CommandBinding {
+        command: Command::Exchange,
+        number: "27",
+        aliases: &["exchange", "Exchange", "EXCHANGE"],
+        description: "to sell wood for gold or gold for wood on the market, a MARKET is needed,\n  then hit enter, type the resource to sell and specify how much to sell",
+    },
+    CommandBinding {
+        command: Command::Fortify,
+        number: "28",
+        aliases: &["fortify", "Fortify", "FORTIFY"],
+        description: "to build a tower on a field with your units, which makes them stronger there,\n  optionally followed by field coordinates (for example 'fortify 1 0')",
+    },
+    CommandBinding {
+        command: Command::History,
+        number: "29",
+        aliases: &["history", "History", "HISTORY"],
+        description: "to list the actions performed in the game, optionally followed by filters:\n  a command, 'round 5' or 'round 5-8', 'player Bob' and words to search for\n  (for example 'history conquer round 5-8 player Bob')",
+    },
+];
+
+/// Find the command invoked by a typed word
+///
+/// Params
+/// ---
+/// - word: first word of the user input, f.e. '1' or 'build'
+///
+/// Returns
+/// ---
+/// - Some(command): if the word invokes a registered command
+/// - None: otherwise
+pub fn find_command(word: &str) -> Option<Command> {
+    COMMANDS
+        .iter()
+        .find(|binding| binding.number == word || binding.aliases.contains(&word))
+        .map(|binding| binding.command)
+}
+
+/// Describe how a command is invoked and what it does
+///
+/// Params
+/// ---
+/// - binding: registered command
+///
+/// Returns
+/// ---
+/// - String: f.e. "-'2' or 'harvest', 'Harvest', 'HARVEST' to harvest resources"
+pub fn describe_command(binding: &CommandBinding) -> String {
+    let aliases: Vec<String> = binding
+        .aliases
+        .iter()
+        .map(|alias| format!("'{}'", alias))
+        .collect();
+
+    // descriptions starting on a new line are not separated by a space
+    let separator = match binding.description.starts_with('\n') {
+        true => "",
+        false => " ",
+    };
+
+    format!(
+        "-'{}' or {}{}{}",
+        binding.number,
+        aliases.join(", "),
+        separator,
+        binding.description
+    )
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use super::events::{GameEvent, Observer};
+use super::types::{
+    actions::Actions, buildings::BuildingType, troops::UnitType, value_types::Quantity,
+};
+
+// trainings of at least this size are worth a comment
+const MASSING_THRESHOLD: Quantity = 20;
+
+/// Built-in simple commentator, which comments on notable moves of players
+pub struct Commentator {
+    // last known leader of each field (by coordinates)
+    field_leaders: HashMap<(usize, usize), Option<String>>,
+}
+
+impl Commentator {
+    /// Create a new commentator
+    pub fn new() -> Self {
+        Self {
+            field_leaders: HashMap::new(),
+        }
+    }
+}
+
+impl Default for Commentator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Observer for Commentator {
+    fn notify(&mut self, event: &GameEvent) -> Option<String> {
+        match event {
+            // announce the last round
+            GameEvent::RoundStarted {
+                round,
+                rounds_total,
+            } if round == rounds_total => Some(String::from(
+                "The final round begins! Everything is at stake!",
+            )),
+            GameEvent::RoundStarted { .. } => None,
+            GameEvent::ActionPerformed {
+                player,
+                action,
+                game_plan,
+            } => match action {
+                Actions::Build(BuildingType::Base, 1) => Some(format!(
+                    "{} expands their kingdom with a new BASE.",
+                    player.nick
+                )),
+                Actions::Build(BuildingType::Base, quantity) => Some(format!(
+                    "{} expands their kingdom with {} new BASES!",
+                    player.nick, quantity
+                )),
+                Actions::Build(BuildingType::Farm, _) => Some(format!(
+                    "{} ploughs new fields, the granaries will be full!",
+                    player.nick
+                )),
+                Actions::Build(BuildingType::Market, _) => Some(format!(
+                    "Merchants flock to the new market of {}!",
+                    player.nick
+                )),
+                Actions::Train(UnitType::Archer, quantity) if *quantity >= MASSING_THRESHOLD => {
+                    Some(format!("{} is massing archers!", player.nick))
+                }
+                Actions::Train(UnitType::Warrior, quantity) if *quantity >= MASSING_THRESHOLD => {
+                    Some(format!("{} is raising a horde of warriors!", player.nick))
+                }
+                Actions::Train(UnitType::Cavalry, quantity) if *quantity >= MASSING_THRESHOLD => {
+                    Some(format!(
+                        "{} is saddling up for a cavalry charge!",
+                        player.nick
+                    ))
+                }
+                Actions::AnswerTrade { from, accept: true } => Some(format!(
+                    "{} and {} strike a deal, the merchants are busy!",
+                    from, player.nick
+                )),
+                Actions::Conquer(x, y, _, _) => {
+                    let field = game_plan.field(*x, *y)?;
+                    let label = field.label();
+                    let leader = field.leader().map(|(leader, _)| leader);
+
+                    // only a change of the field's leader is worth a comment
+                    let previous_leader = self.field_leaders.insert((*x, *y), leader.clone());
+
+                    match (previous_leader.flatten(), leader) {
+                        (previous, Some(leader)) if previous.as_ref() != Some(&leader) => {
+                            match previous {
+                                Some(previous) => Some(format!(
+                                    "{} seizes field {} from {}!",
+                                    leader, label, previous
+                                )),
+                                None => {
+                                    Some(format!("{} takes the lead on field {}!", leader, label))
+                                }
+                            }
+                        }
+                        (Some(previous), None) => Some(format!(
+                            "{} is no longer the sole master of field {}, it is a stalemate!",
+                            previous, label
+                        )),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            // the leader of the field is known again, the taken back move is not commented
+            GameEvent::ActionUndone {
+                player,
+                action,
+                game_plan,
+            } => {
+                if let Some((x, y)) = action.target_field() {
+                    let leader = game_plan.field(x, y)?.leader().map(|(leader, _)| leader);
+                    self.field_leaders.insert((x, y), leader);
+                }
+                Some(format!(
+                    "{} has second thoughts and takes back their move.",
+                    player.nick
+                ))
+            }
+        }
+    }
+}
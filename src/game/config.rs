@@ -0,0 +1,174 @@
+// Configuration file overriding the balance values of the game.
+// It is a small subset of TOML: one 'name = value' setting per line, '#' starts a comment.
+//
+//     archer_cost = [0, 12]     # wood, gold
+//     archer_power = 2.0
+//     base_capacity = 250
+
+use std::fs;
+use std::path::Path;
+
+use super::types::{
+    rules::{RuleValue, Rules},
+    value_types::{FighterPower, Quantity},
+};
+
+// file loaded at the start of the game, if it exists
+pub const DEFAULT_CONFIG_FILE: &str = "wartycoon.toml";
+
+/// Load the rules from a configuration file
+///
+/// Params
+/// ---
+/// - path: path of the configuration file
+/// - required: whether a missing file is an error
+///
+/// Returns
+/// ---
+/// - Ok(Some(rules)): rules with the values of the file
+/// - Ok(None): if the file does not exist and is not required
+/// - Err(String) if the file cannot be read or contains invalid settings
+pub fn load_config(path: &str, required: bool) -> Result<Option<Rules>, String> {
+    if !required && !Path::new(path).exists() {
+        return Ok(None);
+    }
+
+    let contents =
+        fs::read_to_string(path).map_err(|error| format!("Cannot read {}: {}", path, error))?;
+
+    parse_config(&contents)
+        .map(Some)
+        .map_err(|error| format!("{}: {}", path, error))
+}
+
+/// Override the default rules with the settings of a configuration file
+///
+/// Params
+/// ---
+/// - contents: contents of the configuration file
+///
+/// Returns
+/// ---
+/// - Ok(rules) with the settings applied
+/// - Err(String) naming the line with an invalid setting
+pub fn parse_config(contents: &str) -> Result<Rules, String> {
+    let mut rules = Rules::DEFAULT;
+
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+
+        // tables only group the settings, their names do not matter
+        if line.is_empty() || (line.starts_with('[') && line.ends_with(']') && !line.contains('='))
+        {
+            continue;
+        }
+
+        apply_setting(&mut rules, line)
+            .map_err(|error| format!("line {}: {}", number + 1, error))?;
+    }
+
+    Ok(rules)
+}
+
+/// Describe the rules, one per line, f.e. 'archer_cost = [0, 10]'
+///
+/// Params
+/// ---
+/// - rules: rules to describe
+///
+/// Returns
+/// ---
+/// - lines describing the rules
+pub fn describe_rules(rules: &Rules) -> Vec<String> {
+    let mut rules = *rules;
+
+    rules
+        .entries()
+        .into_iter()
+        .map(|(name, value)| match value {
+            RuleValue::Capacity(value) | RuleValue::Quantity(value) => {
+                format!("{} = {}", name, value)
+            }
+            RuleValue::Cost((wood, gold)) => format!("{} = [{}, {}]", name, wood, gold),
+            RuleValue::Power(value) | RuleValue::Tolerance(value) => {
+                format!("{} = {}", name, value)
+            }
+        })
+        .collect()
+}
+
+/// Apply one 'name = value' setting
+///
+/// Params
+/// ---
+/// - rules: rules to change
+/// - line: the setting without comments
+///
+/// Returns
+/// ---
+/// - Ok(()) if the setting was applied
+/// - Err(String) if the setting is unknown or its value is invalid
+fn apply_setting(rules: &mut Rules, line: &str) -> Result<(), String> {
+    let (name, value) = line
+        .split_once('=')
+        .ok_or_else(|| format!("expected 'name = value', found '{}'", line))?;
+    let (name, value) = (name.trim(), value.trim());
+
+    let entry = rules
+        .entries()
+        .into_iter()
+        .find(|(rule, _)| *rule == name)
+        .map(|(_, value)| value)
+        .ok_or_else(|| format!("unknown setting '{}'", name))?;
+
+    match entry {
+        RuleValue::Capacity(rule) | RuleValue::Quantity(rule) => {
+            *rule = parse_quantity(name, value)?;
+        }
+        RuleValue::Cost(rule) => {
+            let parts: Vec<&str> = value
+                .strip_prefix('[')
+                .and_then(|value| value.strip_suffix(']'))
+                .ok_or_else(|| format!("'{}' has to be '[wood, gold]'", name))?
+                .split(',')
+                .collect();
+
+            match parts.as_slice() {
+                [wood, gold] => {
+                    *rule = (parse_quantity(name, wood)?, parse_quantity(name, gold)?);
+                }
+                _ => return Err(format!("'{}' has to be '[wood, gold]'", name)),
+            }
+        }
+        RuleValue::Power(rule) => {
+            *rule = match value.parse::<FighterPower>() {
+                Ok(power) if power.is_finite() && power > 0.0 => power,
+                _ => return Err(format!("'{}' has to be a positive number", name)),
+            };
+        }
+        RuleValue::Tolerance(rule) => {
+            *rule = match value.parse::<FighterPower>() {
+                Ok(tolerance) if tolerance.is_finite() && tolerance >= 0.0 => tolerance,
+                _ => return Err(format!("'{}' cannot be a negative number", name)),
+            };
+        }
+    }
+
+    // bases without any room would make training impossible
+    if rules.base_capacity <= 0 {
+        return Err(String::from("'base_capacity' has to be at least 1"));
+    }
+
+    Ok(())
+}
+
+/// Parse a whole number which cannot be negative
+fn parse_quantity(name: &str, value: &str) -> Result<Quantity, String> {
+    match value.trim().parse::<Quantity>() {
+        Ok(quantity) if quantity >= 0 => Ok(quantity),
+        _ => Err(format!(
+            "'{}' has to consist of whole numbers, which are not negative",
+            name
+        )),
+    }
+}
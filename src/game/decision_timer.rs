@@ -0,0 +1,53 @@
+// Measures how long players take to decide on their actions.
+// The end-of-game report awards the fastest and the slowest thinker.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Time spent deciding and number of decisions of every player
+#[derive(Default)]
+pub struct DecisionTimer {
+    decisions: HashMap<String, (Duration, u32)>,
+}
+
+impl DecisionTimer {
+    /// Create a timer with no decisions recorded
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record how long a player took to decide on an action
+    ///
+    /// Params
+    /// ---
+    /// - nick: nick of the player
+    /// - duration: time the player spent deciding
+    pub fn record(&mut self, nick: &str, duration: Duration) {
+        let (total, count) = self
+            .decisions
+            .entry(nick.to_string())
+            .or_insert((Duration::ZERO, 0));
+
+        *total += duration;
+        *count += 1;
+    }
+
+    /// Average decision time of every player, from the fastest to the slowest
+    ///
+    /// Returns
+    /// ---
+    /// - vector of (nick, average decision time)
+    pub fn averages(&self) -> Vec<(String, Duration)> {
+        let mut averages: Vec<(String, Duration)> = self
+            .decisions
+            .iter()
+            .filter(|(_, (_, count))| *count > 0)
+            .map(|(nick, (total, count))| (nick.clone(), *total / *count))
+            .collect();
+
+        averages.sort_by(|(nick_a, time_a), (nick_b, time_b)| {
+            time_a.cmp(time_b).then_with(|| nick_a.cmp(nick_b))
+        });
+        averages
+    }
+}
@@ -0,0 +1,129 @@
+// Developer console for testing scenarios, configurations and bots without playing whole games.
+// It is only compiled with the 'dev' feature, f.e. 'cargo run --features dev'.
+//
+//     cheat give wood 1000
+//     cheat spawn 50 archers at 2 3
+//     cheat reveal
+//     cheat skip 10
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::player_action::{parse_field, parse_unit_type};
+use super::types::{
+    board::{GamePlan, UnitInField},
+    player::Player,
+    plurals::plural_suffix,
+    troops::Unit,
+    value_types::Quantity,
+};
+
+// turns of the rounds before this one end right after they start
+static SKIP_TO_ROUND: AtomicUsize = AtomicUsize::new(0);
+
+/// Run a cheat typed after the 'cheat' command
+///
+/// Params
+/// ---
+/// - player: player who typed the cheat
+/// - game_plan: battlefield of the game
+/// - arguments: words of the cheat, f.e. ['give', 'wood', '1000']
+///
+/// Returns
+/// ---
+/// - Ok(String) describing what the cheat did
+/// - Err(String) if the cheat is unknown or its arguments are invalid
+pub fn run_cheat(
+    player: &mut Player,
+    game_plan: &mut GamePlan,
+    arguments: &[&str],
+) -> Result<String, String> {
+    match arguments {
+        ["give", resource, amount] => {
+            let amount = parse_amount(amount)?;
+            match resource.to_lowercase().as_str() {
+                "wood" => player.cheat_resources(amount, 0),
+                "gold" => player.cheat_resources(0, amount),
+                _ => return Err(format!("Unknown resource '{}', try WOOD or GOLD.", resource)),
+            }
+            Ok(format!("{} received {} {}.", player.nick, amount, resource))
+        }
+        ["spawn", quantity, unit_type, place @ ..] => {
+            let quantity = parse_amount(quantity)?;
+            // plurals are accepted, f.e. 'archers'
+            let unit_type = parse_unit_type(unit_type)
+                .or_else(|| parse_unit_type(unit_type.trim_end_matches(['s', 'S'])))
+                .ok_or_else(|| format!("Unknown unit type '{}'.", unit_type))?;
+
+            match place {
+                [] => {
+                    player.cheat_units(unit_type, quantity);
+                    Ok(format!(
+                        "{} {}{} joined the army of {}.",
+                        quantity,
+                        unit_type,
+                        plural_suffix(quantity, "S"),
+                        player.nick
+                    ))
+                }
+                ["at", field @ ..] => {
+                    // the coordinates can be separated by a comma, f.e. 'at 2,3'
+                    let field = field.join(" ").replace(',', " ");
+                    let field: Vec<&str> = field.split_whitespace().collect();
+                    let field = parse_field(game_plan, &field)
+                        .and_then(|(x, y)| game_plan.get_game_field(x, y))
+                        .ok_or_else(|| String::from("There is no such field."))?;
+
+                    field.add_units(UnitInField::new(
+                        player.nick.clone(),
+                        Unit::unit_to_send(unit_type, quantity),
+                    ));
+                    Ok(format!(
+                        "{} {}{} of {} appeared on field {}.",
+                        quantity,
+                        unit_type,
+                        plural_suffix(quantity, "S"),
+                        player.nick,
+                        field.label()
+                    ))
+                }
+                _ => Err(String::from(
+                    "Please type 'cheat spawn <quantity> <unit type>', optionally followed by 'at <x> <y>'.",
+                )),
+            }
+        }
+        ["reveal"] | ["reveal", "map"] => Ok(format!(
+            "The whole battlefield:\n\n{}",
+            game_plan.render_map()
+        )),
+        ["skip", round] | ["skip", "to", round] | ["skip", "to", "round", round] => {
+            let round: usize = round
+                .parse()
+                .map_err(|_| format!("Incorrect round: {}.", round))?;
+            SKIP_TO_ROUND.store(round, Ordering::Relaxed);
+            Ok(format!(
+                "All turns are skipped until round {}, once this one is ended.",
+                round
+            ))
+        }
+        _ => Err(String::from(
+            "Known cheats: 'give <wood|gold> <amount>', 'spawn <quantity> <unit type> [at <x> <y>]', 'reveal', 'skip <round>'.",
+        )),
+    }
+}
+
+/// Check whether the turns of a round are skipped
+///
+/// Params
+/// ---
+/// - round: which round it is
+pub fn skipping(round: usize) -> bool {
+    round < SKIP_TO_ROUND.load(Ordering::Relaxed)
+}
+
+/// Parse an amount of resources or units, which has to be positive
+fn parse_amount(amount: &str) -> Result<Quantity, String> {
+    match amount.parse::<Quantity>() {
+        Ok(amount) if amount > 0 => Ok(amount),
+        _ => Err(format!("Incorrect amount: {}.", amount)),
+    }
+}
@@ -0,0 +1,97 @@
+// Market shared by all the players, where wood is exchanged for gold and back.
+// The market keeps reserves of both resources, everything the players sell there
+// adds to the supply of the resource, so a resource flooding the market gets cheaper
+// and the scarce one gets dearer, for everybody.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{
+    resources::ResourceType,
+    rules::rules,
+    value_types::{Quantity, ResourceValue},
+};
+
+/// Reserves of the market, they set the exchange rates
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Economy {
+    wood: Quantity,
+    gold: Quantity,
+}
+
+/// Markets open with the reserves from the rules
+impl Default for Economy {
+    fn default() -> Self {
+        let (wood, gold) = rules().market_reserves;
+        Self { wood, gold }
+    }
+}
+
+impl Economy {
+    /// Return how much of the other resource the market pays for a resource
+    ///
+    /// The product of the reserves stays the same, so every sold unit is worth
+    /// a bit less than the one before it.
+    ///
+    /// Params
+    /// ---
+    /// - resource: type of the sold resource
+    /// - quantity: how much of it is sold
+    /// - fee: percents of the payment the market keeps
+    ///
+    /// Returns
+    /// ---
+    /// - quantity of the other resource paid for the sold one
+    pub fn quote(&self, resource: ResourceType, quantity: Quantity, fee: Quantity) -> Quantity {
+        let (supply, demand) = match resource {
+            ResourceType::Wood => (self.wood as i64, self.gold as i64),
+            ResourceType::Gold => (self.gold as i64, self.wood as i64),
+        };
+
+        if quantity <= 0 || supply + quantity as i64 <= 0 {
+            return 0;
+        }
+
+        let payment = demand * quantity as i64 / (supply + quantity as i64);
+        let fee = fee.clamp(0, 100) as i64;
+
+        (payment * (100 - fee) / 100) as Quantity
+    }
+
+    /// Sell a resource to the market, its reserves change accordingly
+    ///
+    /// Params
+    /// ---
+    /// - resource: type of the sold resource
+    /// - quantity: how much of it is sold
+    /// - fee: percents of the payment the market keeps
+    ///
+    /// Returns
+    /// ---
+    /// - quantity of the other resource paid for the sold one
+    pub fn exchange(
+        &mut self,
+        resource: ResourceType,
+        quantity: Quantity,
+        fee: Quantity,
+    ) -> Quantity {
+        let payment = self.quote(resource, quantity, fee);
+
+        match resource {
+            ResourceType::Wood => {
+                self.wood += quantity;
+                self.gold -= payment;
+            }
+            ResourceType::Gold => {
+                self.gold += quantity;
+                self.wood -= payment;
+            }
+        }
+
+        payment
+    }
+
+    /// Return the reserves of the market as (wood, gold)
+    pub fn reserves(&self) -> ResourceValue {
+        (self.wood, self.gold)
+    }
+}
@@ -0,0 +1,608 @@
+// Game engine without any terminal input or output.
+// It accepts actions of the players and returns their results,
+// so the game can be embedded into other programs or driven by tests.
+
+use std::time::Duration;
+
+use super::revolts::{resolve_revolts, Revolt};
+use super::rng::GameRng;
+use super::save::SavedGame;
+use super::settings::GameSettings;
+use super::types::{
+    actions::Actions,
+    board::{BattleReport, GamePlan},
+    items::award_spoils,
+    limits::ACTION_POINTS_PER_TURN,
+    player::Player,
+    trade::answer_offer,
+    value_types::Quantity,
+};
+
+/// State of a running game, advanced by the actions of the players
+#[derive(Clone)]
+pub struct GameEngine {
+    players: Vec<Player>,
+    game_plan: GamePlan,
+    settings: GameSettings,
+    round: usize,
+    // index of the player whose turn it is
+    current_player: usize,
+    // action points the current player has left in their turn
+    action_points: Quantity,
+    // battles fought at the end of the last round
+    battles: Vec<BattleReport>,
+    // fields which revolted at the end of the last round
+    revolts: Vec<Revolt>,
+    // player who has fulfilled the victory condition before the last round
+    victor: Option<String>,
+    // source of the randomness of the game
+    rng: GameRng,
+    // state before the last action of the current turn
+    undo_point: Option<Snapshot>,
+    // the current player has already taken an action back this turn
+    undo_used: bool,
+}
+
+/// State of an engine captured at one moment, independent of the engine it was taken from
+///
+/// Searching bots and what-if simulations can try actions out on the engine
+/// and restore the snapshot afterwards, the snapshot can be restored any number of times.
+#[derive(Clone)]
+pub struct Snapshot {
+    engine: Box<GameEngine>,
+}
+
+impl GameEngine {
+    /// Create an engine for a new game, starting with the first player in round 1
+    ///
+    /// Params
+    /// ---
+    /// - players: players of the game, in the order of their turns
+    /// - game_plan: battlefield of the game
+    /// - settings: settings of the game
+    /// - rng: generator of the game, the same seed and actions give the same game
+    pub fn new(
+        players: Vec<Player>,
+        game_plan: GamePlan,
+        settings: GameSettings,
+        rng: GameRng,
+    ) -> Self {
+        Self {
+            players,
+            game_plan,
+            settings,
+            round: 1,
+            current_player: 0,
+            action_points: ACTION_POINTS_PER_TURN,
+            battles: Vec::new(),
+            revolts: Vec::new(),
+            victor: None,
+            rng,
+            undo_point: None,
+            undo_used: false,
+        }
+    }
+
+    /// Create an engine continuing a saved game
+    ///
+    /// Params
+    /// ---
+    /// - saved_game: state of the game at the start of a player's turn
+    pub fn from_saved(saved_game: SavedGame) -> Self {
+        Self {
+            players: saved_game.players,
+            game_plan: saved_game.game_plan,
+            settings: saved_game.settings,
+            round: saved_game.round,
+            current_player: saved_game.next_player,
+            action_points: ACTION_POINTS_PER_TURN,
+            battles: Vec::new(),
+            revolts: Vec::new(),
+            victor: None,
+            rng: saved_game.rng,
+            undo_point: None,
+            undo_used: false,
+        }
+    }
+
+    /// Capture the state of the game, so that it can be saved
+    ///
+    /// The saved game continues at the start of the current player's turn,
+    /// so it should be captured before the player's first action.
+    pub fn save_point(&self) -> SavedGame {
+        SavedGame {
+            players: self.players.clone(),
+            game_plan: self.game_plan.clone(),
+            settings: self.settings.clone(),
+            round: self.round,
+            next_player: self.current_player,
+            rng: self.rng.clone(),
+        }
+    }
+
+    /// Capture the whole state of the game
+    ///
+    /// The undo point is captured too, so the last action can still be taken back
+    /// after the snapshot is restored.
+    ///
+    /// Returns
+    /// ---
+    /// - snapshot sharing nothing with the engine, later actions do not change it
+    pub fn snapshot(&self) -> Snapshot {
+        self.capture(self.undo_point.clone())
+    }
+
+    // capture the state with the given undo point, undo points themselves leave it out,
+    // so that the snapshots nest at most once
+    fn capture(&self, undo_point: Option<Snapshot>) -> Snapshot {
+        Snapshot {
+            engine: Box::new(GameEngine {
+                players: self.players.clone(),
+                game_plan: self.game_plan.clone(),
+                settings: self.settings.clone(),
+                round: self.round,
+                current_player: self.current_player,
+                action_points: self.action_points,
+                battles: self.battles.clone(),
+                revolts: self.revolts.clone(),
+                victor: self.victor.clone(),
+                rng: self.rng.clone(),
+                undo_point,
+                undo_used: self.undo_used,
+            }),
+        }
+    }
+
+    /// Return the game to a captured state
+    ///
+    /// Params
+    /// ---
+    /// - snapshot: the state to return to, clone it first to restore it again later
+    pub fn restore(&mut self, snapshot: Snapshot) {
+        *self = *snapshot.engine;
+    }
+
+    /// Round which is currently played
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// Player whose turn it is
+    pub fn current_player(&self) -> &Player {
+        &self.players[self.current_player]
+    }
+
+    /// Player whose turn it is along with the battlefield, for the changes made outside
+    /// the rules of the game, f.e. army templates, names of the fields or a bot taking over
+    pub fn current_player_and_plan_mut(&mut self) -> (&mut Player, &mut GamePlan) {
+        (&mut self.players[self.current_player], &mut self.game_plan)
+    }
+
+    /// Action points the current player has left in their turn
+    pub fn action_points(&self) -> Quantity {
+        self.action_points
+    }
+
+    /// Battles fought on the contested fields at the end of the last round
+    pub fn battles(&self) -> &[BattleReport] {
+        &self.battles
+    }
+
+    /// Fields which revolted against overextended players at the end of the last round
+    pub fn revolts(&self) -> &[Revolt] {
+        &self.revolts
+    }
+
+    /// All players of the game
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    /// Battlefield of the game
+    pub fn game_plan(&self) -> &GamePlan {
+        &self.game_plan
+    }
+
+    /// Settings of the game
+    pub fn settings(&self) -> &GameSettings {
+        &self.settings
+    }
+
+    /// Player who has fulfilled the victory condition, ending the game before its last round
+    pub fn victor(&self) -> Option<&str> {
+        self.victor.as_deref()
+    }
+
+    /// Check whether all the rounds have been played, or somebody has already won
+    pub fn is_over(&self) -> bool {
+        self.round > self.settings.rounds || self.victor.is_some()
+    }
+
+    /// Start the turn of the current player
+    ///
+    /// Scouted fields fade back into the fog, bases of the player produce new peasants,
+    /// farms yield crops and the army is paid, so it should be called once before the action of every turn.
+    ///
+    /// Returns
+    /// ---
+    /// - Some(String) describing what happened in player's kingdom
+    /// - None: if nothing happened
+    pub fn start_turn(&mut self) -> Option<String> {
+        self.action_points = ACTION_POINTS_PER_TURN;
+
+        let player = &mut self.players[self.current_player];
+        player.age_scouting();
+        if self.settings.fog_of_war {
+            player.remember_visible(&self.game_plan, self.round);
+        }
+        let news: Vec<String> = [
+            player.grow_population(),
+            player.collect_farm_income(),
+            player.pay_upkeep(&mut self.game_plan),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        match news.is_empty() {
+            true => None,
+            false => Some(news.join("\n")),
+        }
+    }
+
+    /// Choose the action of the current player, if it is controlled by the computer
+    ///
+    /// Returns
+    /// ---
+    /// - Some(action): action chosen by the bot's strategy,
+    ///   harvesting if the strategy exceeds the bot time limit,
+    ///   or ending the turn if the action does not fit into the remaining action points
+    /// - None: if the current player is a human
+    pub fn bot_action(&self) -> Option<Actions> {
+        let player = self.current_player();
+        let strategy = player.bot()?;
+
+        // offers made to the bot are answered before anything else
+        if let Some(offer) = self
+            .players
+            .iter()
+            .flat_map(|other| other.offers())
+            .find(|offer| offer.to == player.nick)
+        {
+            return Some(Actions::AnswerTrade {
+                from: offer.from.clone(),
+                accept: offer.favorable_for(player),
+            });
+        }
+
+        // bots only know what they can see
+        let visible_plan = match self.settings.fog_of_war {
+            true => player.visible_plan(&self.game_plan),
+            false => self.game_plan.clone(),
+        };
+
+        let time_limit = Duration::from_millis(self.settings.bot_time_limit);
+
+        let action = strategy
+            .choose_action_within(player, &visible_plan, self.round, time_limit)
+            .unwrap_or(Actions::Harvest);
+
+        match action.action_points() > self.action_points {
+            true => Some(Actions::EndTurn),
+            false => Some(action),
+        }
+    }
+
+    /// Perform an action of the current player, spending their action points
+    ///
+    /// The turn is passed to the next player when the action points run out,
+    /// or when the player ends the turn. Failed actions do not cost any points.
+    /// The last action of a turn can be taken back with 'Undo', once per turn.
+    ///
+    /// Params
+    /// ---
+    /// - action: action of the current player
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) describing the result of the action
+    /// - Err(String) if the action cannot be performed
+    pub fn apply(&mut self, action: Actions) -> Result<String, String> {
+        if self.is_over() {
+            return Err(String::from(
+                "The game is over, no more actions can be taken.",
+            ));
+        }
+
+        // quitting and saving are up to the frontend
+        if matches!(action, Actions::Quit | Actions::Save(_)) {
+            return Err(format!("'{}' is not an action of a game round.", action));
+        }
+
+        if action == Actions::Undo {
+            return self.undo();
+        }
+
+        if action == Actions::EndTurn {
+            self.next_turn();
+            return Ok(String::from("The turn has been ended."));
+        }
+
+        // the answer completes the trade on the side of both players, it cannot be taken back
+        if let Actions::AnswerTrade { from, accept } = &action {
+            self.undo_point = None;
+            let nick = self.current_player().nick.clone();
+            return answer_offer(&mut self.players, from, &nick, *accept);
+        }
+
+        if let Actions::Trade { to, .. } = &action {
+            if !self.players.iter().any(|player| player.nick == *to) {
+                return Err(format!(
+                    "║{:^78}║",
+                    format!("There is no player called {}.", to),
+                ));
+            }
+        }
+
+        check_arguments(&action)?;
+        self.settings.validate_action(&action, self.round)?;
+        check_action_points(&action, self.action_points)?;
+
+        let cost = action.action_points();
+        let snapshot = self.capture(None);
+        let player = &mut self.players[self.current_player];
+        let notification = player.perform_action(action, &mut self.game_plan)?;
+
+        // scouting and conquering reveal fields
+        if self.settings.fog_of_war {
+            player.remember_visible(&self.game_plan, self.round);
+        }
+
+        self.undo_point = Some(snapshot);
+        self.action_points -= cost;
+        if self.action_points == 0 {
+            self.next_turn();
+        }
+
+        Ok(notification)
+    }
+
+    /// Compute the winner of the game according to the current state of the battlefield
+    ///
+    /// Returns
+    /// ---
+    /// - Some(nick): if a player has fulfilled the victory condition,
+    ///   or a single player leads the most fields
+    /// - None: if nobody leads a field or the best players are tied
+    pub fn winner(&self) -> Option<String> {
+        if self.victor.is_some() {
+            return self.victor.clone();
+        }
+
+        self.game_plan.sole_leader()
+    }
+
+    /// Take back the last action of the current turn, once per turn
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) confirming the action has been taken back
+    /// - Err(String) if there is no action to take back, or one has been taken back already
+    fn undo(&mut self) -> Result<String, String> {
+        if self.undo_used {
+            return Err(String::from("Only one action can be taken back per turn."));
+        }
+
+        let snapshot = self
+            .undo_point
+            .take()
+            .ok_or_else(|| String::from("There is no action to take back."))?;
+        self.restore(snapshot);
+        self.undo_used = true;
+
+        Ok(String::from("The last action has been taken back."))
+    }
+
+    /// Pass the turn to the next player, starting a new round after the last one
+    ///
+    /// Battles on the contested fields are fought before a new round starts.
+    fn next_turn(&mut self) {
+        self.undo_point = None;
+        self.undo_used = false;
+        self.action_points = ACTION_POINTS_PER_TURN;
+        self.current_player += 1;
+
+        if self.current_player == self.players.len() {
+            self.game_plan.name_regiments(&mut self.rng);
+            self.battles = self.game_plan.resolve_battles();
+            award_spoils(&mut self.players, &self.battles);
+            self.revolts = resolve_revolts(&self.players, &mut self.game_plan, &mut self.rng);
+            self.victor = self
+                .settings
+                .victory
+                .round_winner(&self.players, &self.game_plan);
+            self.current_player = 0;
+            self.round += 1;
+        }
+    }
+}
+
+/// Check whether the quantities of an action are positive
+///
+/// The terminal prompts ask for positive quantities only, other frontends
+/// send the actions as they are, so they are checked here once more.
+///
+/// Params
+/// ---
+/// - action: action the player wants to perform
+///
+/// Returns
+/// ---
+/// - Ok(()) if every quantity of the action is positive
+/// - Err(String) with an explanation otherwise
+fn check_arguments(action: &Actions) -> Result<(), String> {
+    let quantity = match action {
+        Actions::Build(_, quantity)
+        | Actions::Train(_, quantity)
+        | Actions::Recruit(_, quantity)
+        | Actions::Conquer(_, _, _, quantity)
+        | Actions::Recall(_, _, _, quantity)
+        | Actions::BuyItem(_, quantity)
+        | Actions::Exchange(_, quantity) => *quantity,
+        Actions::TrainArmy(template) => {
+            if template.units.is_empty() {
+                return Err(format!(
+                    "║{:^78}║",
+                    format!("Army '{}' has no units to train.", template.name),
+                ));
+            }
+
+            template
+                .units
+                .iter()
+                .map(|(_, quantity)| *quantity)
+                .min()
+                .unwrap_or_default()
+        }
+        _ => return Ok(()),
+    };
+
+    match quantity {
+        quantity if quantity <= 0 => Err(format!(
+            "║{:^78}║",
+            format!("Quantities have to be positive, {} is not.", quantity),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Check whether the player has enough action points left for an action
+///
+/// Params
+/// ---
+/// - action: action the player wants to perform
+/// - action_points: action points the player has left in this turn
+///
+/// Returns
+/// ---
+/// - Ok(()) if the action can be afforded
+/// - Err(String) with an explanation otherwise
+fn check_action_points(action: &Actions, action_points: Quantity) -> Result<(), String> {
+    match action.action_points() {
+        cost if cost > action_points => Err(format!(
+            "║{:^78}║\n║{:^78}║",
+            "You do not have enough action points left for this action.",
+            format!(
+                "{} needed, {} left. Type 'end' to end your turn.",
+                cost, action_points
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::settings::InputEnd;
+    use super::super::victory::VictoryCondition;
+    use super::*;
+
+    fn engine() -> GameEngine {
+        let players = ["Alice", "Bob"]
+            .into_iter()
+            .map(|nick| {
+                let mut player = Player::new(nick);
+                player.receive_resources((100, 100));
+                player
+            })
+            .collect();
+        let settings = GameSettings {
+            rounds: 10,
+            conquest_round: 1,
+            fog_of_war: false,
+            privacy_screen: false,
+            bot_time_limit: 1000,
+            turn_time_limit: None,
+            victory: VictoryCondition::MostFields,
+            on_input_end: InputEnd::default(),
+        };
+
+        GameEngine::new(players, GamePlan::new(2, 1), settings, GameRng::new(7))
+    }
+
+    // everything a snapshot captures, in a comparable form
+    fn state(engine: &GameEngine) -> (String, usize, usize, Quantity, bool) {
+        (
+            serde_json::to_string(&engine.save_point()).expect("the game can be serialized"),
+            engine.round,
+            engine.current_player,
+            engine.action_points,
+            engine.undo_used,
+        )
+    }
+
+    fn restored(snapshot: &Snapshot) -> GameEngine {
+        let mut engine = engine();
+        engine.restore(snapshot.clone());
+        engine
+    }
+
+    #[test]
+    fn later_actions_leave_the_snapshot_unchanged() {
+        let mut engine = engine();
+        let before = state(&engine);
+        let snapshot = engine.snapshot();
+
+        engine
+            .apply(Actions::Harvest)
+            .expect("harvesting is allowed");
+        engine
+            .apply(Actions::EndTurn)
+            .expect("the turn can be ended");
+        let (player, game_plan) = engine.current_player_and_plan_mut();
+        player.receive_resources((50, 50));
+        *game_plan = GamePlan::new(3, 3);
+        assert_ne!(state(&engine), before);
+
+        assert_eq!(state(&restored(&snapshot)), before);
+    }
+
+    #[test]
+    fn a_snapshot_is_restored_any_number_of_times() {
+        let mut engine = engine();
+        let before = state(&engine);
+        let snapshot = engine.snapshot();
+
+        for _ in 0..3 {
+            engine
+                .apply(Actions::Harvest)
+                .expect("harvesting is allowed");
+            engine.restore(snapshot.clone());
+            assert_eq!(state(&engine), before);
+        }
+    }
+
+    #[test]
+    fn the_last_action_is_taken_back_after_a_restore() {
+        let mut engine = engine();
+        let before = state(&engine);
+
+        engine
+            .apply(Actions::Harvest)
+            .expect("harvesting is allowed");
+        let snapshot = engine.snapshot();
+        engine
+            .current_player_and_plan_mut()
+            .0
+            .receive_resources((50, 50));
+        engine.restore(snapshot);
+
+        engine
+            .apply(Actions::Undo)
+            .expect("the harvest can be taken back");
+        assert_eq!(
+            state(&engine),
+            (before.0, 1, 0, ACTION_POINTS_PER_TURN, true)
+        );
+    }
+}
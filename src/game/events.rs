@@ -0,0 +1,87 @@
+// Observers can be registered to receive events of the game,
+// f.e. to comment on the game or to collect statistics.
+
+use super::notifications::print_commentary;
+use super::types::{actions::Actions, board::GamePlan, player::Player};
+
+/// Events that happen during the game
+pub enum GameEvent<'a> {
+    /// A new round has started
+    RoundStarted { round: usize, rounds_total: usize },
+    /// A player has successfully performed an action
+    ActionPerformed {
+        player: &'a Player,
+        action: &'a Actions,
+        game_plan: &'a GamePlan,
+    },
+    /// A player has taken back their last action, the game is back in the state before it
+    ActionUndone {
+        player: &'a Player,
+        action: &'a Actions,
+        game_plan: &'a GamePlan,
+    },
+}
+
+/// Observer of the game, which can react to game events
+///
+/// It can return a commentary line, which is printed into the game output.
+pub trait Observer {
+    /// React to an event of the game
+    ///
+    /// Params
+    /// ---
+    /// - event: event which happened in the game
+    ///
+    /// Returns
+    /// ---
+    /// - Some(line): commentary line to print
+    /// - None: if the observer has nothing to say
+    fn notify(&mut self, event: &GameEvent) -> Option<String>;
+
+    /// Hand over the messages queued for a player
+    ///
+    /// Params
+    /// ---
+    /// - nick: nick of the player whose turn starts
+    ///
+    /// Returns
+    /// ---
+    /// - messages for the player, empty for observers which do not send any
+    fn take_messages(&mut self, _nick: &str) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Send an event to all observers and print their commentary
+///
+/// Params
+/// ---
+/// - observers: registered observers of the game
+/// - event: event which happened in the game
+pub fn broadcast(observers: &mut [Box<dyn Observer>], event: GameEvent) {
+    let commentary: Vec<String> = observers
+        .iter_mut()
+        .filter_map(|observer| observer.notify(&event))
+        .collect();
+
+    if !commentary.is_empty() {
+        print_commentary(&commentary);
+    }
+}
+
+/// Collect the messages of all observers queued for a player
+///
+/// Params
+/// ---
+/// - observers: registered observers of the game
+/// - nick: nick of the player whose turn starts
+///
+/// Returns
+/// ---
+/// - messages for the player
+pub fn collect_messages(observers: &mut [Box<dyn Observer>], nick: &str) -> Vec<String> {
+    observers
+        .iter_mut()
+        .flat_map(|observer| observer.take_messages(nick))
+        .collect()
+}
@@ -0,0 +1,236 @@
+// Hall of fame of the games played on this computer.
+// At the end of every game, its statistics are searched for feats worth remembering
+// (the fastest win, the largest army, the biggest comeback), a feat beating
+// the one in the hall of fame takes its place. 'wartycoon hall-of-fame' shows them.
+
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+
+use super::stats::GameStats;
+use super::types::{
+    board::GamePlan,
+    plurals::{format_number, plural_suffix},
+};
+
+// file the hall of fame is kept in
+pub const DEFAULT_HALL_OF_FAME_FILE: &str = "wartycoon_hall_of_fame.json";
+
+/// A feat of a player in one game
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Feat {
+    /// nick of the player who achieved it
+    pub nick: String,
+    /// how big the feat was, f.e. the number of rounds of the fastest win
+    pub value: usize,
+    /// nicks of the other players of the game
+    pub opponents: Vec<String>,
+    /// (width, height) of the battlefield
+    pub battlefield: (usize, usize),
+}
+
+impl Feat {
+    /// Describe the feat, f.e. 'Alice, 4 rounds (3 x 2 battlefield, against Bob)'
+    ///
+    /// Params
+    /// ---
+    /// - unit: what the value of the feat counts, f.e. 'round'
+    fn describe(&self, unit: &str) -> String {
+        format!(
+            "{}, {} {}{} ({} x {} battlefield, against {})",
+            self.nick,
+            format_number(self.value),
+            unit,
+            plural_suffix(self.value, "s"),
+            self.battlefield.0,
+            self.battlefield.1,
+            self.opponents.join(", ")
+        )
+    }
+}
+
+/// The most notable feats of all the games played
+#[derive(Default, Serialize, Deserialize)]
+pub struct HallOfFame {
+    /// the fewest rounds a game was won in
+    #[serde(default)]
+    fastest_win: Option<Feat>,
+    /// the most units a player owned at the end of a round
+    #[serde(default)]
+    largest_army: Option<Feat>,
+    /// the most fields the winner of a game was behind the leader
+    #[serde(default)]
+    biggest_comeback: Option<Feat>,
+}
+
+impl HallOfFame {
+    /// Load the hall of fame, a missing file means that no game has been played yet
+    ///
+    /// Params
+    /// ---
+    /// - path: path of the hall of fame file
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(HallOfFame) with the notable feats
+    /// - Err(String) if the file exists but could not be read
+    pub fn load(path: &str) -> Result<HallOfFame, String> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(HallOfFame::default())
+            }
+            Err(error) => return Err(format!("Cannot read {}: {}", path, error)),
+        };
+
+        serde_json::from_str(&contents)
+            .map_err(|error| format!("{} is not a valid hall of fame: {}", path, error))
+    }
+
+    /// Write the hall of fame into a file
+    ///
+    /// Params
+    /// ---
+    /// - path: path of the hall of fame file
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) containing a confirmation message
+    /// - Err(String) if the file could not be written
+    pub fn save(&self, path: &str) -> Result<String, String> {
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|error| format!("Cannot serialize the hall of fame: {}", error))?;
+
+        fs::write(path, contents).map_err(|error| format!("Cannot write {}: {}", path, error))?;
+
+        Ok(format!("The hall of fame has been updated in {}.", path))
+    }
+
+    /// Search a finished game for feats beating the ones in the hall of fame
+    ///
+    /// Params
+    /// ---
+    /// - stats: statistics of every round of the game
+    /// - game_plan: battlefield of the game
+    /// - winner: nick of the winner, None for a draw
+    ///
+    /// Returns
+    /// ---
+    /// - announcements of the feats which made it into the hall of fame
+    pub fn consider(
+        &mut self,
+        stats: &GameStats,
+        game_plan: &GamePlan,
+        winner: Option<&str>,
+    ) -> Vec<String> {
+        let records = stats.records();
+        let Some(last) = records.last() else {
+            return Vec::new();
+        };
+
+        let mut nicks: Vec<&str> = Vec::new();
+        for record in records {
+            if !nicks.contains(&record.nick.as_str()) {
+                nicks.push(&record.nick);
+            }
+        }
+
+        let feat = |nick: &str, value: usize| Feat {
+            nick: nick.into(),
+            value,
+            opponents: nicks
+                .iter()
+                .filter(|other| **other != nick)
+                .map(|other| other.to_string())
+                .collect(),
+            battlefield: (game_plan.width(), game_plan.height()),
+        };
+
+        let mut announcements = Vec::new();
+
+        if let Some(winner) = winner {
+            // the last recorded round is the one the game ended in
+            let rounds = last.round;
+            if self
+                .fastest_win
+                .as_ref()
+                .is_none_or(|best| rounds < best.value)
+            {
+                let fastest_win = feat(winner, rounds);
+                announcements.push(format!("Fastest win: {}", fastest_win.describe("round")));
+                self.fastest_win = Some(fastest_win);
+            }
+
+            // how far behind the leader the winner was at the end of a round
+            let deficit = records
+                .iter()
+                .filter(|record| record.nick == winner)
+                .map(|own| {
+                    let leading = records
+                        .iter()
+                        .filter(|record| record.round == own.round && record.nick != winner)
+                        .map(|record| record.fields_held)
+                        .max()
+                        .unwrap_or(0);
+                    leading.saturating_sub(own.fields_held)
+                })
+                .max()
+                .unwrap_or(0);
+            if deficit > 0
+                && self
+                    .biggest_comeback
+                    .as_ref()
+                    .is_none_or(|best| deficit > best.value)
+            {
+                let biggest_comeback = feat(winner, deficit);
+                announcements.push(format!(
+                    "Biggest comeback: {}",
+                    biggest_comeback.describe("field")
+                ));
+                self.biggest_comeback = Some(biggest_comeback);
+            }
+        }
+
+        // the first player to field the army gets the credit
+        if let Some(record) = records
+            .iter()
+            .rev()
+            .max_by_key(|record| record.army)
+            .filter(|record| record.army > 0)
+        {
+            let army = record.army as usize;
+            if self
+                .largest_army
+                .as_ref()
+                .is_none_or(|best| army > best.value)
+            {
+                let largest_army = feat(&record.nick, army);
+                announcements.push(format!("Largest army: {}", largest_army.describe("unit")));
+                self.largest_army = Some(largest_army);
+            }
+        }
+
+        announcements
+    }
+
+    /// Describe all the feats in the hall of fame
+    ///
+    /// Returns
+    /// ---
+    /// - one line per kind of feat
+    pub fn report(&self) -> Vec<String> {
+        let describe = |feat: &Option<Feat>, unit: &str| match feat {
+            Some(feat) => feat.describe(unit),
+            None => String::from("nobody yet"),
+        };
+
+        vec![
+            format!("Fastest win: {}", describe(&self.fastest_win, "round")),
+            format!("Largest army: {}", describe(&self.largest_army, "unit")),
+            format!(
+                "Biggest comeback: {}",
+                describe(&self.biggest_comeback, "field")
+            ),
+        ]
+    }
+}
@@ -0,0 +1,222 @@
+// Log of the actions performed during the game, searched with the 'history' command.
+// The log keeps the actions themselves rather than their descriptions, so they can be
+// filtered by their command, round and player, f.e. 'history conquer round 5-8 player Bob'.
+
+use std::sync::Mutex;
+
+use super::commands::{find_command, Command};
+use super::events::{GameEvent, Observer};
+use super::types::actions::Actions;
+
+// actions performed since the game was started or loaded
+static HISTORY: Mutex<Vec<HistoryEntry>> = Mutex::new(Vec::new());
+
+// at most this many of the matching actions are listed, the latest ones
+const HISTORY_ROWS: usize = 20;
+
+/// One performed action
+#[derive(Clone)]
+pub struct HistoryEntry {
+    pub round: usize,
+    pub player: String,
+    pub action: Actions,
+}
+
+/// Observer writing the performed actions into the log
+pub struct HistoryRecorder {
+    round: usize,
+}
+
+impl HistoryRecorder {
+    /// Start a new log, the actions of the previous game are forgotten
+    ///
+    /// Params
+    /// ---
+    /// - round: round the game starts or continues in
+    pub fn new(round: usize) -> Self {
+        update_history(|history| history.clear());
+        Self { round }
+    }
+}
+
+impl Observer for HistoryRecorder {
+    fn notify(&mut self, event: &GameEvent) -> Option<String> {
+        match event {
+            GameEvent::RoundStarted { round, .. } => self.round = *round,
+            GameEvent::ActionPerformed { player, action, .. } => {
+                let entry = HistoryEntry {
+                    round: self.round,
+                    player: player.nick.clone(),
+                    action: (*action).clone(),
+                };
+                update_history(|history| history.push(entry));
+            }
+            GameEvent::ActionUndone { .. } => update_history(|history| {
+                history.pop();
+            }),
+        }
+
+        None
+    }
+}
+
+/// Conditions the listed actions have to meet, all of them at once
+#[derive(Default)]
+pub struct HistoryFilter {
+    command: Option<Command>,
+    // first and last round, both included
+    rounds: Option<(usize, usize)>,
+    player: Option<String>,
+    // words the description of the action has to contain
+    words: Vec<String>,
+}
+
+impl HistoryFilter {
+    /// Parse the arguments of the 'history' command
+    ///
+    /// Params
+    /// ---
+    /// - arguments: f.e. ["conquer", "round", "5-8", "player", "Bob"],
+    ///   words which are neither commands nor filters are searched for in the actions
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(filter): the filter described by the arguments
+    /// - Err(String) describing the malformed filter
+    pub fn parse(arguments: &[&str]) -> Result<HistoryFilter, String> {
+        let mut filter = HistoryFilter::default();
+        let mut words = arguments.iter();
+
+        while let Some(word) = words.next() {
+            match word.to_lowercase().as_str() {
+                "round" | "rounds" => {
+                    let rounds = words.next().and_then(|rounds| parse_rounds(rounds));
+                    filter.rounds = Some(rounds.ok_or(
+                        "Please put a round or a range of rounds after 'round', f.e. 'round 5-8'.",
+                    )?);
+                }
+                "player" => {
+                    let nick = words
+                        .next()
+                        .ok_or("Please put the nick of a player after 'player'.")?;
+                    filter.player = Some(nick.to_lowercase());
+                }
+                // numbers are searched for, f.e. quantities, rather than taken for commands
+                _ => match (find_command(word), filter.command) {
+                    (Some(command), None) if word.parse::<usize>().is_err() => {
+                        filter.command = Some(command)
+                    }
+                    _ => filter.words.push(word.to_lowercase()),
+                },
+            }
+        }
+
+        Ok(filter)
+    }
+
+    /// Check whether an action of the log meets the filter
+    ///
+    /// Params
+    /// ---
+    /// - entry: the logged action
+    pub fn matches(&self, entry: &HistoryEntry) -> bool {
+        let description = entry.action.to_string().to_lowercase();
+
+        self.command
+            .is_none_or(|command| command_of(&entry.action) == command)
+            && self
+                .rounds
+                .is_none_or(|(first, last)| (first..=last).contains(&entry.round))
+            && self
+                .player
+                .as_ref()
+                .is_none_or(|nick| entry.player.to_lowercase() == *nick)
+            && self.words.iter().all(|word| description.contains(word))
+    }
+}
+
+/// Find the latest actions of the log meeting a filter
+///
+/// Params
+/// ---
+/// - filter: conditions of the search
+/// - nick: only the actions of this player are searched, f.e. with the fog of war on
+///
+/// Returns
+/// ---
+/// - (entries, left_out): the latest matching actions, oldest first,
+///   and how many older ones did not fit into the listing
+pub fn search_history(filter: &HistoryFilter, nick: Option<&str>) -> (Vec<HistoryEntry>, usize) {
+    let history = match HISTORY.lock() {
+        Ok(history) => history,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    let matching: Vec<HistoryEntry> = history
+        .iter()
+        .filter(|entry| nick.is_none_or(|nick| entry.player == nick))
+        .filter(|entry| filter.matches(entry))
+        .cloned()
+        .collect();
+    let left_out = matching.len().saturating_sub(HISTORY_ROWS);
+
+    (matching[left_out..].to_vec(), left_out)
+}
+
+/// Parse a round or a range of rounds, f.e. '5' or '5-8'
+///
+/// Params
+/// ---
+/// - rounds: the typed rounds
+///
+/// Returns
+/// ---
+/// - Some((first, last)): if the rounds are valid
+/// - None: otherwise
+fn parse_rounds(rounds: &str) -> Option<(usize, usize)> {
+    let (first, last) = rounds.split_once('-').unwrap_or((rounds, rounds));
+    let (first, last) = (first.trim().parse().ok()?, last.trim().parse().ok()?);
+
+    match first <= last {
+        true => Some((first, last)),
+        false => None,
+    }
+}
+
+/// Return the command the player types to perform an action
+///
+/// Params
+/// ---
+/// - action: the performed action
+fn command_of(action: &Actions) -> Command {
+    match action {
+        Actions::Build(..) => Command::Build,
+        Actions::Upgrade(_) => Command::Upgrade,
+        Actions::Harvest => Command::Harvest,
+        Actions::Train(..) => Command::Train,
+        Actions::Recruit(..) => Command::Recruit,
+        Actions::TrainArmy(_) => Command::Army,
+        Actions::Conquer(..) => Command::Conquer,
+        Actions::Stance(..) => Command::Stance,
+        Actions::Scout(..) => Command::Scout,
+        Actions::Recall(..) => Command::Recall,
+        Actions::BuyItem(..) => Command::Buy,
+        Actions::UseItem(..) => Command::Use,
+        Actions::Fortify(..) => Command::Fortify,
+        Actions::Trade { .. } | Actions::AnswerTrade { .. } => Command::Trade,
+        Actions::Exchange(..) => Command::Exchange,
+        Actions::Pass => Command::Pass,
+        Actions::EndTurn => Command::End,
+        Actions::Undo => Command::Undo,
+        Actions::Quit => Command::Quit,
+        Actions::Save(_) => Command::Save,
+    }
+}
+
+// change the log, even if a panicking thread left it poisoned
+fn update_history(change: impl FnOnce(&mut Vec<HistoryEntry>)) {
+    match HISTORY.lock() {
+        Ok(mut history) => change(&mut history),
+        Err(poisoned) => change(&mut poisoned.into_inner()),
+    }
+}
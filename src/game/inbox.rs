@@ -0,0 +1,88 @@
+// Inbox of messages for the players, fed by the game events.
+// Players learn what happened on their fields at the start of their next turn.
+
+use std::collections::HashMap;
+
+use super::events::{GameEvent, Observer};
+use super::types::{actions::Actions, plurals::plural_suffix};
+
+/// Messages waiting for each player (by nick)
+#[derive(Default)]
+pub struct Inbox {
+    messages: HashMap<String, Vec<String>>,
+    // players who got a message about the last action, it is withdrawn if the action is undone
+    last_recipients: Vec<String>,
+}
+
+impl Inbox {
+    /// Create an empty inbox
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Observer for Inbox {
+    fn notify(&mut self, event: &GameEvent) -> Option<String> {
+        match event {
+            // only players with units on a field can see who else comes there
+            GameEvent::ActionPerformed {
+                player,
+                action: Actions::Conquer(x, y, unit_type, quantity),
+                game_plan,
+            } => {
+                self.last_recipients.clear();
+                let field = game_plan.field(*x, *y)?;
+                let message = format!(
+                    "{} sent {} {}{} to field {}!",
+                    player.nick,
+                    quantity,
+                    unit_type,
+                    plural_suffix(*quantity, "S"),
+                    field.label()
+                );
+
+                for occupant in field.occupants() {
+                    if occupant != player.nick {
+                        self.messages
+                            .entry(occupant.clone())
+                            .or_default()
+                            .push(message.clone());
+                        self.last_recipients.push(occupant);
+                    }
+                }
+            }
+            // the offering player learns the answer at the start of their next turn
+            GameEvent::ActionPerformed {
+                player,
+                action: Actions::AnswerTrade { from, accept },
+                ..
+            } => {
+                self.last_recipients.clear();
+                let answer = match accept {
+                    true => "accepted",
+                    false => "declined",
+                };
+                self.messages
+                    .entry(from.clone())
+                    .or_default()
+                    .push(format!("{} {} your trade offer.", player.nick, answer));
+            }
+            GameEvent::ActionPerformed { .. } => self.last_recipients.clear(),
+            GameEvent::ActionUndone { .. } => {
+                for recipient in self.last_recipients.drain(..) {
+                    if let Some(messages) = self.messages.get_mut(&recipient) {
+                        messages.pop();
+                    }
+                }
+            }
+            GameEvent::RoundStarted { .. } => {}
+        }
+
+        // the messages are private, nothing is commented publicly
+        None
+    }
+
+    fn take_messages(&mut self, nick: &str) -> Vec<String> {
+        self.messages.remove(nick).unwrap_or_default()
+    }
+}
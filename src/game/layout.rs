@@ -0,0 +1,123 @@
+// Responsive layout of the game output.
+// Tables of the game are 80 characters wide, on narrower terminals
+// they would break, so a plain vertical format is used instead.
+
+use std::env;
+use std::fs::File;
+use std::process::Command;
+use std::sync::OnceLock;
+
+// width of game tables, including borders
+const TABLE_WIDTH: usize = 80;
+
+// width of the terminal, detected once at the first use
+static TERMINAL_WIDTH: OnceLock<Option<usize>> = OnceLock::new();
+
+/// Obtain the width of the terminal
+///
+/// The width is detected at the first call and reused afterwards,
+/// so that the terminal is not asked before every printed table.
+///
+/// Returns
+/// ---
+/// - Some(width): number of columns of the terminal
+/// - None: if the width could not be detected
+pub fn terminal_width() -> Option<usize> {
+    *TERMINAL_WIDTH.get_or_init(detect_terminal_width)
+}
+
+// the `COLUMNS` environment variable has the priority, otherwise the terminal is asked via `stty`
+fn detect_terminal_width() -> Option<usize> {
+    if let Some(columns) = env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.trim().parse().ok())
+    {
+        return Some(columns);
+    }
+
+    // 'stty size' prints "rows columns" of the terminal connected to its stdin
+    let output = Command::new("stty")
+        .arg("size")
+        .stdin(File::open("/dev/tty").ok()?)
+        .output()
+        .ok()?;
+
+    String::from_utf8(output.stdout)
+        .ok()?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()
+}
+
+/// Check whether the terminal is too narrow to display game tables
+pub fn is_narrow_terminal() -> bool {
+    terminal_width().is_some_and(|width| width < TABLE_WIDTH)
+}
+
+/// Remove table borders from lines of a text
+///
+/// Params
+/// ---
+/// - text: text with lines formatted as table rows
+///
+/// Returns
+/// ---
+/// - text with plain lines only
+pub fn plain_lines(text: &str) -> String {
+    text.lines()
+        .map(|line| line.trim_matches(|c: char| c == '║' || c == '│' || c.is_whitespace()))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+/// Frame a text into a double lined table with a title
+///
+/// Params
+/// ---
+/// - title: title of the table
+/// - body: rows of the table, already formatted as '║{:^78}║'
+///
+/// Returns
+/// ---
+/// - formatted table, or a plain text on narrow terminals
+pub fn framed(title: &str, body: &str) -> String {
+    if is_narrow_terminal() {
+        return format!("== {} ==\n{}\n", title, plain_lines(body));
+    }
+
+    let line_smooth = "═".repeat(78);
+    format!(
+        "╔{}╗\n║{:^78}║\n╠{}╣\n{}\n╚{}╝\n",
+        &line_smooth, title, &line_smooth, body, &line_smooth,
+    )
+}
+
+/// Frame lines of text into a rounded single lined box
+///
+/// Params
+/// ---
+/// - lines: lines to center in the box
+///
+/// Returns
+/// ---
+/// - formatted box, or a plain text on narrow terminals
+pub fn rounded(lines: &[String]) -> String {
+    if is_narrow_terminal() {
+        return format!("-- {} --\n", lines.join("\n"));
+    }
+
+    let line = "─".repeat(78);
+    let lines: Vec<String> = lines.iter().map(|text| format!("│{:^78}│", text)).collect();
+    format!("╭{}╮\n{}\n╰{}╯\n", &line, lines.join("\n"), &line)
+}
+
+/// Return a separator line fitting into the terminal
+pub fn separator() -> String {
+    let width = terminal_width()
+        .unwrap_or(TABLE_WIDTH)
+        .min(TABLE_WIDTH)
+        .saturating_sub(2);
+    "┄".repeat(width)
+}
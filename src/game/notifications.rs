@@ -1,5 +1,22 @@
-use super::sleep_intervals::{game_sleep_second, game_sleep_two_seconds};
-use super::types::{board::GamePlan, player::Player};
+use super::colors::{paint, paint_player, Color};
+use super::commands::{describe_command, COMMANDS};
+use super::history::HistoryEntry;
+use super::layout::{framed, rounded, separator};
+use super::revolts::Revolt;
+use super::sleep_intervals::Pacing;
+use super::types::{
+    board::{BattleReport, GameField, GamePlan},
+    items::ItemType,
+    player::Player,
+    plurals::{format_number, plural_suffix},
+    rules::rules,
+    troops::{Stance, UnitType},
+    value_types::Quantity,
+};
+use super::user_input::numbered_options;
+use super::victory::VictoryCondition;
+
+use std::time::Duration;
 
 // default game prints
 const GAME_INITIAL_GREETING: &str = "Welcome to WarTycoon! An interactive command line game.\nWe hope you have a great time playing with us!\n";
@@ -12,30 +29,660 @@ const GAME_START: &str = "The game is starting! Fasten your seatbelts and prepar
 /// - player: player reference, used for displaying their name
 /// - round: used for displaying which round it is
 pub fn notify_players_turn(player: &Player, round: usize) {
-    let line = "─".repeat(78);
     println!(
-        "╭{}╮\n│{:^78}│\n╰{}╯\n\n",
-        &line,
-        format!("It's {}'s turn for round {}!", player.nick, &round),
-        &line,
+        "{}\n",
+        paint_player(
+            &rounded(&[format!("It's {}'s turn for round {}!", player.nick, &round)]),
+            &player.nick
+        )
     )
 }
 
+/// Ask the next player to take the keyboard, the previous turn has already been cleared
+///
+/// Params
+/// ---
+/// - nick: nick of the player whose turn comes
+pub fn print_privacy_screen(nick: &str) {
+    println!(
+        "{}",
+        rounded(&[
+            format!("Please pass the keyboard to {}.", nick),
+            String::from("Everybody else, please look away!"),
+        ])
+    );
+    println!(
+        "{}, press enter when you are ready to play your turn.",
+        nick
+    );
+}
+
+/// Clear the terminal, including its scrollback, so earlier output cannot be scrolled back to
+pub fn clear_screen() {
+    print!("\x1b[H\x1b[2J\x1b[3J");
+}
+
+/// Offer players to end the game early as its outcome is decided
+///
+/// Params
+/// ---
+/// - leader: nick of the projected winner
+/// - locked_fields: how many fields can no longer be contested
+/// - total_fields: how many fields there are on the battlefield
+/// - rounds_left: how many rounds remain to be played
+pub fn print_early_finish_offer(
+    leader: &str,
+    locked_fields: usize,
+    total_fields: usize,
+    rounds_left: usize,
+) {
+    let plural = plural_suffix(rounds_left, "s");
+    println!(
+        "{}",
+        rounded(&[
+            String::from("The outcome of the game has been decided!"),
+            format!(
+                "{} holds {} of {} fields, which cannot be contested",
+                leader, locked_fields, total_fields
+            ),
+            format!("in the remaining {} round{}.", rounds_left, plural),
+        ])
+    );
+    println!(
+        "Do you wish to end the game now? (type 'yes', 'y' or '1', or press enter to continue playing)"
+    );
+}
+
+/// Announce the winner of a game decided by its victory condition
+///
+/// Params
+/// ---
+/// - winner: nick of the winner
+/// - victory: the fulfilled victory condition
+pub fn print_victory(winner: &str, victory: &VictoryCondition) {
+    let achievement = match victory {
+        VictoryCondition::MostFields => String::from("has won the most fields"),
+        VictoryCondition::Domination => String::from("leads every field of the battlefield"),
+        VictoryCondition::Economic(gold) => format!("has gathered at least {} gold", gold),
+        VictoryCondition::LastStanding => String::from("is the last player with an army"),
+    };
+
+    println!(
+        "{}",
+        paint_player(
+            &rounded(&[
+                format!("{} {}", winner, achievement),
+                format!("and wins the game by {}!", victory),
+            ]),
+            winner
+        )
+    );
+}
+
+/// Offer players to play again with the same settings
+///
+/// Params
+/// ---
+/// - nicks: nicks of the players of the finished game
+pub fn print_rematch_offer(nicks: &[String]) {
+    println!(
+        "{}",
+        rounded(&[
+            String::from("Up for a rematch?"),
+            format!("{} can play again right away,", nicks.join(", ")),
+            String::from("on the same battlefield and with the same settings."),
+        ])
+    );
+    println!("Do you wish to play a rematch? (type 'yes', 'y' or '1', or press enter to quit)");
+}
+
+/// Announce the start of a campaign
+///
+/// Params
+/// ---
+/// - seed: seed of the campaign
+/// - nick: nick of the player
+/// - best_stage: the furthest stage won with the seed before, if any
+pub fn print_campaign_start(seed: u64, nick: &str, best_stage: Option<usize>) {
+    let record = match best_stage {
+        Some(stage) if stage > 0 => format!("Your best so far: stage {}.", stage),
+        _ => String::from("Nobody knows how far it leads yet."),
+    };
+
+    println!(
+        "{}",
+        rounded(&[
+            format!("{} sets out on campaign {}.", nick, seed),
+            String::from("Every won skirmish leads to a tougher one."),
+            record,
+        ])
+    );
+}
+
+/// Introduce a skirmish of a campaign
+///
+/// Params
+/// ---
+/// - stage: number of the skirmish in the campaign
+/// - description: lines describing the skirmish
+/// - pacing: how long the game pauses after the notification
+pub fn print_skirmish(stage: usize, description: &[String], pacing: Pacing) {
+    let rows: Vec<String> = description
+        .iter()
+        .map(|line| format!("║{:^78}║", line))
+        .collect();
+
+    println!(
+        "{}",
+        framed(&format!("Skirmish {}", stage), &rows.join("\n"))
+    );
+    pacing.pause();
+}
+
+/// Offer the player of a campaign the next skirmish
+///
+/// Params
+/// ---
+/// - stage: the next stage of the campaign
+pub fn print_next_skirmish_offer(stage: usize) {
+    println!(
+        "Do you wish to march on to skirmish {}? (type 'yes', 'y' or '1', or press enter to end the campaign)",
+        stage
+    );
+}
+
+/// Announce a won skirmish of a campaign
+///
+/// Params
+/// ---
+/// - stage: the won stage
+/// - core: (unit type, quantity) of the units marching on
+/// - new_best: whether no stage this far has been won with the seed before
+pub fn print_skirmish_won(stage: usize, core: &[(UnitType, Quantity)], new_best: bool) {
+    let veterans: Vec<String> = core
+        .iter()
+        .map(|(unit_type, quantity)| {
+            format!(
+                "{} {}{}",
+                quantity,
+                unit_type,
+                plural_suffix(*quantity, "S")
+            )
+        })
+        .collect();
+
+    let mut lines = vec![format!("Skirmish {} is won!", stage)];
+    lines.push(match veterans.is_empty() {
+        true => String::from("No units are left to march on."),
+        false => format!("{} march on.", veterans.join(", ")),
+    });
+    if new_best {
+        lines.push(String::from(
+            "Nobody has come this far with this seed before!",
+        ));
+    }
+
+    println!("{}", rounded(&lines));
+}
+
+/// Announce the end of a campaign
+///
+/// Params
+/// ---
+/// - stages_won: number of skirmishes won in this campaign
+/// - best_stage: the furthest stage ever won with the seed
+pub fn print_campaign_over(stages_won: usize, best_stage: usize) {
+    println!(
+        "{}",
+        rounded(&[
+            String::from("The campaign is over."),
+            format!(
+                "{} skirmish{} won, the best with this seed is stage {}.",
+                stages_won,
+                plural_suffix(stages_won, "es"),
+                best_stage
+            ),
+        ])
+    );
+}
+
+/// Introduce a lesson of the tutorial
+///
+/// Params
+/// ---
+/// - number: number of the lesson, starting with 1
+/// - total: number of all the lessons
+/// - title: what the lesson teaches
+/// - explanation: lines explaining the lesson
+/// - pacing: how long the game pauses after the notification
+pub fn print_lesson(
+    number: usize,
+    total: usize,
+    title: &str,
+    explanation: &[&str],
+    pacing: Pacing,
+) {
+    let rows: Vec<String> = explanation
+        .iter()
+        .map(|line| format!("║{:^78}║", line))
+        .collect();
+
+    println!(
+        "{}",
+        framed(
+            &format!("Lesson {} of {}: {}", number, total, title),
+            &rows.join("\n")
+        )
+    );
+    pacing.pause();
+}
+
+/// Announce the end of the tutorial
+///
+/// Params
+/// ---
+/// - won: whether the player leads the battlefield after the first battle
+pub fn print_tutorial_over(won: bool) {
+    let outcome = match won {
+        true => String::from("Your army holds the battlefield, well done!"),
+        false => String::from("The dummy holds out this time, more units would have helped."),
+    };
+
+    println!(
+        "{}",
+        rounded(&[
+            String::from("The tutorial is over."),
+            outcome,
+            String::from("Start the game without 'tutorial' to play a real opponent,"),
+            String::from("type 'help' during a turn to see all the other commands."),
+        ])
+    );
+}
+
+/// Announce the feats of the game which made it into the hall of fame
+///
+/// Params
+/// ---
+/// - feats: descriptions of the feats
+pub fn print_hall_of_fame_entries(feats: &[String]) {
+    if feats.is_empty() {
+        return;
+    }
+
+    let mut lines = vec![String::from("New in the hall of fame!")];
+    lines.extend(feats.iter().cloned());
+
+    println!("{}", rounded(&lines));
+}
+
+/// Print the scoreboard of a finished game, one row per player
+///
+/// Params
+/// ---
+/// - players: all players of the game
+/// - game_plan: battlefield at the end of the game
+pub fn print_scoreboard(players: &[Player], game_plan: &GamePlan) {
+    let standings = game_plan.standings();
+
+    let mut rows = vec![format!(
+        "║{:<18}{:>12}{:>12}{:>12}{:>12}{:>12}║",
+        " PLAYER", "WOOD HARV.", "GOLD HARV.", "TRAINED", "SENT", "FIELDS "
+    )];
+    rows.extend(players.iter().map(|player| {
+        let stats = player.stats();
+        format!(
+            "║ {:<17}{:>12}{:>12}{:>12}{:>12}{:>11} ║",
+            player.nick,
+            format_number(stats.harvested.0),
+            format_number(stats.harvested.1),
+            format_number(stats.trained),
+            format_number(stats.sent),
+            standings.get(&player.nick).copied().unwrap_or(0)
+        )
+    }));
+
+    println!("{}", framed("Scoreboard", &rows.join("\n")));
+}
+
+/// Print the fastest and the slowest thinker of the game
+///
+/// Params
+/// ---
+/// - averages: average decision time of every player, from the fastest to the slowest
+pub fn print_decision_awards(averages: &[(String, Duration)]) {
+    // awards only make sense when there is someone to compare with
+    if averages.len() < 2 {
+        return;
+    }
+
+    let (fastest, fastest_time) = &averages[0];
+    let (slowest, slowest_time) = &averages[averages.len() - 1];
+
+    println!(
+        "{}",
+        rounded(&[
+            format!(
+                "Fastest thinker: {} ({:.1} s per decision)",
+                fastest,
+                fastest_time.as_secs_f64()
+            ),
+            format!(
+                "Slowest thinker: {} ({:.1} s per decision)",
+                slowest,
+                slowest_time.as_secs_f64()
+            ),
+        ])
+    );
+}
+
+/// Print an analysis of a field from the perspective of a player
+///
+/// Compares player's committed power with the strongest opponent on the field
+/// and shows how many units of each type would be needed to take the lead.
+///
+/// Params
+/// ---
+/// - player: reference of a player that's currently playing
+/// - field: field to analyze
+pub fn print_field_analysis(player: &Player, field: &GameField) {
+    let own_power = field.power_of(&player.nick);
+    let power_needed = field.power_needed_to_lead(&player.nick);
+
+    // describe the strongest opponent
+    let opponent = match field.strongest_opponent(&player.nick) {
+        Some((opponent, power)) => {
+            format!("Strongest opponent: {} with power {:.2}", opponent, power)
+        }
+        None => String::from("No opponent units occupy this field."),
+    };
+
+    // describe what it takes to lead the field
+    let verdict = match power_needed {
+        power if power <= 0.0 => vec![String::from("You are currently leading this field!")],
+        power => {
+            let mut verdict = vec![format!(
+                "You need {:.2} more power to lead this field:",
+                power
+            )];
+            verdict.extend(UnitType::all().iter().map(|unit_type| {
+                format!(
+                    "{} more {}S (you have {} available)",
+                    unit_type.units_for_power(power),
+                    unit_type,
+                    player.send_max_units(*unit_type),
+                )
+            }));
+            verdict
+        }
+    };
+
+    let mut lines = vec![format!("Your power: {:.2}", own_power), opponent];
+    lines.extend(verdict);
+
+    if let Some(owner) = field.tower() {
+        lines.push(format!("A TOWER of {} stands on this field.", owner));
+    }
+
+    // the regiments on the field are listed by their names
+    lines.extend(field.regiments());
+
+    let body: Vec<String> = lines.iter().map(|line| format!("║{:^78}║", line)).collect();
+
+    println!(
+        "{}",
+        framed(
+            &format!("{}'s analysis of field {}:", player.nick, field.label()),
+            &body.join("\n"),
+        )
+    );
+}
+
+/// Print commentary lines of game observers
+///
+/// Params
+/// ---
+/// - commentary: lines to print
+pub fn print_commentary(commentary: &[String]) {
+    let line = separator();
+    let commentary: Vec<String> = commentary
+        .iter()
+        .map(|comment| format!(" >> {}", comment))
+        .collect();
+
+    println!("{}\n{}\n{}\n", &line, commentary.join("\n"), &line);
+}
+
+/// Print the battle log of the battles fought at the end of a round
+///
+/// Params
+/// ---
+/// - reports: reports of the battles on the contested fields
+/// - round: which round has ended
+/// - pacing: how long the game pauses after the notification
+pub fn print_battle_log(reports: &[BattleReport], round: usize, pacing: Pacing) {
+    if reports.is_empty() {
+        return;
+    }
+
+    let rows: Vec<String> = reports
+        .iter()
+        .flat_map(|report| {
+            let losses = match report.losses.is_empty() {
+                true => vec![String::from("Nobody has fallen.")],
+                false => report
+                    .losses
+                    .iter()
+                    .map(|(owner, regiment, unit_type, quantity)| {
+                        let losing = match regiment {
+                            Some(regiment) => format!("{} of {}", regiment, owner),
+                            None => owner.clone(),
+                        };
+                        format!(
+                            "{} lost {} {}{}",
+                            losing,
+                            quantity,
+                            unit_type,
+                            plural_suffix(*quantity, "S")
+                        )
+                    })
+                    .collect(),
+            };
+
+            // the victor of the battle captures war banners
+            let spoils = match (&report.victor, rules().battle_spoils) {
+                (Some(victor), spoils) if spoils > 0 => vec![format!(
+                    "{} captured {} {}{}",
+                    victor,
+                    spoils,
+                    ItemType::WarBanner,
+                    plural_suffix(spoils, "S")
+                )],
+                _ => Vec::new(),
+            };
+
+            std::iter::once(format!("Battle on field {}:", report.field))
+                .chain(losses)
+                .chain(spoils)
+        })
+        .map(|row| format!("║{:^78}║", row))
+        .collect();
+
+    println!(
+        "{}",
+        framed(&format!("Battle log of round {}", round), &rows.join("\n"))
+    );
+    pacing.pause();
+}
+
+/// Print the actions found in the history of the game
+///
+/// Params
+/// ---
+/// - entries: the found actions, oldest first
+/// - left_out: how many older actions were found, but are not listed
+pub fn print_history(entries: &[HistoryEntry], left_out: usize) {
+    let mut rows: Vec<String> = entries
+        .iter()
+        .map(|entry| format!("Round {}, {}: {}", entry.round, entry.player, entry.action))
+        .collect();
+
+    match (rows.is_empty(), left_out) {
+        (true, _) => rows.push(String::from("No actions match the search.")),
+        (false, 0) => {}
+        (false, left_out) => rows.insert(
+            0,
+            format!(
+                "... {} older action{}, narrow the search to see them",
+                format_number(left_out),
+                plural_suffix(left_out, "s")
+            ),
+        ),
+    }
+
+    let rows: Vec<String> = rows.iter().map(|row| format!("║{:^78}║", row)).collect();
+    println!("{}", framed("History of the game", &rows.join("\n")));
+}
+
+/// Print the fields which revolted at the end of a round
+///
+/// Params
+/// ---
+/// - revolts: fields which revolted
+/// - round: the round which has ended
+/// - pacing: how long the game pauses after the notification
+pub fn print_revolts(revolts: &[Revolt], round: usize, pacing: Pacing) {
+    if revolts.is_empty() {
+        return;
+    }
+
+    let rows: Vec<String> = revolts
+        .iter()
+        .flat_map(|revolt| {
+            let losses: Vec<String> = revolt
+                .losses
+                .iter()
+                .map(|(owner, unit_type, quantity)| {
+                    format!(
+                        "{} {}{} of {}",
+                        quantity,
+                        unit_type,
+                        plural_suffix(*quantity, "S"),
+                        owner
+                    )
+                })
+                .collect();
+
+            [
+                format!("Field {} revolted against {}!", revolt.field, revolt.player),
+                format!("The rebels drove out {}.", losses.join(", ")),
+            ]
+        })
+        .map(|row| format!("║{:^78}║", row))
+        .collect();
+
+    println!(
+        "{}",
+        framed(&format!("Revolts of round {}", round), &rows.join("\n"))
+    );
+    pacing.pause();
+}
+
+/// Print the rules of the game loaded from a configuration file
+///
+/// Params
+/// ---
+/// - path: path of the configuration file
+/// - rules: lines describing the active rules
+/// - pacing: how long the game pauses after the notification
+pub fn print_active_rules(path: &str, rules: &[String], pacing: Pacing) {
+    let rows: Vec<String> = rules.iter().map(|rule| format!("║{:^78}║", rule)).collect();
+
+    println!(
+        "{}",
+        framed(&format!("Rules loaded from {}", path), &rows.join("\n"))
+    );
+    pacing.pause();
+}
+
+/// Announce that fields can be conquered from now on
+///
+/// Params
+/// ---
+/// - pacing: how long the game pauses after the notification
+pub fn print_conquest_phase(pacing: Pacing) {
+    println!(
+        "{}",
+        rounded(&[
+            String::from("The exploration phase is over!"),
+            String::from("From this round on, fields can be conquered."),
+        ])
+    );
+    pacing.pause();
+}
+
 /// Notify user the game has started
-pub fn print_game_start() {
+///
+/// Params
+/// ---
+/// - pacing: how long the game pauses after the notification
+pub fn print_game_start(pacing: Pacing) {
     println!("{}", GAME_START);
-    game_sleep_second();
+    pacing.pause();
+}
+
+/// Print the seed of the game, so that the game can be played the same way again
+///
+/// Params
+/// ---
+/// - seed: seed of the game's generator
+pub fn print_seed(seed: u64) {
+    println!(
+        "The seed of this game is {}, play with '--seed {}' to get the same luck.\n",
+        seed, seed
+    );
 }
 
 /// Greet user before the start of the game
-pub fn print_greeting() {
+///
+/// Params
+/// ---
+/// - pacing: how long the game pauses after the notification
+pub fn print_greeting(pacing: Pacing) {
     println!("{}", GAME_INITIAL_GREETING);
-    game_sleep_second();
+    pacing.pause();
 }
 
 /// Print help -> which actions can user invoke
 pub fn print_help() {
-    println!("\nROUND CONTROLS:\n-'1' or 'build', 'Build', 'BUILD' to build a base\n\n-'2' or 'harvest', 'Harvest', 'HARVEST' to harvest resources\n\n-'3' or 'train', 'Train', 'TRAIN' to train units,\n  hit enter and then type unit type (for example 'ARCHER')\n  hit enter and specify the number of units you wish to train\n\n-'4' or 'conquer', 'Conquer', 'CONQUER' to send troops to conquer a field,\n  then hit enter and specify type (same as in train),\n  hit enter and put a desired number of troops\n\n-'5' or 'q', 'Q', 'quit', 'Quit', 'QUIT' to quit the game\n\n-'6' or 'h', 'H', 'help', 'Help', 'HELP' to display this help\n\n-'7' or 'stats', 'Stats', 'STATS', 'statistics', 'Statistics', 'STATISTICS'\n  to display current player's statistics\n\n-'8' or 'rules', 'Rules', 'RULES' to display game rules.\n");
+    // generated from the registered commands, so it never misses one
+    let controls: Vec<String> = COMMANDS.iter().map(describe_command).collect();
+    println!("\nROUND CONTROLS:\n{}\n", controls.join("\n\n"));
+
+    // every prompt can be answered by digits only
+    println!(
+        "NUMBERS ONLY INPUT:\n-confirmations: '1' for yes, '2' for no\n-unit types: {}\n-stances: {}\n-fields: their number, counted row by row from 1\n-'0' leaves a choice of unit type, stance or field\n",
+        numbered_options(&UnitType::all()),
+        numbered_options(&Stance::all())
+    );
+}
+
+/// Print what happened in player's kingdom at the start of their turn
+///
+/// Params
+/// ---
+/// - notification: rows describing the news
+/// - player: reference of a player that's currently playing
+/// - round: which game round it is
+/// - pacing: how long the game pauses after the notification
+pub fn print_kingdom_news(notification: &str, player: &Player, round: usize, pacing: Pacing) {
+    println!(
+        "{}",
+        framed(
+            &format!("News from {}'s kingdom in round {}:", player.nick, round),
+            notification,
+        )
+    );
+    pacing.pause();
 }
 
 /// Print the result of a game round, along with player's status
@@ -47,36 +694,126 @@ pub fn print_help() {
 /// - game_plan: reference to the game plan (to obtain how many units a player sent to the field)
 /// - round: which game round it is
 /// - status_at_the_end: whether to print player's status at the end
+/// - pacing: how long the game pauses after the status
 pub fn print_round_action(
     notification: &str,
     player: &Player,
     game_plan: &GamePlan,
     round: usize,
     status_at_the_end: bool,
+    pacing: Pacing,
 ) {
-    // straight 78 character long line
-    let line_smooth = "═".repeat(78);
-
-    // format string to return a nicely formatted table
+    // format string to return a nicely formatted table, green for a performed action
     println!(
-        "╔{}╗\n║{:^78}║\n╠{}╣\n{}\n╚{}╝\n",
-        &line_smooth,
-        format!("{}'s action info for round {}:", player.nick, round),
-        &line_smooth,
-        notification,
-        &line_smooth,
+        "{}",
+        paint(
+            &framed(
+                &format!("{}'s action info for round {}:", player.nick, round),
+                notification,
+            ),
+            Color::Green
+        )
     );
 
-    game_sleep_second();
+    pacing.pause();
 
     // display the status at the end as well
     if status_at_the_end {
         println!("{}\n\n", player.status(round, game_plan, "after"));
-        game_sleep_two_seconds();
+        pacing.long_pause();
+    }
+}
+
+/// Print why an action of a player could not be performed, in red
+///
+/// Params
+/// ---
+/// - notification: string (slice) containing the reason of the failure
+/// - player: reference of a player that's currently playing
+/// - round: which game round it is
+/// - pacing: how long the game pauses after the notification
+pub fn print_action_error(notification: &str, player: &Player, round: usize, pacing: Pacing) {
+    println!(
+        "{}",
+        paint(
+            &framed(
+                &format!("{}'s action info for round {}:", player.nick, round),
+                notification,
+            ),
+            Color::Red
+        )
+    );
+
+    pacing.pause();
+}
+
+/// Print the result of a simulated action along with projected standings
+///
+/// Params
+/// ---
+/// - notification: text result of the simulated action
+/// - player: simulated copy of the player
+/// - game_plan: simulated copy of the game plan
+/// - round: which game round it is
+/// - success: whether the simulated action succeeded
+pub fn print_simulation(
+    notification: &str,
+    player: &Player,
+    game_plan: &GamePlan,
+    round: usize,
+    success: bool,
+) {
+    let color = match success {
+        true => Color::Green,
+        false => Color::Red,
+    };
+    println!(
+        "{}",
+        paint(
+            &framed(
+                &format!("{}'s SIMULATED action for round {}:", player.nick, round),
+                notification,
+            ),
+            color
+        )
+    );
+
+    // a failed action does not change the standings
+    if !success {
+        println!("The simulated action would fail, nothing has changed.\n");
+        return;
     }
+
+    // sort the projected standings from the best player
+    let mut standings: Vec<(String, usize)> = game_plan.standings().into_iter().collect();
+    standings.sort_by(|(nick_a, fields_a), (nick_b, fields_b)| {
+        fields_b.cmp(fields_a).then(nick_a.cmp(nick_b))
+    });
+
+    let standings: Vec<String> = match standings.is_empty() {
+        true => vec![format!("║{:^78}║", "No player would lead any field.")],
+        false => standings
+            .iter()
+            .map(|(nick, fields)| {
+                let plural = plural_suffix(*fields, "s");
+                format!(
+                    "║{:^78}║",
+                    format!("{} would lead {} field{}", nick, fields, plural)
+                )
+            })
+            .collect(),
+    };
+
+    println!("{}", framed("Projected standings:", &standings.join("\n")));
+
+    println!(
+        "{}\n",
+        player.status(round, game_plan, "after a simulated action in")
+    );
+    println!("This was only a simulation, your real game has not changed.\n");
 }
 
 /// Print game rules
 pub fn print_rules() {
-    println!("\n- Harvesting gives player 200 units of wood and 120 units of gold.\n- It is necessary to build a base in order to train units.\n- To build a base, you need 220 units of wood and 100 units of gold\n- Base has a capacity of 200 units. To be able to have more than 200 units at your disposal, you have to build another base.\n- There are two types of units, Archers and Warriors.\n- It costs 10 units of gold to train one Archer.\n- It costs 10 units of wood and 5 units of gold to train one Warrior.\n- Archers are a bit stronger in the field than Warriors. (1.9 strength vs 1.2 strength)\n- You can send troops to conquer a piece of land, your opponent will probably do the same.\n- Player with strongest force on a certain field will be considered the conqueror of that field.\n- At the end of the game, the fields are evaluated and the person with most conquered fields wins.\n- If there are equal forces on the field at the end of the game, it is NOT won.\n- The DEFAULT version of the game only includes one field. Custom game mode may be coming in a future patch.\n- The DEFAULT version of the game only allows 2 players. Custom game modes might be implemented in the next patch.\n- You can decide to quit the game at any round. Please, know that the round will continue for other players.\n");
+    println!("\n- Every turn, you have 3 action points. Building, upgrading, harvesting and training an army cost 2 points, other actions 1 point. The turn ends when you run out of points, or type 'end'. While your turn lasts, 'undo' takes back your last action, once per turn.\n- Harvesting gives player 200 units of wood and 120 units of gold.\n- Instead of acting, you can 'pass' your whole turn and gather 20 units of wood and 10 units of gold.\n- It is necessary to build a base in order to train units.\n- To build a base, you need 220 units of wood and 100 units of gold\n- Base has a capacity of 200 units. To be able to have more than 200 units at your disposal, you have to build another base or upgrade one.\n- Every level of a base adds 150 units of capacity, up to level 3. An upgrade costs 140 units of wood and 70 units of gold times the current level of the base (100 units of wood and 50 units of gold for a farm).\n- There are three types of units, Archers, Warriors and Riders (cavalry).\n- It costs 10 units of gold to train one Archer.\n- It costs 10 units of wood and 5 units of gold to train one Warrior.\n- It costs 15 units of wood and 20 units of gold to train one Rider.\n- A farm costs 150 units of wood and 60 units of gold and requires a base. At the start of your turn, every farm yields 40 units of wood and 20 units of gold per its level.\n- Every base produces 5 free peasants at the start of your turn, as long as there is free capacity.\n- At the start of your turn, your army is paid: every 10 Archers or Warriors cost 1 unit of gold, every 10 Riders 2 units of gold, wherever they are. Units you cannot pay desert.\n- Upgrading a peasant costs 5 units of gold for an Archer, 5 units of wood and 2 units of gold for a Warrior, or 8 units of wood and 10 units of gold for a Rider.\n- Archers are a bit stronger in the field than Warriors, Riders are the strongest. (1.9 strength vs 1.2 strength vs 2.8 strength)\n- You can send troops to conquer a piece of land, your opponent will probably do the same.\n- At the end of every round, armies of different players on the same field fight. Every player loses a share of their units there, the stronger the enemies, the higher the losses.\n- A stack of 20 or more units on a field becomes a named regiment (f.e. 3rd Archer Regiment), shown in battle logs and field analyses.\n- Units on a field can be recalled back into your bases, if there is enough room in them.\n- Player with strongest force on a certain field will be considered the conqueror of that field.\n- Every base keeps 2 fields in order (at least 1 field is always kept). At the end of every round, each of the weakest fields a player leads above this limit revolts with a 25 % chance, driving all the units there out for good, the field turns neutral.\n- Units of the player who occupied a field first can GARRISON it, which makes them 1.25 times stronger.\n- Siege ladders (20 units of wood and 30 units of gold), fire arrows (40 units of gold) and healing potions (10 units of wood and 30 units of gold) can be bought and used on a field with your units until the end of the round. A siege ladder makes attackers of the field 1.2 times stronger, fire arrows make archers 1.3 times stronger, a healing potion halves the losses.\n- The strongest player of a field after a battle captures a war banner, which makes all their units on a field 1.1 times stronger for a round.\n- A tower (150 units of wood and 50 units of gold, 2 action points) can be built on a field with your units ('fortify'), one tower per field. It makes all your units there 1.15 times stronger and stands until you have no units left on the field.\n- A market costs 200 units of wood and 20 units of gold and requires a base. It lets you sell wood for gold or gold for wood on the market shared by all players, the more of a resource is sold, the less it is worth. The market keeps a 10 % fee, lower with a higher level of your market.\n- You can offer wood and gold to another player for theirs. The offered resources are put aside until the player accepts or declines the offer at the start of their next turn.\n- At the end of the game, the fields are evaluated and the person with most conquered fields wins.\n- Another victory can be chosen at the start: DOMINATION (lead every field), ECONOMIC (hold the chosen gold) or SURVIVAL (be the last player with an army). It is checked at the end of every round, if nobody achieves it, the fields decide.\n- If there are equal forces on the field at the end of the game, it is NOT won.\n- The DEFAULT version of the game only includes one field, a larger battlefield (up to 10 x 10 fields) can be picked at the start of the game.\n- The DEFAULT version of the game only allows 2 players. Custom game modes might be implemented in the next patch.\n- Any player can be replaced by a computer controlled BOT, which follows either an ECONOMIC or an AGGRESSIVE strategy.\n- The game can start with an exploration phase, fields cannot be conquered until the round chosen at the start of the game.\n- In the fog of war, you only see opponents' units on fields where you have units. Scouting a field reveals it until the end of your next turn.\n- Players sharing a keyboard can pass it between turns, the screen is then cleared so nobody sees the resources of the others.\n- You can decide to quit the game at any round. Please, know that the round will continue for other players.\n");
 }
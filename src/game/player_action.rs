@@ -1,26 +1,59 @@
-use super::notifications::{print_help, print_rules};
-use super::types::limits::{DEFAULT_PLAN_HEIGHT, DEFAULT_PLAN_WIDTH};
+use super::commands::{find_command, Command};
+use super::engine::GameEngine;
+use super::history::{search_history, HistoryFilter};
+use super::layout::is_narrow_terminal;
+use super::notifications::{
+    print_field_analysis, print_help, print_history, print_rules, print_simulation,
+};
+use super::save::DEFAULT_SAVE_FILE;
 use super::types::{
-    actions::Actions, board::GamePlan, buildings::Building, player::Player, troops::UnitType,
+    actions::Actions,
+    board::{GameField, GamePlan, UnitInField},
+    buildings::BuildingType,
+    items::ItemType,
+    limits::{ACTION_POINTS_PER_TURN, MAX_BUILDING_LEVEL},
+    player::Player,
+    plurals::plural_suffix,
+    resources::ResourceType,
+    trade::{describe_resources, TradeOffer},
+    troops::{ArmyTemplate, Stance, Unit, UnitType},
+    value_types::{Quantity, ResourceValue},
+};
+use super::user_input::{
+    get_line, input_closed, numbered_options, parse_confirmation, parse_numbered, turn_interrupted,
 };
-use super::user_input::get_line;
 
 /// Confirm an action from user
 /// Prints a confirmation message and asks user to confirm, that they want to do the action.
 pub fn confirm_action(action: &Actions) -> bool {
+    // actions taking up the turn show their cost
+    let cost = match action.action_points() {
+        0 => String::new(),
+        points => format!(" ({} action point{})", points, plural_suffix(points, "s")),
+    };
+
     loop {
-        // ask user to confirm action
-        println!("\nPlease confirm this action: {}\n(Either press enter or type 'yes' or 'y', or decline by typing 'no' or 'n'.)", action);
+        // ask user to confirm action (shorter prompt for narrow terminals)
+        match is_narrow_terminal() {
+            true => println!("\nConfirm: {}{}? [Y/n]", action, cost),
+            false => println!("\nPlease confirm this action: {}{}\n(Either press enter or type 'yes', 'y' or '1', or decline by typing 'no', 'n' or '2'.)", action, cost),
+        }
 
         // get a line and trim it
         let line = get_line();
+        if turn_interrupted() {
+            return false;
+        }
         let line = line.trim();
 
         // check what it said
         match line {
-            "YES" | "Yes" | "yes" | "Y" | "y" | "" => return true,
-            "NO" | "No" | "no" | "N" | "n" => return false,
-            _ => continue,
+            "" => return true,
+            line => {
+                if let Some(confirmed) = parse_confirmation(line) {
+                    return confirmed;
+                }
+            }
         }
     }
 }
@@ -37,10 +70,488 @@ pub fn confirm_action(action: &Actions) -> bool {
 /// ---
 /// - Some(conquer_action): if user decided to conquer a field
 /// - None: if the user chose to leave the conquer action specification
-fn get_conquer_action(player: &Player, x: usize, y: usize) -> Option<Actions> {
+pub(super) fn get_conquer_action(player: &Player, x: usize, y: usize) -> Option<Actions> {
     units_action(player, UnitAction::Conquer(x, y))
 }
 
+/// Get the stance action
+///
+/// Params
+/// ---
+/// - x: x coordinate
+/// - y: y coordinate
+///
+/// Returns
+/// ---
+/// - Some(stance_action): if user picked a new stance
+/// - None: if the user chose to leave the stance action specification
+fn get_stance_action(x: usize, y: usize) -> Option<Actions> {
+    loop {
+        println!("\nPlease specify the stance of your units on field ({},{}):\n(possible options: {}, GARRISON defends the field, RAIDING has no stance)\n(to quit, type 'QUIT', 'quit', 'q' or '0')\n", x, y, numbered_options(&Stance::all()));
+
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let line = line.trim();
+
+        match line {
+            "GARRISON" | "garrison" => return Some(Actions::Stance(x, y, Stance::Garrison)),
+            "RAIDING" | "raiding" => return Some(Actions::Stance(x, y, Stance::Raiding)),
+            "QUIT" | "Quit" | "Q" | "quit" | "q" | "0" => return None,
+            line => match parse_numbered(line, &Stance::all()) {
+                Some(stance) => return Some(Actions::Stance(x, y, stance)),
+                None => println!("\nUnknown stance, please pick one of the listed options.\n"),
+            },
+        }
+    }
+}
+
+/// Get the recruit action
+///
+/// Params
+/// ---
+/// - player: Reference to player (for aid, how many peasants can player upgrade)
+///
+/// Returns
+/// ---
+/// - Some(recruit_action): if user decided to upgrade peasants
+/// - None: if user chose to leave the recruit action specification
+fn get_recruit_action(player: &Player) -> Option<Actions> {
+    units_action(player, UnitAction::Recruit)
+}
+
+/// Get the building action
+///
+/// Params
+/// ---
+/// - player: Reference to player (for aid, how many buildings can player afford)
+///
+/// Returns
+/// ---
+/// - Some(building_action): if user decided to build
+/// - None: if user chose to leave the building action specification
+pub(super) fn get_build_action(player: &Player) -> Option<Actions> {
+    let building_type = get_building_type("build")?;
+
+    // list the buildings which are still missing
+    let requirements: String = player
+        .missing_prerequisites(building_type)
+        .iter()
+        .map(|prerequisite| {
+            format!(
+                "Requires {} {}{} ({} built).\n",
+                prerequisite.required,
+                prerequisite.building,
+                plural_suffix(prerequisite.required, "S"),
+                prerequisite.built
+            )
+        })
+        .collect();
+
+    loop {
+        println!(
+            "\nPlease specify how many buildings of type {} you wish to build:\n{}You can currently afford {}.\n(press enter to build one, to quit, type 'QUIT', 'quit' or 'q')\n",
+            building_type,
+            requirements,
+            player.build_max_buildings(building_type)
+        );
+
+        // get the line and trim it
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let line = line.trim();
+
+        match line {
+            "" => return Some(Actions::Build(building_type, 1)),
+            "QUIT" | "Quit" | "Q" | "quit" | "q" => return None,
+            line => match line.parse::<Quantity>() {
+                Ok(n) if n > 0 => return Some(Actions::Build(building_type, n)),
+                _ => println!("\nIncorrect format! Please put a positive number to specify number of buildings!\n"),
+            },
+        }
+    }
+}
+
+/// Get the type of a building the player wants to build or upgrade
+///
+/// Params
+/// ---
+/// - purpose: what is to be done with the building (used in prompts)
+///
+/// Returns
+/// ---
+/// - Some(building_type): type picked by the player
+/// - None: if the player chose to quit
+fn get_building_type(purpose: &str) -> Option<BuildingType> {
+    loop {
+        println!(
+            "\nWhich building do you wish to {}?\n(possible options: {})\n(to quit, type 'QUIT', 'quit', 'q' or '0')\n",
+            purpose,
+            numbered_options(&BuildingType::all())
+        );
+
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let line = line.trim();
+
+        match line {
+            "BASE" | "Base" | "base" => return Some(BuildingType::Base),
+            "FARM" | "Farm" | "farm" => return Some(BuildingType::Farm),
+            "MARKET" | "Market" | "market" => return Some(BuildingType::Market),
+            "QUIT" | "Quit" | "Q" | "quit" | "q" | "0" => return None,
+            line => match parse_numbered(line, &BuildingType::all()) {
+                Some(building_type) => return Some(building_type),
+                None => println!("\nUnknown building, please pick one of the listed options.\n"),
+            },
+        }
+    }
+}
+
+/// Get the type of an item the player wants to buy or use
+///
+/// Params
+/// ---
+/// - purpose: what is to be done with the item (used in prompts)
+/// - options: item types the player can pick from
+///
+/// Returns
+/// ---
+/// - Some(item): type picked by the player
+/// - None: if the player chose to quit
+fn get_item_type(purpose: &str, options: &[ItemType]) -> Option<ItemType> {
+    loop {
+        println!(
+            "\nWhich item do you wish to {}?\n(possible options: {})\nSIEGE LADDERS help attackers of a field, FIRE ARROWS help archers, HEALING POTIONS lower the losses\nand WAR BANNERS captured in battles help all your units.\n(to quit, type 'QUIT', 'quit', 'q' or '0')\n",
+            purpose,
+            numbered_options(options)
+        );
+
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let line = line.trim();
+
+        match line {
+            "SIEGE LADDER" | "Siege ladder" | "siege ladder" | "LADDER" | "ladder" => {
+                return Some(ItemType::SiegeLadder)
+            }
+            "FIRE ARROW" | "Fire arrow" | "fire arrow" | "ARROW" | "arrow" => {
+                return Some(ItemType::FireArrow)
+            }
+            "HEALING POTION" | "Healing potion" | "healing potion" | "POTION" | "potion" => {
+                return Some(ItemType::HealingPotion)
+            }
+            "WAR BANNER" | "War banner" | "war banner" | "BANNER" | "banner" => {
+                return Some(ItemType::WarBanner)
+            }
+            "QUIT" | "Quit" | "Q" | "quit" | "q" | "0" => return None,
+            line => match parse_numbered(line, options) {
+                Some(item) => return Some(item),
+                None => println!("\nUnknown item, please pick one of the listed options.\n"),
+            },
+        }
+    }
+}
+
+/// Get the action buying items
+///
+/// Params
+/// ---
+/// - player: Reference to player (for aid, how many items can player afford)
+///
+/// Returns
+/// ---
+/// - Some(buy_action): if user decided to buy items
+/// - None: if user chose to leave the buying action specification
+fn get_buy_action(player: &Player) -> Option<Actions> {
+    let item = get_item_type("buy", &ItemType::purchasable())?;
+
+    loop {
+        println!(
+            "\nPlease specify how many items of type {} you wish to buy:\nYou can currently afford {}.\n(press enter to buy one, to quit, type 'QUIT', 'quit' or 'q')\n",
+            item,
+            player.buy_max_items(item)
+        );
+
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let line = line.trim();
+
+        match line {
+            "" => return Some(Actions::BuyItem(item, 1)),
+            "QUIT" | "Quit" | "Q" | "quit" | "q" => return None,
+            line => match line.parse::<Quantity>() {
+                Ok(n) if n > 0 => return Some(Actions::BuyItem(item, n)),
+                _ => println!("\nIncorrect format! Please put a positive number to specify number of items!\n"),
+            },
+        }
+    }
+}
+
+/// Get the action using an item
+///
+/// Params
+/// ---
+/// - player: Reference to player (for aid, which items the player has)
+/// - x: x coordinate
+/// - y: y coordinate
+///
+/// Returns
+/// ---
+/// - Some(use_action): if user picked an item
+/// - None: if user chose to leave the action specification
+fn get_use_action(player: &Player, x: usize, y: usize) -> Option<Actions> {
+    let owned: Vec<String> = ItemType::all()
+        .iter()
+        .map(|item| {
+            let quantity = player.items(*item);
+            format!("{} {}{}", quantity, item, plural_suffix(quantity, "S"))
+        })
+        .collect();
+    println!("\nYour inventory: {}", owned.join(", "));
+
+    let item = get_item_type(&format!("use on field ({},{})", x, y), &ItemType::all())?;
+    Some(Actions::UseItem(x, y, item))
+}
+
+/// Get the action selling a resource on the market
+///
+/// Params
+/// ---
+/// - player: Reference to player (for aid, how much the player has and what fee they pay)
+/// - game_plan: game plan with the market shared by all the players
+///
+/// Returns
+/// ---
+/// - Some(exchange_action): if user decided to sell a resource
+/// - None: if the player has no market or chose to leave the action specification
+fn get_exchange_action(player: &Player, game_plan: &GamePlan) -> Option<Actions> {
+    let Some(fee) = player.market_fee() else {
+        println!("\nYou need a MARKET to exchange resources.\n");
+        return None;
+    };
+
+    let options = [ResourceType::Wood, ResourceType::Gold];
+    let economy = game_plan.economy();
+    let resource = loop {
+        println!(
+            "\nWhich resource do you wish to sell?\n(possible options: {})\nRight now the market pays {} GOLD for 100 WOOD and {} WOOD for 100 GOLD, keeping {} % as a fee.\n(to quit, type 'QUIT', 'quit', 'q' or '0')\n",
+            numbered_options(&options),
+            economy.quote(ResourceType::Wood, 100, fee),
+            economy.quote(ResourceType::Gold, 100, fee),
+            fee
+        );
+
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let line = line.trim();
+
+        match line {
+            "WOOD" | "Wood" | "wood" => break ResourceType::Wood,
+            "GOLD" | "Gold" | "gold" => break ResourceType::Gold,
+            "QUIT" | "Quit" | "Q" | "quit" | "q" | "0" => return None,
+            line => match parse_numbered(line, &options) {
+                Some(resource) => break resource,
+                None => println!("\nUnknown resource, please pick one of the listed options.\n"),
+            },
+        }
+    };
+
+    let available = match resource {
+        ResourceType::Wood => player.supplies().0,
+        ResourceType::Gold => player.supplies().1,
+    };
+
+    loop {
+        println!(
+            "\nPlease specify how much {} you wish to sell:\nYou currently have {}.\n(to quit, type 'QUIT', 'quit' or 'q')\n",
+            resource, available
+        );
+
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let line = line.trim();
+
+        match line {
+            "QUIT" | "Quit" | "Q" | "quit" | "q" => return None,
+            line => match line.parse::<Quantity>() {
+                Ok(n) if n > 0 => return Some(Actions::Exchange(resource, n)),
+                _ => println!("\nIncorrect format! Please put a positive number to specify how much to sell!\n"),
+            },
+        }
+    }
+}
+
+/// Get the action offering a trade to another player
+///
+/// Params
+/// ---
+/// - player: Reference to player (for aid, how many resources the player has)
+/// - opponents: nicks of the other players
+///
+/// Returns
+/// ---
+/// - Some(trade_action): if user specified the whole offer
+/// - None: if user chose to leave the trade action specification
+fn get_trade_action(player: &Player, opponents: &[String]) -> Option<Actions> {
+    let options: Vec<&String> = opponents.iter().collect();
+
+    // with a single opponent, there is nobody else to trade with
+    let to = match options.as_slice() {
+        [] => return None,
+        [only] => (*only).clone(),
+        _ => loop {
+            println!(
+                "\nWhich player do you wish to trade with?\n(possible options: {})\n(to quit, type 'QUIT', 'quit', 'q' or '0')\n",
+                numbered_options(&options)
+            );
+
+            let line = get_line();
+            if turn_interrupted() {
+                return None;
+            }
+            let line = line.trim();
+
+            match line {
+                "QUIT" | "Quit" | "Q" | "quit" | "q" | "0" => return None,
+                line => match opponents.iter().find(|nick| *nick == line) {
+                    Some(nick) => break nick.clone(),
+                    None => match parse_numbered(line, &options) {
+                        Some(nick) => break nick.clone(),
+                        None => {
+                            println!("\nUnknown player, please pick one of the listed options.\n")
+                        }
+                    },
+                },
+            }
+        },
+    };
+
+    let (wood, gold) = player.supplies();
+    let give = get_resources(&format!(
+        "you offer to {} (you have {} wood and {} gold)",
+        to, wood, gold
+    ))?;
+    let want = get_resources(&format!("you want from {} in return", to))?;
+
+    Some(Actions::Trade { to, give, want })
+}
+
+/// Get an amount of wood and gold from the user
+///
+/// Params
+/// ---
+/// - purpose: what the resources are for (used in prompts)
+///
+/// Returns
+/// ---
+/// - Some((wood, gold)): non-negative amounts typed by the user
+/// - None: if user chose to quit
+fn get_resources(purpose: &str) -> Option<ResourceValue> {
+    loop {
+        println!(
+            "\nPlease type the wood and the gold {}, f.e. '100 50':\n(to quit, type 'QUIT', 'quit' or 'q')\n",
+            purpose
+        );
+
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let line = line.trim();
+
+        if matches!(line, "QUIT" | "Quit" | "Q" | "quit" | "q") {
+            return None;
+        }
+
+        let numbers: Vec<Option<Quantity>> = line
+            .split_whitespace()
+            .map(|word| word.parse::<Quantity>().ok().filter(|n| *n >= 0))
+            .collect();
+
+        match numbers.as_slice() {
+            [Some(wood), Some(gold)] => return Some((*wood, *gold)),
+            _ => println!(
+                "\nIncorrect format! Please put two non-negative numbers, the wood and the gold!\n"
+            ),
+        }
+    }
+}
+
+/// Ask the player whether they accept a trade offer made to them
+///
+/// Params
+/// ---
+/// - offer: offer made to the player
+///
+/// Returns
+/// ---
+/// - true: if the player accepted the offer
+/// - false: if the player declined it
+pub fn get_trade_answer(offer: &TradeOffer) -> bool {
+    loop {
+        println!(
+            "\n{} offers you {} for {}.\nDo you accept the trade? (type 'yes', 'y' or '1', or decline by typing 'no', 'n' or '2')\n",
+            offer.from,
+            describe_resources(offer.give),
+            describe_resources(offer.want)
+        );
+
+        let line = get_line();
+        // nobody is left to answer, the offer is declined
+        if input_closed() {
+            return false;
+        }
+
+        if let Some(accepted) = parse_confirmation(line.trim()) {
+            return accepted;
+        }
+    }
+}
+
+/// Get the upgrade action
+///
+/// Params
+/// ---
+/// - player: Reference to player (for aid, how much the upgrade costs)
+///
+/// Returns
+/// ---
+/// - Some(upgrade_action): if there is a building to upgrade
+/// - None: if all the buildings have the highest level, or there are none
+fn get_upgrade_action(player: &Player) -> Option<Actions> {
+    let building_type = get_building_type("upgrade")?;
+
+    match player.upgrade_cost(building_type) {
+        Some((wood, gold)) => {
+            println!(
+                "\nThe next upgrade of a {} costs {} units of wood and {} units of gold.",
+                building_type, wood, gold
+            );
+            Some(Actions::Upgrade(building_type))
+        }
+        None => {
+            println!(
+                "\nYou have no {} below the highest level {} to upgrade.",
+                building_type, MAX_BUILDING_LEVEL
+            );
+            None
+        }
+    }
+}
+
 /// Get the training action
 ///
 /// Params
@@ -51,7 +562,7 @@ fn get_conquer_action(player: &Player, x: usize, y: usize) -> Option<Actions> {
 /// ---
 /// - Some(training_action): if user decided to train units
 /// - None: if user chose to leave the training action specification
-fn get_train_action(player: &Player) -> Option<Actions> {
+pub(super) fn get_train_action(player: &Player) -> Option<Actions> {
     units_action(player, UnitAction::Train)
 }
 
@@ -60,54 +571,240 @@ fn get_train_action(player: &Player) -> Option<Actions> {
 ///
 /// Params
 /// ---
-/// - player: player reference
-/// - game_plan: game plan reference (for printing of current status)
-/// - round: which round is currently
+/// - engine: the running game, it is the turn of the player
+///   (for saving army templates, naming fields and simulations)
 ///
 /// Returns
 /// ---
 /// - Actions: what action has user decided to perform
-pub fn get_player_action(player: &Player, game_plan: &GamePlan, round: usize) -> Actions {
+pub fn get_player_action(engine: &mut GameEngine) -> Actions {
+    let round = engine.round();
+    let fog_of_war = engine.settings().fog_of_war;
+    let action_points = engine.action_points();
+    let nick = engine.current_player().nick.clone();
+
+    // nicks of the other players, used for simulations and trades
+    let opponents: Vec<String> = engine
+        .players()
+        .iter()
+        .filter(|player| player.nick != nick)
+        .map(|player| player.nick.clone())
+        .collect();
+    let opponents = opponents.as_slice();
+
     // input loop
     loop {
+        let (player, game_plan) = engine.current_player_and_plan_mut();
         println!(
-            "\nRound {}, {}'s action (for help please, type '6' or 'help'):\n",
-            round, player.nick
+            "\nRound {}, {}'s action ({} of {} action points left, for help please, type '6' or 'help'):\n",
+            round, player.nick, action_points, ACTION_POINTS_PER_TURN
         );
 
         // get the line, trim it
         let line_one = get_line();
+        // the player ran out of time or input, the turn is resolved by the caller
+        if turn_interrupted() {
+            return Actions::EndTurn;
+        }
         let line_one = line_one.trim();
 
+        // split the line into the command and its arguments
+        let mut words = line_one.split_whitespace();
+        let command = words.next().unwrap_or("");
+        let arguments: Vec<&str> = words.collect();
+
+        // the developer console changes the game directly, f.e. 'cheat give wood 1000'
+        #[cfg(feature = "dev")]
+        if command == "cheat" {
+            match super::dev_console::run_cheat(player, game_plan, &arguments) {
+                Ok(message) => println!("\nCHEAT: {}\n", message),
+                Err(error) => println!("\nCHEAT FAILED: {}\n", error),
+            }
+            continue;
+        }
+
         // parse the contents of the line
-        match line_one {
-            "1" | "build" | "Build" | "BUILD" => return Actions::Build(Building::Base),
-            "2" | "harvest" | "Harvest" | "HARVEST" => return Actions::Harvest,
-            "3" | "train" | "Train" | "TRAIN" => match get_train_action(player) {
+        match find_command(command) {
+            Some(Command::Build) => match get_build_action(player) {
+                Some(action) => return action,
+                None => {
+                    println!("\nNo worries, nothing was built!\n");
+                }
+            },
+            Some(Command::Harvest) => return Actions::Harvest,
+            Some(Command::Train) => match get_train_action(player) {
                 Some(action) => return action,
                 None => {
                     println!("\nNo worries, no units were trained!\n");
                 }
             },
-            "4" | "conquer" | "Conquer" | "CONQUER" => {
-                // putting coordinates 0,0 as this is the default behavior,
-                // in case the custom game mode is implemented, there will be additional
-                // input handling to just simply call this function with the input.
-                // until then, this might seem unnecessary
-                match get_conquer_action(player, DEFAULT_PLAN_WIDTH - 1, DEFAULT_PLAN_HEIGHT - 1) {
+            Some(Command::Conquer) => {
+                // the fields are only listed when the player is about to pick one
+                if arguments.is_empty() {
+                    print_conquest_forecasts(player, game_plan, fog_of_war);
+                }
+
+                // coordinates can follow the command, f.e. 'conquer 1 2'
+                match get_target_field(game_plan, &arguments, "conquer")
+                    .and_then(|(x, y)| get_conquer_action(player, x, y))
+                {
                     Some(action) => return action,
                     None => {
                         println!("\nNo worries, no units were sent away!\n");
                     }
                 }
             }
-            "5" | "q" | "Q" | "quit" | "Quit" | "QUIT" => return Actions::Quit,
-            "6" | "h" | "H" | "help" | "Help" | "HELP" => print_help(),
-            "7" | "stats" | "Stats" | "STATS" | "statistics" | "Statistics" | "STATISTICS" => {
+            Some(Command::Quit) => return Actions::Quit,
+            Some(Command::End) => return Actions::EndTurn,
+            Some(Command::Undo) => return Actions::Undo,
+            Some(Command::Pass) => return Actions::Pass,
+            Some(Command::Recall) => {
+                let field = get_target_field(game_plan, &arguments, "recall units from")
+                    .and_then(|(x, y)| game_plan.field(x, y));
+
+                match field.and_then(|field| units_action(player, UnitAction::Recall(field))) {
+                    Some(action) => return action,
+                    None => println!("\nNo worries, your units stay on the field!\n"),
+                }
+            }
+            Some(Command::Map) => match fog_of_war {
+                true => println!(
+                    "\n{}'~' marks the last known leader of a field hidden in the fog of war.\n",
+                    game_plan.render_map_for(|field| player.map_owner(field))
+                ),
+                false => println!("\n{}", game_plan.render_map()),
+            },
+            Some(Command::Save) => {
+                // the path can follow the command, f.e. 'save my_game.json'
+                let path = match arguments.as_slice() {
+                    [] => DEFAULT_SAVE_FILE.to_string(),
+                    path => path.join(" "),
+                };
+                return Actions::Save(path);
+            }
+            Some(Command::Help) => print_help(),
+            Some(Command::Stats) => {
                 println!("\n{}\n", player.status(round, game_plan, "during"))
             }
-            "8" | "rules" | "Rules" | "RULES" => print_rules(),
-            _ => {
+            Some(Command::Rules) => print_rules(),
+            Some(Command::History) => match HistoryFilter::parse(&arguments) {
+                // with the fog of war on, the moves of the opponents stay secret
+                Ok(filter) => {
+                    let nick = fog_of_war.then_some(player.nick.as_str());
+                    let (entries, left_out) = search_history(&filter, nick);
+                    print_history(&entries, left_out);
+                }
+                Err(error) => println!("\n{}\n", error),
+            },
+            Some(Command::Analyze) => {
+                let coordinates = get_target_field(game_plan, &arguments, "analyze");
+
+                match coordinates.and_then(|(x, y)| game_plan.field(x, y)) {
+                    Some(field) if fog_of_war && !player.sees(field) => {
+                        let (x, y) = field.coordinates();
+                        match player.intel(x, y) {
+                            Some(known) => {
+                                println!(
+                                    "\nField {} is hidden in the fog of war, this is what you knew in round {}:",
+                                    field.label(),
+                                    known.round
+                                );
+                                print_field_analysis(player, &known.field);
+                            }
+                            None => println!(
+                                "\nField {} is hidden in the fog of war, scout it or send units there first.\n",
+                                field.label()
+                            ),
+                        }
+                    }
+                    Some(field) => print_field_analysis(player, field),
+                    None => {}
+                }
+            }
+            Some(Command::Simulate) => simulate_action(engine, opponents),
+            Some(Command::Upgrade) => match get_upgrade_action(player) {
+                Some(action) => return action,
+                None => println!("\nNo worries, nothing was upgraded!\n"),
+            },
+            Some(Command::Scout) => match get_target_field(game_plan, &arguments, "scout") {
+                Some((x, y)) => return Actions::Scout(x, y),
+                None => println!("\nNo worries, no scouts were sent!\n"),
+            },
+            Some(Command::Buy) => match get_buy_action(player) {
+                Some(action) => return action,
+                None => println!("\nNo worries, nothing was bought!\n"),
+            },
+            Some(Command::Use) => {
+                match get_target_field(game_plan, &arguments, "use an item on")
+                    .and_then(|(x, y)| get_use_action(player, x, y))
+                {
+                    Some(action) => return action,
+                    None => println!("\nNo worries, your items stay in the inventory!\n"),
+                }
+            }
+            Some(Command::Fortify) => match get_target_field(game_plan, &arguments, "fortify") {
+                Some((x, y)) => return Actions::Fortify(x, y),
+                None => println!("\nNo worries, no tower was built!\n"),
+            },
+            Some(Command::Exchange) => match get_exchange_action(player, game_plan) {
+                Some(action) => return action,
+                None => println!("\nNo worries, nothing was sold!\n"),
+            },
+            Some(Command::Trade) => match get_trade_action(player, opponents) {
+                Some(action) => return action,
+                None => println!("\nNo worries, no offer was made!\n"),
+            },
+            Some(Command::Recruit) => match get_recruit_action(player) {
+                Some(action) => return action,
+                None => println!("\nNo worries, no peasants were upgraded!\n"),
+            },
+            Some(Command::Stance) => {
+                match get_target_field(game_plan, &arguments, "change the stance on")
+                    .and_then(|(x, y)| get_stance_action(x, y))
+                {
+                    Some(action) => return action,
+                    None => println!("\nNo worries, your units keep their stance!\n"),
+                }
+            }
+            Some(Command::Name) => {
+                // the name is everything after the coordinates
+                let result = match arguments.as_slice() {
+                    [x, y, name @ ..] => match parse_coordinates(&[x, y]) {
+                        Some((x, y)) => game_plan.name_field(x, y, &name.join(" ")),
+                        None => Err(String::from("Incorrect coordinates!")),
+                    },
+                    _ => Err(String::from(
+                        "Please type 'name x y' followed by the new name.",
+                    )),
+                };
+
+                match result {
+                    Ok(label) => println!("\nThe field is now known as {}.\n", label),
+                    Err(error) => println!("\nCannot name the field: {}\n", error),
+                }
+            }
+            Some(Command::Template) => match arguments.as_slice() {
+                [] => print_templates(player),
+                [name] => match player.remove_template(name) {
+                    true => println!("\nArmy template '{}' was removed.\n", name),
+                    false => println!("\nThere is no army template called '{}'.\n", name),
+                },
+                [name, units @ ..] => match parse_template(name, units) {
+                    Ok(template) => {
+                        println!("\nArmy template '{}' saved: {}\n", name, template);
+                        player.save_template(template);
+                    }
+                    Err(error) => println!("\nCannot save the army template: {}\n", error),
+                },
+            },
+            Some(Command::Army) => match arguments.as_slice() {
+                [name] => match player.template(name) {
+                    Some(template) => return Actions::TrainArmy(template.clone()),
+                    None => println!("\nThere is no army template called '{}'.\n", name),
+                },
+                _ => println!("\nPlease type 'army' followed by the name of a template.\n"),
+            },
+            None => {
                 println!(
                     "\nUnknown command! Please, type '6' or 'help' and hit enter to see help.\n"
                 )
@@ -116,10 +813,428 @@ pub fn get_player_action(player: &Player, game_plan: &GamePlan, round: usize) ->
     }
 }
 
+/// Print a battle forecast for every field, as if the player sent there all the units they have
+///
+/// Fields hidden in the fog of war are forecast from what the player knew about them.
+///
+/// Params
+/// ---
+/// - player: the player picking a field to conquer
+/// - game_plan: the real game plan
+/// - fog_of_war: whether opponents' units are hidden on fields the player cannot see
+fn print_conquest_forecasts(player: &Player, game_plan: &GamePlan, fog_of_war: bool) {
+    if !player.has_fighters_available() {
+        return;
+    }
+
+    let mut forecasts = Vec::new();
+    for y in 0..game_plan.height() {
+        for x in 0..game_plan.width() {
+            let Some(field) = game_plan.field(x, y) else {
+                continue;
+            };
+
+            let forecast = match (fog_of_war && !player.sees(field), player.intel(x, y)) {
+                (false, _) => player.conquest_forecast(field),
+                (true, Some(known)) => format!(
+                    "{} (as seen in round {})",
+                    player.conquest_forecast(&known.field),
+                    known.round
+                ),
+                (true, None) => format!(
+                    "{} (hidden in the fog of war)",
+                    player.conquest_forecast(&field.players_units(player.nick.clone()))
+                ),
+            };
+            forecasts.push(format!("- {}: {}", field.label(), forecast));
+        }
+    }
+
+    println!(
+        "\nBattle forecasts if you send all your units:\n{}",
+        forecasts.join("\n")
+    );
+}
+
+/// Parse an army template from command arguments
+///
+/// Params
+/// ---
+/// - name: name of the template
+/// - units: pairs of quantity and unit type, f.e. ['20', 'archer', '10', 'warrior']
+///
+/// Returns
+/// ---
+/// - Ok(template): if every pair names a positive quantity of a unit type
+/// - Err(String) describing the problem otherwise
+fn parse_template(name: &str, units: &[&str]) -> Result<ArmyTemplate, String> {
+    if !units.len().is_multiple_of(2) {
+        return Err(String::from(
+            "Please put a quantity and a unit type for every unit, f.e. '20 archer'.",
+        ));
+    }
+
+    let units = units
+        .chunks(2)
+        .map(
+            |pair| match (pair[0].parse::<Quantity>(), parse_unit_type(pair[1])) {
+                (Ok(quantity), Some(unit_type)) if quantity > 0 => Ok((unit_type, quantity)),
+                _ => Err(format!(
+                    "'{} {}' is not a positive quantity of a unit type.",
+                    pair[0], pair[1]
+                )),
+            },
+        )
+        .collect::<Result<Vec<(UnitType, Quantity)>, String>>()?;
+
+    Ok(ArmyTemplate {
+        name: name.to_string(),
+        units,
+    })
+}
+
+/// Print the army templates of a player
+///
+/// Params
+/// ---
+/// - player: player reference
+fn print_templates(player: &Player) {
+    if player.templates().is_empty() {
+        println!("\nYou have no army templates yet, save one with 'template name 20 archer'.\n");
+        return;
+    }
+
+    let templates: Vec<String> = player
+        .templates()
+        .iter()
+        .map(|template| format!("-'{}': {}", template.name, template))
+        .collect();
+
+    println!("\nYour army templates:\n{}\n", templates.join("\n"));
+}
+
+/// Parse field coordinates from command arguments
+///
+/// Params
+/// ---
+/// - arguments: command arguments, expected to be exactly 'x y'
+///
+/// Returns
+/// ---
+/// - Some((x, y)): if both coordinates are valid positive whole numbers
+/// - None: otherwise
+fn parse_coordinates(arguments: &[&str]) -> Option<(usize, usize)> {
+    match arguments {
+        [x, y] => Some((x.parse().ok()?, y.parse().ok()?)),
+        _ => None,
+    }
+}
+
+/// Parse a field typed by the user, either by its coordinates or by its number
+///
+/// Params
+/// ---
+/// - game_plan: game plan reference (fields are numbered row by row from 1)
+/// - arguments: f.e. ['0', '1'] for coordinates or ['2'] for a field number
+///
+/// Returns
+/// ---
+/// - Some((x, y)): coordinates of the field
+/// - None: if the input is neither
+pub(super) fn parse_field(game_plan: &GamePlan, arguments: &[&str]) -> Option<(usize, usize)> {
+    match arguments {
+        [number] => game_plan.field_coordinates(number.parse().ok()?),
+        arguments => parse_coordinates(arguments),
+    }
+}
+
+/// Get coordinates of an existing field the player wants to target
+///
+/// On a single-field battlefield, the only field is picked right away.
+///
+/// Params
+/// ---
+/// - game_plan: game plan reference (to validate the coordinates)
+/// - arguments: coordinates typed after the command, if any
+/// - purpose: what is to be done with the field (used in prompts)
+///
+/// Returns
+/// ---
+/// - Some((x, y)): coordinates of an existing field
+/// - None: if the coordinates were invalid, or the user chose to quit
+pub(super) fn get_target_field(
+    game_plan: &GamePlan,
+    arguments: &[&str],
+    purpose: &str,
+) -> Option<(usize, usize)> {
+    let (width, height) = (game_plan.width(), game_plan.height());
+
+    // coordinates typed right after the command
+    if !arguments.is_empty() {
+        let coordinates = parse_field(game_plan, arguments);
+
+        return match coordinates.and_then(|(x, y)| game_plan.field(x, y)) {
+            Some(field) => {
+                println!("\nTarget field: {}", field.label());
+                coordinates
+            }
+            None => {
+                println!(
+                    "\nThere is no such field! Coordinates go from 0 0 up to {} {}.\n",
+                    width - 1,
+                    height - 1
+                );
+                None
+            }
+        };
+    }
+
+    if game_plan.number_of_fields() == 1 {
+        let field = game_plan.field(0, 0)?;
+        println!("\nTarget field: {}", field.label());
+        return Some((0, 0));
+    }
+
+    loop {
+        println!(
+            "\nPlease specify coordinates of the field to {} (for example '0 1'),\ncoordinates go from 0 0 up to {} {}, or type the field number from 1 to {} (counted row by row):\n(to quit, type 'QUIT', 'quit', 'q' or '0')\n",
+            purpose,
+            width - 1,
+            height - 1,
+            game_plan.number_of_fields()
+        );
+
+        // get the line and trim it
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let line = line.trim();
+
+        if let "QUIT" | "Quit" | "Q" | "quit" | "q" | "0" = line {
+            return None;
+        }
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        let coordinates = parse_field(game_plan, &words);
+
+        match coordinates.and_then(|(x, y)| game_plan.field(x, y)) {
+            Some(field) => {
+                println!("\nTarget field: {}", field.label());
+                return coordinates;
+            }
+            None => println!(
+                "\nThere is no such field! Please put two whole numbers within the battlefield.\n"
+            ),
+        }
+    }
+}
+
+/// Parse a unit type typed by the user
+///
+/// Params
+/// ---
+/// - word: user input, f.e. 'ARCHER', 'archer' or its number '1'
+///
+/// Returns
+/// ---
+/// - Some(unit_type): if the word names a unit type
+/// - None: otherwise
+pub(super) fn parse_unit_type(word: &str) -> Option<UnitType> {
+    match word {
+        "ARCHER" | "archer" => Some(UnitType::Archer),
+        "WARRIOR" | "warrior" => Some(UnitType::Warrior),
+        "RIDER" | "rider" | "CAVALRY" | "cavalry" => Some(UnitType::Cavalry),
+        word => parse_numbered(word, &UnitType::all()),
+    }
+}
+
+/// Describe a quantity of every unit type as alternatives
+///
+/// Params
+/// ---
+/// - describe: describes the quantity of one unit type
+///
+/// Returns
+/// ---
+/// - String: f.e. "5 units of type ARCHER *OR* 2 units of type WARRIOR"
+fn describe_per_type(describe: impl Fn(UnitType) -> String) -> String {
+    UnitType::all()
+        .into_iter()
+        .map(describe)
+        .collect::<Vec<String>>()
+        .join(" *OR* ")
+}
+
+/// Let the player try out an action on the game, which is restored afterwards
+///
+/// The action (and optionally a guessed deployment of an opponent)
+/// is applied to the game after taking its snapshot, restoring the snapshot
+/// leaves the real game untouched. Action points are not spent on simulations.
+///
+/// Params
+/// ---
+/// - engine: the running game, it is the turn of the player
+/// - opponents: nicks of the other players
+fn simulate_action(engine: &mut GameEngine, opponents: &[String]) {
+    let round = engine.round();
+    let player = engine.current_player();
+
+    // pick the action to simulate
+    let action = loop {
+        println!("\nWhich action do you want to simulate?\n(possible options: '1' or 'build', '2' or 'harvest', '3' or 'train', '4' or 'conquer')\n(to quit, type '0', 'quit' or 'q')\n");
+
+        let line = get_line();
+        if turn_interrupted() {
+            return;
+        }
+        let line = line.trim();
+
+        match find_command(line) {
+            Some(Command::Build) => match get_build_action(player) {
+                Some(action) => break action,
+                None => return,
+            },
+            Some(Command::Harvest) => break Actions::Harvest,
+            Some(Command::Train) => match get_train_action(player) {
+                Some(action) => break action,
+                None => return,
+            },
+            Some(Command::Conquer) => {
+                match get_target_field(engine.game_plan(), &[], "conquer")
+                    .and_then(|(x, y)| get_conquer_action(player, x, y))
+                {
+                    Some(action) => break action,
+                    None => return,
+                }
+            }
+            _ if ["QUIT", "Quit", "Q", "quit", "q", "0"].contains(&line) => return,
+            _ => println!("\nUnknown action, please pick one of the listed options.\n"),
+        }
+    };
+
+    let action_target = match action {
+        Actions::Conquer(x, y, _, _) => Some((x, y)),
+        _ => None,
+    };
+
+    // everything below changes the game, until the snapshot is restored
+    let snapshot = engine.snapshot();
+    let fog_of_war = engine.settings().fog_of_war;
+    let (player, game_plan) = engine.current_player_and_plan_mut();
+
+    // the simulation only knows what the player can see
+    if fog_of_war {
+        *game_plan = player.visible_plan(game_plan);
+    }
+
+    match player.perform_action(action, game_plan) {
+        Ok(notification) => {
+            // optionally guess what an opponent might do
+            if let Some((opponent, unit_type, quantity)) = get_guessed_deployment(opponents) {
+                // the opponent is expected to respond on the conquered field
+                let coordinates = match action_target {
+                    Some(coordinates) => Some(coordinates),
+                    None => get_target_field(game_plan, &[], "send the opponent's units to"),
+                };
+
+                if let Some(field) = coordinates.and_then(|(x, y)| game_plan.get_game_field(x, y)) {
+                    field.add_units(UnitInField::new(
+                        opponent,
+                        Unit::unit_to_send(unit_type, quantity),
+                    ));
+                }
+            }
+
+            print_simulation(&notification, player, game_plan, round, true);
+        }
+        Err(notification) => print_simulation(&notification, player, game_plan, round, false),
+    }
+
+    engine.restore(snapshot);
+}
+
+/// Ask the player for a guessed deployment of one of their opponents
+///
+/// Params
+/// ---
+/// - opponents: nicks of the other players
+///
+/// Returns
+/// ---
+/// - Some((nick, unit_type, quantity)): guessed deployment
+/// - None: if the player chose not to guess
+fn get_guessed_deployment(opponents: &[String]) -> Option<(String, UnitType, Quantity)> {
+    if opponents.is_empty() {
+        return None;
+    }
+
+    // pick the opponent
+    let opponent = loop {
+        println!(
+            "\nDo you want to guess an opponent's response? Type their nick or number ({}) or press enter to skip:\n",
+            numbered_options(opponents)
+        );
+
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let line = line.trim();
+
+        // opponents can be picked by their number as well
+        let numbered = line
+            .parse::<usize>()
+            .ok()
+            .and_then(|number| opponents.get(number.checked_sub(1)?));
+
+        match (line, numbered) {
+            ("", _) => return None,
+            (_, Some(nick)) => break nick.to_string(),
+            (nick, None) if opponents.iter().any(|opponent| opponent == nick) => {
+                break nick.to_string()
+            }
+            _ => println!("\nThere is no opponent with this nick!\n"),
+        }
+    };
+
+    // pick the deployment
+    loop {
+        println!(
+            "\nHow many units would {} send to the field? Type the unit type and quantity (for example 'ARCHER 20'),\nor press enter to skip:\n",
+            opponent
+        );
+
+        let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words.as_slice() {
+            [] => return None,
+            [unit_type, quantity] => {
+                match (parse_unit_type(unit_type), quantity.parse::<Quantity>()) {
+                    (Some(unit_type), Ok(quantity)) if quantity > 0 => {
+                        return Some((opponent, unit_type, quantity))
+                    }
+                    _ => println!(
+                        "\nIncorrect format! Please put a unit type and a positive number.\n"
+                    ),
+                }
+            }
+            _ => println!("\nIncorrect format! Please put a unit type and a positive number.\n"),
+        }
+    }
+}
+
 /// Used for specifying the desired units action.
-enum UnitAction {
+enum UnitAction<'a> {
     Conquer(usize, usize),
+    Recruit,
     Train,
+    Recall(&'a GameField),
 }
 
 /// Function that can either return a unit action,
@@ -132,75 +1247,107 @@ enum UnitAction {
 fn units_action(player: &Player, unit_action: UnitAction) -> Option<Actions> {
     let unit_type: UnitType;
 
-    // auxiliary output variables
-    let action: &str;
-    let action_past: &str;
-    let action_0_units: &str;
-    let action_units_counted: String;
-
     // fill auxiliary variables output strings
-    match unit_action {
-        UnitAction::Train => {
-            action = "train";
-            action_past = "trained";
-            action_0_units = "train";
-            action_units_counted = match player.current_fighters_capacity() {
+    let (action, action_past, action_0_units, action_units_counted) = match unit_action {
+        UnitAction::Train => (
+            "train",
+            "trained",
+            "train",
+            match player.current_fighters_capacity() {
                 // when there is no capacity,
                 0 => String::from(
                     "You cannot currently train any units. Consider building a base first.",
                 ),
                 _ => format!(
-                    "You can currently train {} units of type {} *OR* {} units of type {}.",
-                    player.train_max_units(UnitType::Archer),
-                    UnitType::Archer,
-                    player.train_max_units(UnitType::Warrior),
-                    UnitType::Warrior,
+                    "You can currently train {}.",
+                    describe_per_type(|unit_type| format!(
+                        "{} units of type {}",
+                        player.train_max_units(unit_type),
+                        unit_type
+                    ))
+                ),
+            },
+        ),
+        UnitAction::Recruit => (
+            "recruit",
+            "recruited",
+            "upgrade",
+            match player.peasants() {
+                0 => String::from(
+                    "You have no peasants to upgrade. Bases produce new peasants every round.",
+                ),
+                peasants => format!(
+                    "You have {} peasants, you can upgrade {}.",
+                    peasants,
+                    describe_per_type(|unit_type| format!(
+                        "{} into {}S",
+                        player.recruit_max_units(unit_type),
+                        unit_type
+                    ))
+                ),
+            },
+        ),
+        UnitAction::Conquer(_, _) => (
+            "send to conquer",
+            "sent to conquer",
+            "send",
+            match player.has_fighters_available() {
+                true => format!(
+                    "You can send {}.",
+                    describe_per_type(|unit_type| format!(
+                        "{} units of type {}",
+                        player.send_max_units(unit_type),
+                        unit_type
+                    ))
                 ),
-            }
-        }
-        UnitAction::Conquer(_, _) => {
-            action = "send to conquer";
-            action_past = "sent to conquer";
-            action_0_units = "send";
-            action_units_counted = match player.has_fighters_available() {
-                true => {
-                    format!(
-                        "You can send {} units of type {} *OR* {} units of type {}.",
-                        player.send_max_units(UnitType::Archer),
-                        UnitType::Archer,
-                        player.send_max_units(UnitType::Warrior),
-                        UnitType::Warrior,
-                    )
-                }
                 false => String::from(
                     "Cannot currently send any units. Consider training some units instead.",
                 ),
-            }
-        }
-    }
+            },
+        ),
+        UnitAction::Recall(field) => (
+            "recall",
+            "recalled",
+            "recall",
+            format!(
+                "You have {} on field {}.",
+                UnitType::all()
+                    .iter()
+                    .map(|unit_type| format!(
+                        "{} units of type {}",
+                        field.units_of(&player.nick, *unit_type),
+                        unit_type
+                    ))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                field.label()
+            ),
+        ),
+    };
 
     // get unit type
     loop {
         println!(
-            "\nPlease specify which unit type you want to {}:\n{}\n(possible options: 'ARCHER', 'WARRIOR')\n(to quit, type 'QUIT', 'quit' or 'q')\n",
-            action, action_units_counted
+            "\nPlease specify which unit type you want to {}:\n{}\n(possible options: {})\n(to quit, type 'QUIT', 'quit', 'q' or '0')\n",
+            action,
+            action_units_counted,
+            numbered_options(&UnitType::all())
         );
 
         // get the line and trim it
         let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
         let line = line.trim();
 
         // obtain information from line
         match line {
-            "ARCHER" | "archer" => {
-                unit_type = UnitType::Archer;
-                break;
-            }
-            "WARRIOR" | "warrior" => {
-                unit_type = UnitType::Warrior;
+            "QUIT" | "Quit" | "Q" | "quit" | "q" | "0" => return None,
+            line if parse_unit_type(line).is_some() => {
+                unit_type = parse_unit_type(line)?;
                 break;
             }
-            "QUIT" | "Quit" | "Q" | "quit" | "q" => return None,
             _ => {
                 println!("\nUnknown unit type, the units will not be {}.\nType 'QUIT', 'quit' or 'q' to change your move.\n", action_past);
             }
@@ -219,6 +1366,9 @@ fn units_action(player: &Player, unit_action: UnitAction) -> Option<Actions> {
 
         // get the line and trim it
         let line = get_line();
+        if turn_interrupted() {
+            return None;
+        }
         let line = line.trim();
 
         // obtain quantity
@@ -228,11 +1378,16 @@ fn units_action(player: &Player, unit_action: UnitAction) -> Option<Actions> {
                 // return desired action
                 match unit_action {
                     UnitAction::Train => return Some(Actions::Train(unit_type, n)),
+                    UnitAction::Recruit => return Some(Actions::Recruit(unit_type, n)),
                     UnitAction::Conquer(x, y) => return Some(Actions::Conquer(x, y, unit_type, n)),
+                    UnitAction::Recall(field) => {
+                        let (x, y) = field.coordinates();
+                        return Some(Actions::Recall(x, y, unit_type, n));
+                    }
                 }
             }
             // 0 units -> incorrect input
-            Ok(n) if n == 0 => {
+            Ok(0) => {
                 println!(
                     "\nCannot {} 0 units of type {}!\n",
                     action_0_units, unit_type
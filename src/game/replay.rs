@@ -0,0 +1,263 @@
+// Recording of games and their playback.
+// Every performed action is recorded along with the round and the player,
+// the game is deterministic, so replaying the actions from the starting state
+// shows the match exactly as it was played.
+
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+
+use super::colors::register_players;
+use super::config::{describe_rules, parse_config};
+use super::engine::GameEngine;
+use super::events::{GameEvent, Observer};
+use super::notifications::{print_battle_log, print_revolts, print_round_action};
+use super::save::SavedGame;
+use super::sleep_intervals::Pacing;
+use super::types::{
+    actions::Actions,
+    rules::{rules, set_rules},
+};
+use super::version::GameVersion;
+
+// file the game is recorded into, unless another one is picked with '--record <file>'
+pub const DEFAULT_REPLAY_FILE: &str = "wartycoon_replay.json";
+
+/// One recorded action
+#[derive(Serialize, Deserialize)]
+pub struct ReplayMove {
+    pub round: usize,
+    pub player: String,
+    pub action: Actions,
+}
+
+/// Recorded game, its starting state and the actions of the players
+#[derive(Serialize, Deserialize)]
+pub struct Replay {
+    pub version: GameVersion,
+    // balance values the game was played with, in the format of the configuration file
+    pub rules: Vec<String>,
+    pub start: SavedGame,
+    pub moves: Vec<ReplayMove>,
+}
+
+/// Observer recording the game into a replay file after every action
+pub struct ReplayRecorder {
+    path: String,
+    replay: Replay,
+    round: usize,
+    // the failure to write is only reported once
+    failed: bool,
+}
+
+impl ReplayRecorder {
+    /// Start recording a game
+    ///
+    /// Params
+    /// ---
+    /// - path: path of the replay file
+    /// - start: state of the game before the first recorded action
+    pub fn new(path: &str, start: SavedGame) -> Self {
+        Self {
+            path: path.to_string(),
+            round: start.round,
+            replay: Replay {
+                version: GameVersion::current(),
+                rules: describe_rules(&rules()),
+                start,
+                moves: Vec::new(),
+            },
+            failed: false,
+        }
+    }
+
+    /// Write the recording into the replay file
+    ///
+    /// Returns
+    /// ---
+    /// - Some(String) with a commentary line, if the recording could not be written for the first time
+    /// - None: otherwise
+    fn save(&mut self) -> Option<String> {
+        // the file is kept up to date, so even an interrupted game can be replayed
+        match (save_replay(&self.replay, &self.path), self.failed) {
+            (Ok(()), _) | (Err(_), true) => None,
+            (Err(error), false) => {
+                self.failed = true;
+                Some(format!("The game is not recorded: {}", error))
+            }
+        }
+    }
+}
+
+impl Observer for ReplayRecorder {
+    fn notify(&mut self, event: &GameEvent) -> Option<String> {
+        match event {
+            GameEvent::RoundStarted { round, .. } => {
+                self.round = *round;
+                None
+            }
+            GameEvent::ActionPerformed { player, action, .. } => {
+                self.replay.moves.push(ReplayMove {
+                    round: self.round,
+                    player: player.nick.clone(),
+                    action: (*action).clone(),
+                });
+
+                self.save()
+            }
+            GameEvent::ActionUndone { .. } => {
+                self.replay.moves.pop();
+                self.save()
+            }
+        }
+    }
+}
+
+/// Write a replay into a file
+///
+/// Params
+/// ---
+/// - replay: the recorded game
+/// - path: path of the replay file
+///
+/// Returns
+/// ---
+/// - Err(String) if the replay could not be written
+fn save_replay(replay: &Replay, path: &str) -> Result<(), String> {
+    let contents = serde_json::to_string(replay)
+        .map_err(|error| format!("Cannot serialize the replay: {}", error))?;
+
+    fs::write(path, contents).map_err(|error| format!("Cannot write to {}: {}", path, error))
+}
+
+/// Load a replay from a file
+///
+/// Params
+/// ---
+/// - path: path of the replay file
+///
+/// Returns
+/// ---
+/// - Ok((Replay, warning)) with the recorded game and a warning,
+///   if it was recorded by another version of the game with the same rules
+/// - Err(String) if the file could not be read, is not a replay
+///   or was recorded with different rules
+pub fn load_replay(path: &str) -> Result<(Replay, Option<String>), String> {
+    let contents =
+        fs::read_to_string(path).map_err(|error| format!("Cannot read {}: {}", path, error))?;
+
+    let replay: Replay = serde_json::from_str(&contents)
+        .map_err(|error| format!("{} is not a valid replay: {}", path, error))?;
+
+    let warning = replay.version.check(path)?;
+
+    if replay.start.next_player >= replay.start.players.len() {
+        return Err(format!("{} contains an inconsistent game state!", path));
+    }
+
+    Ok((replay, warning))
+}
+
+/// Play a recorded game back, printing every action and the battlefield after it
+///
+/// Params
+/// ---
+/// - replay: the recorded game
+/// - pacing: how long the game pauses after the battles and at the end of the replay
+///
+/// Returns
+/// ---
+/// - Err(String) if the rules of the replay are invalid or an action cannot be repeated
+pub fn play_replay(replay: Replay, pacing: Pacing) -> Result<(), String> {
+    set_rules(parse_config(&replay.rules.join("\n"))?);
+    register_players(
+        &replay
+            .start
+            .players
+            .iter()
+            .map(|player| player.nick.clone())
+            .collect::<Vec<String>>(),
+    );
+
+    let mut engine = GameEngine::from_saved(replay.start);
+    engine.start_turn();
+
+    println!("\n{}", engine.game_plan().render_map());
+
+    for recorded in replay.moves {
+        // turns ended early were not recorded, they are ended until the recorded one comes
+        while !engine.is_over()
+            && (engine.round(), engine.current_player().nick.as_str())
+                != (recorded.round, recorded.player.as_str())
+        {
+            pass_turn(&mut engine, Actions::EndTurn, pacing)?;
+        }
+
+        if engine.is_over() {
+            return Err(format!(
+                "{} cannot play in round {}, the game is already over.",
+                recorded.player, recorded.round
+            ));
+        }
+
+        println!(
+            "\nRound {}, {} decides to: {}\n",
+            recorded.round, recorded.player, recorded.action
+        );
+        let notification = pass_turn(&mut engine, recorded.action, pacing).map_err(|error| {
+            format!(
+                "The action of {} in round {} cannot be repeated: {}",
+                recorded.player, recorded.round, error
+            )
+        })?;
+
+        let player = engine
+            .players()
+            .iter()
+            .find(|player| player.nick == recorded.player)
+            .unwrap_or(engine.current_player());
+        print_round_action(
+            &notification,
+            player,
+            engine.game_plan(),
+            recorded.round,
+            false,
+            pacing,
+        );
+        println!("\n{}", engine.game_plan().render_map());
+    }
+
+    println!("\nThe replay is over, this is how the battlefield ended up:\n");
+    engine.game_plan().evaluate();
+    pacing.pause();
+
+    Ok(())
+}
+
+/// Perform an action, start the next turn and print the battles, if the turn has passed
+///
+/// Params
+/// ---
+/// - engine: replayed game
+/// - action: action of the current player
+/// - pacing: how long the game pauses after the battles
+///
+/// Returns
+/// ---
+/// - Ok(String) describing the result of the action
+/// - Err(String) if the action cannot be performed
+fn pass_turn(engine: &mut GameEngine, action: Actions, pacing: Pacing) -> Result<String, String> {
+    let round = engine.round();
+    let turn = (round, engine.current_player().nick.clone());
+    let notification = engine.apply(action)?;
+
+    if engine.round() != round {
+        print_battle_log(engine.battles(), round, pacing);
+        print_revolts(engine.revolts(), round, pacing);
+    }
+    if !engine.is_over() && turn != (engine.round(), engine.current_player().nick.clone()) {
+        engine.start_turn();
+    }
+
+    Ok(notification)
+}
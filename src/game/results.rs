@@ -0,0 +1,157 @@
+// Machine-readable results of finished games.
+// With '--export <file>', the outcome of a finished game is written into a JSON file,
+// so that organizers of leagues can collect the results of many matches with their own tools.
+
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+
+use super::settings::GameSettings;
+use super::stats::PlayerStats;
+use super::types::{
+    board::GamePlan,
+    player::Player,
+    troops::UnitType,
+    value_types::{FighterPower, Quantity},
+};
+use super::version::GameVersion;
+use super::victory::VictoryCondition;
+
+/// Outcome of one field of the battlefield
+#[derive(Serialize, Deserialize)]
+pub struct FieldResult {
+    pub x: usize,
+    pub y: usize,
+    pub label: String,
+    /// the unique strongest player of the field, None for an empty or a tied field
+    pub leader: Option<String>,
+    /// power of the leader
+    pub power: FighterPower,
+}
+
+/// Outcome of one player
+#[derive(Serialize, Deserialize)]
+pub struct PlayerResult {
+    pub nick: String,
+    /// fields the player leads at the end of the game
+    pub fields_held: usize,
+    /// wood and gold left at the end of the game
+    pub wood: Quantity,
+    pub gold: Quantity,
+    /// units of all types, in the kingdom and on the battlefield
+    pub army: Quantity,
+    /// tally of the actions the player has performed
+    pub stats: PlayerStats,
+}
+
+/// Results of a finished game
+#[derive(Serialize, Deserialize)]
+pub struct GameResults {
+    pub version: GameVersion,
+    /// seed of the game's generator, the same seed and moves play the game the same way
+    pub seed: u64,
+    pub rounds: usize,
+    // games can be quit or finished early
+    pub rounds_played: usize,
+    pub victory: VictoryCondition,
+    /// None for a draw
+    pub winner: Option<String>,
+    pub fields: Vec<FieldResult>,
+    pub players: Vec<PlayerResult>,
+}
+
+impl GameResults {
+    /// Collect the results of a finished game
+    ///
+    /// Params
+    /// ---
+    /// - players: players of the game
+    /// - game_plan: battlefield at the end of the game
+    /// - settings: settings of the game
+    /// - seed: seed of the game's generator
+    /// - rounds_played: number of the last round played
+    /// - winner: winner of the game, if there is one
+    pub fn collect(
+        players: &[Player],
+        game_plan: &GamePlan,
+        settings: &GameSettings,
+        seed: u64,
+        rounds_played: usize,
+        winner: Option<&str>,
+    ) -> GameResults {
+        let standings = game_plan.standings();
+
+        let fields = (0..game_plan.height())
+            .flat_map(|y| (0..game_plan.width()).map(move |x| (x, y)))
+            .filter_map(|(x, y)| game_plan.field(x, y))
+            .map(|field| {
+                let (x, y) = field.coordinates();
+                let (leader, power) = match field.leader() {
+                    Some((leader, power)) => (Some(leader), power),
+                    None => (None, 0.0),
+                };
+
+                FieldResult {
+                    x,
+                    y,
+                    label: field.label(),
+                    leader,
+                    power,
+                }
+            })
+            .collect();
+
+        let players = players
+            .iter()
+            .map(|player| {
+                let (wood, gold) = player.supplies();
+
+                PlayerResult {
+                    nick: player.nick.clone(),
+                    fields_held: standings.get(&player.nick).copied().unwrap_or(0),
+                    wood,
+                    gold,
+                    army: UnitType::all()
+                        .iter()
+                        .map(|unit_type| player.units_owned(game_plan, *unit_type))
+                        .sum(),
+                    stats: *player.stats(),
+                }
+            })
+            .collect();
+
+        GameResults {
+            version: GameVersion::current(),
+            seed,
+            rounds: settings.rounds,
+            rounds_played,
+            victory: settings.victory,
+            winner: winner.map(String::from),
+            fields,
+            players,
+        }
+    }
+
+    /// Write the results into a JSON file
+    ///
+    /// Params
+    /// ---
+    /// - path: path of the results file
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) containing a confirmation message
+    /// - Err(String) if the results could not be written
+    pub fn export(&self, path: &str) -> Result<String, String> {
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|error| format!("Cannot serialize the results: {}", error))?;
+
+        fs::write(path, contents)
+            .map_err(|error| format!("Cannot write to {}: {}", path, error))?;
+
+        Ok(format!(
+            "The results of the game have been exported to {}.",
+            path
+        ))
+    }
+}
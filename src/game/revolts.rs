@@ -0,0 +1,114 @@
+// Revolts of fields held by overextended empires.
+// Every base keeps a few fields in order, a player leading more fields than that
+// risks the weakest of them revolting at the end of a round, the rebels drive
+// all the units out and the field turns neutral.
+// The revolts are rolled with the generator of the game, so a replay ends up the same.
+
+use super::rng::GameRng;
+use super::types::{
+    board::GamePlan,
+    buildings::BuildingType,
+    player::Player,
+    rules::rules,
+    troops::UnitType,
+    value_types::{FighterPower, Quantity},
+};
+
+/// Field which revolted against the player leading it
+#[derive(Clone)]
+pub struct Revolt {
+    /// label of the field
+    pub field: String,
+    /// nick of the player who lost the field
+    pub player: String,
+    /// (owner, unit type, quantity) of the units driven out, of every player on the field
+    pub losses: Vec<(String, UnitType, Quantity)>,
+}
+
+/// Return how many fields a player can lead without risking a revolt
+///
+/// Params
+/// ---
+/// - player: the checked player
+///
+/// Returns
+/// ---
+/// - number of fields kept in order by the player's bases, at least one
+pub fn field_limit(player: &Player) -> usize {
+    let bases = player.number_of_buildings(BuildingType::Base) as usize;
+
+    (bases * rules().fields_per_base as usize).max(1)
+}
+
+/// Let the weakest fields of overextended players revolt, at the end of every round
+///
+/// Params
+/// ---
+/// - players: all players of the game
+/// - game_plan: battlefield after the battles of the round
+/// - rng: generator of the game, rolling the revolts
+///
+/// Returns
+/// ---
+/// - fields which revolted
+pub fn resolve_revolts(
+    players: &[Player],
+    game_plan: &mut GamePlan,
+    rng: &mut GameRng,
+) -> Vec<Revolt> {
+    let mut revolts = Vec::new();
+
+    for player in players {
+        // fields the player leads, the weakest ones are the first to revolt
+        let mut led: Vec<((usize, usize), FighterPower)> = (1..=game_plan.number_of_fields())
+            .filter_map(|number| game_plan.field_coordinates(number))
+            .filter_map(|(x, y)| game_plan.field(x, y))
+            .filter_map(|field| match field.leader() {
+                Some((leader, power)) if leader == player.nick => {
+                    Some((field.coordinates(), power))
+                }
+                _ => None,
+            })
+            .collect();
+        led.sort_by(|(_, first), (_, second)| first.total_cmp(second));
+
+        let excess = led.len().saturating_sub(field_limit(player));
+
+        for ((x, y), _) in led.into_iter().take(excess) {
+            if rng.roll_percent() >= rules().revolt_chance {
+                continue;
+            }
+
+            let Some(field) = game_plan.get_game_field(x, y) else {
+                continue;
+            };
+
+            // the field turns neutral, so the units of the other players are driven out as well
+            let losses: Vec<(String, UnitType, Quantity)> = field
+                .occupants()
+                .into_iter()
+                .flat_map(|owner| {
+                    UnitType::all()
+                        .into_iter()
+                        .map(move |unit_type| (owner.clone(), unit_type))
+                })
+                .map(|(owner, unit_type)| {
+                    let quantity = field.units_of(&owner, unit_type);
+                    (owner, unit_type, quantity)
+                })
+                .filter(|(_, _, quantity)| *quantity > 0)
+                .collect();
+            for (owner, unit_type, quantity) in &losses {
+                field.withdraw_units(owner, *unit_type, *quantity);
+            }
+
+            revolts.push(Revolt {
+                field: field.label(),
+                player: player.nick.clone(),
+                losses,
+            });
+        }
+    }
+
+    revolts
+}
@@ -0,0 +1,74 @@
+// Source of all the randomness of a game.
+// Every random decision is drawn from one generator created from a seed, the seed is
+// printed at the start of the game and can be picked with '--seed', so the same seed
+// and the same inputs always lead to the same game. The generator is saved and recorded
+// along with the game, a loaded game or a replay continues with the same draws.
+
+use serde::{Deserialize, Serialize};
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::types::value_types::Quantity;
+
+/// Pseudorandom generator of a game (SplitMix64)
+#[derive(PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct GameRng {
+    // seed the generator was created from
+    seed: u64,
+    // state after the numbers drawn so far
+    state: u64,
+}
+
+impl GameRng {
+    /// Create a generator, the same seed gives the same numbers
+    ///
+    /// Params
+    /// ---
+    /// - seed: seed of the generator
+    pub fn new(seed: u64) -> Self {
+        GameRng { seed, state: seed }
+    }
+
+    /// Create a generator seeded from the clock, for games without a chosen seed
+    pub fn from_time() -> Self {
+        GameRng::new(time_seed())
+    }
+
+    /// Return the seed the generator was created from
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Draw the next number
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        value ^ (value >> 31)
+    }
+
+    /// Draw a number from 0 to bound - 1, 0 for an empty range
+    ///
+    /// Params
+    /// ---
+    /// - bound: the number above the highest possible one
+    pub fn below(&mut self, bound: usize) -> usize {
+        match bound {
+            0 => 0,
+            bound => (self.next_u64() % bound as u64) as usize,
+        }
+    }
+
+    /// Draw a number from 0 to 99, to be compared with a chance in percents
+    pub fn roll_percent(&mut self) -> Quantity {
+        self.below(100) as Quantity
+    }
+}
+
+/// Return a seed taken from the clock
+pub fn time_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_nanos() as u64)
+}
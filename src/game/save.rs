@@ -0,0 +1,106 @@
+// Saving a game to a file and loading it back,
+// so that long matches can be continued later.
+
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+
+use super::rng::GameRng;
+use super::settings::GameSettings;
+use super::types::{board::GamePlan, player::Player};
+use super::version::GameVersion;
+
+// file the game is saved to, unless the player picks another one
+pub const DEFAULT_SAVE_FILE: &str = "wartycoon_save.json";
+
+/// Full state of a game at the start of a player's turn
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SavedGame {
+    pub players: Vec<Player>,
+    pub game_plan: GamePlan,
+    pub settings: GameSettings,
+    pub round: usize,
+    // index of the player whose turn it is
+    pub next_player: usize,
+    // generator of the game, games saved before it existed continue with the seed 0
+    #[serde(default)]
+    pub rng: GameRng,
+}
+
+/// Saved game along with the version of the build which saved it
+#[derive(Serialize)]
+struct VersionedGame<'a> {
+    version: GameVersion,
+    #[serde(flatten)]
+    game: &'a SavedGame,
+}
+
+/// Save the game into a file
+///
+/// Params
+/// ---
+/// - saved_game: state of the game to be saved
+/// - path: path of the save file
+///
+/// Returns
+/// ---
+/// - Ok(String) containing a confirmation message
+/// - Err(String) if the game could not be saved
+pub fn save_game(saved_game: &SavedGame, path: &str) -> Result<String, String> {
+    let versioned = VersionedGame {
+        version: GameVersion::current(),
+        game: saved_game,
+    };
+    let contents = serde_json::to_string_pretty(&versioned)
+        .map_err(|error| format!("Cannot serialize the game: {}", error))?;
+
+    fs::write(path, contents).map_err(|error| format!("Cannot write to {}: {}", path, error))?;
+
+    Ok(format!(
+        "The game has been saved to {}, load it with '--load {}'.",
+        path, path
+    ))
+}
+
+/// Load a game from a file
+///
+/// Params
+/// ---
+/// - path: path of the save file
+///
+/// Returns
+/// ---
+/// - Ok((SavedGame, warning)) with the restored state of the game and a warning,
+///   if it was saved by another version of the game with the same rules
+/// - Err(String) if the file could not be read, is not a saved game
+///   or was saved with different rules
+pub fn load_game(path: &str) -> Result<(SavedGame, Option<String>), String> {
+    let contents =
+        fs::read_to_string(path).map_err(|error| format!("Cannot read {}: {}", path, error))?;
+
+    let value: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|error| format!("{} is not a valid saved game: {}", path, error))?;
+
+    // games saved before the version was stored cannot be checked
+    let warning = match value.get("version") {
+        Some(version) => serde_json::from_value::<GameVersion>(version.clone())
+            .map_err(|error| format!("{} has an invalid version: {}", path, error))?
+            .check(path)?,
+        None => Some(format!(
+            "{} was saved by an older version of WarTycoon, it may play differently.",
+            path
+        )),
+    };
+
+    let saved_game: SavedGame = serde_json::from_value(value)
+        .map_err(|error| format!("{} is not a valid saved game: {}", path, error))?;
+
+    // the game has to be able to continue from the saved point
+    if saved_game.next_player >= saved_game.players.len()
+        || saved_game.round > saved_game.settings.rounds
+    {
+        return Err(format!("{} contains an inconsistent game state!", path));
+    }
+
+    Ok((saved_game, warning))
+}
@@ -0,0 +1,462 @@
+// Optional HTTP server driving a game through the game engine.
+// The public state of the game is served as JSON and actions are accepted as JSON,
+// a bundled page renders the battlefield, f.e. on a TV during a hot-seat game.
+
+use serde::Serialize;
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+use super::colors::error_label;
+use super::engine::GameEngine;
+use super::layout::plain_lines;
+use super::sync::StateHistory;
+use super::types::{
+    actions::Actions, board::UnitInField, trade::TradeOffer, value_types::Quantity,
+};
+use super::version::GameVersion;
+
+// page rendering the battlefield from the JSON state
+const VIEWER_PAGE: &str = include_str!("viewer.html");
+
+// actions are short, longer bodies are refused without being read
+const MAX_BODY_LENGTH: usize = 64 * 1024;
+
+// clients are served one by one, so an idle client is dropped rather than holding up the game
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// State of the game everybody can see, without the kingdoms of the players
+#[derive(Serialize)]
+struct PublicState<'a> {
+    round: usize,
+    rounds: usize,
+    current_player: &'a str,
+    action_points: Quantity,
+    players: Vec<&'a str>,
+    is_over: bool,
+    winner: Option<String>,
+    // trade offers waiting for the answer of the current player
+    offers: Vec<&'a TradeOffer>,
+    // number of fields every player leads
+    standings: HashMap<String, usize>,
+    width: usize,
+    height: usize,
+    // empty when the battlefield is hidden in the fog of war
+    fields: Vec<FieldView<'a>>,
+}
+
+/// One field of the battlefield in the public state
+#[derive(Serialize)]
+struct FieldView<'a> {
+    x: usize,
+    y: usize,
+    label: String,
+    leader: String,
+    units: &'a [UnitInField],
+}
+
+/// Passwords protecting a served game, a game without them is open to everybody
+#[derive(Default, Clone)]
+pub struct Access {
+    /// needed to send actions, also allows watching
+    pub player_password: Option<String>,
+    /// needed to watch the game
+    pub observer_password: Option<String>,
+}
+
+impl Access {
+    /// Check whether a client may send actions
+    ///
+    /// Params
+    /// ---
+    /// - password: value of the 'X-Password' header, if any
+    fn can_play(&self, password: Option<&str>) -> bool {
+        match &self.player_password {
+            Some(expected) => password == Some(expected.as_str()),
+            None => true,
+        }
+    }
+
+    /// Check whether a client may watch the game, players of a protected game always can
+    ///
+    /// A game protected only by the player password is private, so watching it
+    /// needs the player password as well.
+    ///
+    /// Params
+    /// ---
+    /// - password: value of the 'X-Password' header, if any
+    fn can_watch(&self, password: Option<&str>) -> bool {
+        match (&self.observer_password, &self.player_password) {
+            (Some(expected), _) => {
+                password == Some(expected.as_str())
+                    || (self.player_password.is_some() && self.can_play(password))
+            }
+            (None, Some(_)) => self.can_play(password),
+            (None, None) => true,
+        }
+    }
+}
+
+/// Result of an action sent to the server
+#[derive(Serialize)]
+struct ActionResult {
+    ok: bool,
+    message: String,
+}
+
+/// Serve the game over HTTP until the process is stopped
+///
+/// Routes
+/// ---
+/// - GET /: page rendering the battlefield
+/// - GET /version: version of the server, '{"engine": "0.1.0", "rules": 2}'
+/// - GET /state: public state of the game as JSON (observers and players)
+/// - GET /state?since=<seq>: only the changes since the acknowledged state,
+///   '{"seq": n, "full": false, "changes": {...}}', the whole state is sent
+///   as '{"seq": n, "full": true, "state": {...}}' every now and then
+/// - POST /action: action of the current player as JSON, f.e. '"Harvest"'
+///   or '{"Conquer": [0, 0, "Archer", 10]}' (players only),
+///   trade offers listed in the state are answered with
+///   '{"AnswerTrade": {"from": "Alice", "accept": true}}'
+///
+/// Passwords are sent in the 'X-Password' header, the page takes it
+/// from its address, f.e. '/?password=secret'.
+/// Clients can send their version in the 'X-Game-Version' header, f.e. '0.1.0/1',
+/// requests of clients with different rules are refused.
+/// Bots play their turns on their own right after the human before them.
+///
+/// Params
+/// ---
+/// - address: address to listen on, f.e. '127.0.0.1:8080'
+/// - engine: game to be played
+/// - access: passwords protecting the game
+///
+/// Returns
+/// ---
+/// - Err(String) if the server cannot listen on the address
+pub fn serve(address: &str, mut engine: GameEngine, access: Access) -> Result<(), String> {
+    let listener = TcpListener::bind(address)
+        .map_err(|error| format!("Cannot listen on {}: {}", address, error))?;
+
+    println!(
+        "\nThe game is served on http://{}/, send actions to http://{}/action.\n",
+        address, address
+    );
+
+    engine.start_turn();
+    play_bots(&mut engine);
+
+    let mut history = StateHistory::new();
+    for stream in listener.incoming().flatten() {
+        // a broken connection only affects its own request
+        if let Err(error) = handle_connection(stream, &mut engine, &mut history, &access) {
+            println!("\n{} {}\n", error_label(), error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Answer one HTTP request
+///
+/// Params
+/// ---
+/// - stream: connection of the client
+/// - engine: game to be played
+/// - history: recent states sent to the clients
+/// - access: passwords protecting the game
+///
+/// Returns
+/// ---
+/// - Err(String) if the request could not be read or answered
+fn handle_connection(
+    mut stream: TcpStream,
+    engine: &mut GameEngine,
+    history: &mut StateHistory,
+    access: &Access,
+) -> Result<(), String> {
+    stream
+        .set_read_timeout(Some(CONNECTION_TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(CONNECTION_TIMEOUT)))
+        .map_err(|error| format!("Cannot set up the connection: {}", error))?;
+
+    let mut reader = BufReader::new(&stream);
+
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .map_err(|error| format!("Cannot read the request: {}", error))?;
+
+    // only the length of the body, the password and the version are needed from the headers
+    let mut content_length = 0;
+    let mut password: Option<String> = None;
+    let mut client_version: Option<String> = None;
+    loop {
+        let mut header = String::new();
+        reader
+            .read_line(&mut header)
+            .map_err(|error| format!("Cannot read the request: {}", error))?;
+
+        let header = header.trim();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+            if name.eq_ignore_ascii_case("x-password") {
+                password = Some(value.trim().to_string());
+            }
+            if name.eq_ignore_ascii_case("x-game-version") {
+                client_version = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    // the length comes from the client, so it is checked before anything is allocated
+    let too_large = content_length > MAX_BODY_LENGTH;
+    let mut body = Vec::new();
+    if !too_large {
+        body.resize(content_length, 0);
+        reader
+            .read_exact(&mut body)
+            .map_err(|error| format!("Cannot read the request: {}", error))?;
+    }
+
+    let password = password.as_deref();
+    let denied = (
+        "401 Unauthorized",
+        "application/json",
+        to_json(&ActionResult {
+            ok: false,
+            message: String::from("Wrong password, this game is private."),
+        }),
+    );
+
+    let mut words = request_line.split_whitespace();
+    let target = words.next().zip(words.next());
+    let (path, query) = match target {
+        Some((method, target)) => {
+            let (path, query) = target.split_once('?').unwrap_or((target, ""));
+            (Some((method, path)), query)
+        }
+        None => (None, ""),
+    };
+    // sequence number of the state the client has already received
+    let since = query
+        .split('&')
+        .find_map(|parameter| parameter.strip_prefix("since="))
+        .map(|since| since.parse().unwrap_or(0));
+
+    // clients built with different rules would show a different game than the one played
+    let compatibility = match client_version.as_deref().map(GameVersion::parse) {
+        None => Ok(None),
+        Some(Some(version)) => version.check("The client"),
+        Some(None) => Err(String::from(
+            "The 'X-Game-Version' header has to be 'engine/rules', f.e. '0.1.0/1'.",
+        )),
+    };
+    let warning = match &compatibility {
+        Ok(Some(warning)) => format!("Warning: 299 - \"{}\"\r\n", warning),
+        _ => String::new(),
+    };
+
+    // the page itself carries no information about the game
+    let (status, content_type, response) = match path {
+        Some(("GET", "/version")) => (
+            "200 OK",
+            "application/json",
+            to_json(&GameVersion::current()),
+        ),
+        _ if too_large => (
+            "413 Payload Too Large",
+            "application/json",
+            to_json(&ActionResult {
+                ok: false,
+                message: format!("The request is longer than {} bytes.", MAX_BODY_LENGTH),
+            }),
+        ),
+        _ if compatibility.is_err() => (
+            "409 Conflict",
+            "application/json",
+            to_json(&ActionResult {
+                ok: false,
+                message: compatibility.err().unwrap_or_default(),
+            }),
+        ),
+        Some(("GET", "/")) => ("200 OK", "text/html", VIEWER_PAGE.to_string()),
+        Some(("GET", "/state")) if !access.can_watch(password) => denied,
+        Some(("GET", "/state")) => {
+            let state = public_state(engine);
+            let response = match since {
+                Some(since) => {
+                    history.record(state);
+                    history.update(since)
+                }
+                None => state,
+            };
+            ("200 OK", "application/json", to_json(&response))
+        }
+        Some(("POST", "/action")) if !access.can_play(password) => denied,
+        Some(("POST", "/action")) => {
+            let result = perform_action(engine, &body);
+            let status = match result.ok {
+                true => "200 OK",
+                false => "400 Bad Request",
+            };
+            (status, "application/json", to_json(&result))
+        }
+        _ => ("404 Not Found", "text/plain", String::from("Not found")),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\nX-Game-Version: {}\r\n{}Connection: close\r\n\r\n{}",
+        status,
+        content_type,
+        response.len(),
+        GameVersion::current(),
+        warning,
+        response
+    )
+    .map_err(|error| format!("Cannot send the response: {}", error))
+}
+
+/// Perform an action of the current player, then let the bots play
+///
+/// Params
+/// ---
+/// - engine: game to be played
+/// - body: body of the request with the action as JSON
+///
+/// Returns
+/// ---
+/// - result of the action
+fn perform_action(engine: &mut GameEngine, body: &[u8]) -> ActionResult {
+    let action: Actions = match serde_json::from_slice(body) {
+        Ok(action) => action,
+        Err(error) => {
+            return ActionResult {
+                ok: false,
+                message: format!("Not a valid action: {}", error),
+            }
+        }
+    };
+
+    let result = apply_action(engine, action);
+    play_bots(engine);
+
+    // the notifications are formatted as table rows for the terminal
+    match result {
+        Ok(message) => ActionResult {
+            ok: true,
+            message: plain_lines(&message),
+        },
+        Err(message) => ActionResult {
+            ok: false,
+            message: plain_lines(&message),
+        },
+    }
+}
+
+/// Perform an action and start the turn of the next player, if the turn has passed
+///
+/// Params
+/// ---
+/// - engine: game to be played
+/// - action: action of the current player
+///
+/// Returns
+/// ---
+/// - Ok(String) describing the result of the action
+/// - Err(String) if the action cannot be performed
+fn apply_action(engine: &mut GameEngine, action: Actions) -> Result<String, String> {
+    let turn = (engine.round(), engine.current_player().nick.clone());
+    let notification = engine.apply(action)?;
+
+    if !engine.is_over() && turn != (engine.round(), engine.current_player().nick.clone()) {
+        engine.start_turn();
+    }
+
+    Ok(notification)
+}
+
+/// Let bots play until it is a human's turn or the game is over
+///
+/// Params
+/// ---
+/// - engine: game to be played
+fn play_bots(engine: &mut GameEngine) {
+    while !engine.is_over() {
+        let Some(action) = engine.bot_action() else {
+            return;
+        };
+
+        // a bot whose action fails gives up the rest of its turn
+        if apply_action(engine, action).is_err() {
+            let _ = apply_action(engine, Actions::EndTurn);
+        }
+    }
+}
+
+/// Describe the public state of the game as JSON
+///
+/// Params
+/// ---
+/// - engine: game to be described
+fn public_state(engine: &GameEngine) -> serde_json::Value {
+    let game_plan = engine.game_plan();
+
+    // everybody can see the screen, so nobody sees through the fog of war
+    let fields = match engine.settings().fog_of_war {
+        true => Vec::new(),
+        false => (1..=game_plan.number_of_fields())
+            .filter_map(|number| game_plan.field_coordinates(number))
+            .filter_map(|(x, y)| game_plan.field(x, y))
+            .map(|field| {
+                let (x, y) = field.coordinates();
+                FieldView {
+                    x,
+                    y,
+                    label: field.label(),
+                    leader: field.map_owner(),
+                    units: field.units(),
+                }
+            })
+            .collect(),
+    };
+
+    let state = PublicState {
+        round: engine.round().min(engine.settings().rounds),
+        rounds: engine.settings().rounds,
+        current_player: &engine.current_player().nick,
+        action_points: engine.action_points(),
+        players: engine
+            .players()
+            .iter()
+            .map(|player| player.nick.as_str())
+            .collect(),
+        offers: engine
+            .players()
+            .iter()
+            .flat_map(|player| player.offers())
+            .filter(|offer| offer.to == engine.current_player().nick)
+            .collect(),
+        is_over: engine.is_over(),
+        winner: engine.winner(),
+        standings: game_plan.standings(),
+        width: game_plan.width(),
+        height: game_plan.height(),
+        fields,
+    };
+
+    serde_json::to_value(&state).unwrap_or_default()
+}
+
+/// Serialize a response into JSON
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| String::from("null"))
+}
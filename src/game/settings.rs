@@ -0,0 +1,97 @@
+// Settings of the game which are chosen before the game starts.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{actions::Actions, limits::DEFAULT_BOT_TIME_LIMIT};
+use super::victory::VictoryCondition;
+
+/// Settings of one game
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GameSettings {
+    /// number of rounds to be played
+    pub rounds: usize,
+    /// first round in which fields can be conquered
+    pub conquest_round: usize,
+    /// opponents' units are only visible on fields where the player has units, or scouted
+    #[serde(default)]
+    pub fog_of_war: bool,
+    /// the screen is cleared between the turns of human players sharing the keyboard
+    #[serde(default)]
+    pub privacy_screen: bool,
+    /// milliseconds a bot may take to choose its action
+    #[serde(default = "default_bot_time_limit")]
+    pub bot_time_limit: u64,
+    /// seconds a human player has for the whole turn, None for unlimited turns
+    #[serde(default)]
+    pub turn_time_limit: Option<u64>,
+    /// how the game is won, games saved before it could be chosen are won by the most fields
+    #[serde(default)]
+    pub victory: VictoryCondition,
+    /// what happens to human players once their input ends
+    #[serde(default)]
+    pub on_input_end: InputEnd,
+}
+
+/// What happens to a human player whose input has ended, f.e. when a piped script runs out
+#[derive(PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum InputEnd {
+    /// the game is saved at the start of the player's turn and ends
+    #[default]
+    Save,
+    /// a bot plays for the player until the end of the game
+    Bot,
+}
+
+/// Time limit of bots in games saved before it could be configured
+fn default_bot_time_limit() -> u64 {
+    DEFAULT_BOT_TIME_LIMIT
+}
+
+/// Phases of the game
+#[derive(PartialEq, Clone, Copy)]
+pub enum GamePhase {
+    /// only economy actions are allowed
+    Exploration,
+    /// all actions are allowed
+    Conquest,
+}
+
+impl GameSettings {
+    /// Get the phase of the game in a certain round
+    ///
+    /// Params
+    /// ---
+    /// - round: which round it is
+    pub fn phase(&self, round: usize) -> GamePhase {
+        match round < self.conquest_round {
+            true => GamePhase::Exploration,
+            false => GamePhase::Conquest,
+        }
+    }
+
+    /// Check whether an action can be performed in a certain round
+    ///
+    /// Params
+    /// ---
+    /// - action: action to validate
+    /// - round: which round it is
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(()) if the action is allowed
+    /// - Err(String) with an explanation otherwise
+    pub fn validate_action(&self, action: &Actions, round: usize) -> Result<(), String> {
+        match (self.phase(round), action) {
+            (GamePhase::Exploration, Actions::Conquer(..)) => Err(format!(
+                "║{:^78}║\n║{:^78}║",
+                "Fields cannot be conquered during the exploration phase.",
+                format!("Conquering unlocks in round {}.", self.conquest_round),
+            )),
+            (_, Actions::Scout(..)) if !self.fog_of_war => Err(format!(
+                "║{:^78}║",
+                "There is no fog of war in this game, every field is visible.",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
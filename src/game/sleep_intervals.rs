@@ -1,18 +1,75 @@
-use std::thread::sleep;
+// Pauses of the game between its notifications.
+// How long they last is picked at startup with '--pacing' and handed down to everything
+// that pauses, experienced players can speed the game up or turn the pauses off,
+// the scripted mode plays without any.
+
 use std::time::Duration;
 
-pub fn game_sleep_second() {
-    sleep(Duration::from_millis(1000))
-}
+use super::user_input::interruptible_sleep;
 
-pub fn game_sleep_two_seconds() {
-    sleep(Duration::from_millis(2000))
+/// How long the pauses of the game last
+#[derive(PartialEq, Clone, Copy, Default)]
+pub enum Pacing {
+    /// no pauses at all
+    Off,
+    /// a quarter of the normal pauses
+    Fast,
+    #[default]
+    Normal,
+    /// twice the normal pauses
+    Cinematic,
 }
 
-pub fn game_sleep_half_second() {
-    sleep(Duration::from_millis(500))
-}
+impl Pacing {
+    /// Parse a pacing typed on the command line
+    ///
+    /// Params
+    /// ---
+    /// - word: f.e. 'fast'
+    ///
+    /// Returns
+    /// ---
+    /// - Some(pacing): if the word names a pacing
+    /// - None: otherwise
+    pub fn parse(word: &str) -> Option<Pacing> {
+        match word.to_lowercase().as_str() {
+            "off" => Some(Pacing::Off),
+            "fast" => Some(Pacing::Fast),
+            "normal" => Some(Pacing::Normal),
+            "cinematic" => Some(Pacing::Cinematic),
+            _ => None,
+        }
+    }
+
+    /// Pause after a minor notification, half a second at the normal pacing
+    pub fn short_pause(self) {
+        self.pause_for(500);
+    }
+
+    /// Pause after a notification, a second at the normal pacing
+    pub fn pause(self) {
+        self.pause_for(1000);
+    }
+
+    /// Pause after a long notification, two seconds at the normal pacing
+    pub fn long_pause(self) {
+        self.pause_for(2000);
+    }
+
+    /// Pause between the rounds, a second at the normal pacing
+    pub fn round_pause(self) {
+        self.pause_for(1000);
+    }
+
+    // pressing enter skips the rest of a pause
+    fn pause_for(self, milliseconds: u64) {
+        let milliseconds = match self {
+            Pacing::Off => return,
+            Pacing::Fast => milliseconds / 4,
+            Pacing::Normal => milliseconds,
+            Pacing::Cinematic => milliseconds * 2,
+        };
 
-pub fn game_round_sleep() {
-    game_sleep_second();
+        interruptible_sleep(Duration::from_millis(milliseconds))
+    }
 }
@@ -0,0 +1,191 @@
+// Tracks the state of every player at the end of each round,
+// so that the course of the game can be analyzed in a spreadsheet afterwards.
+// Every player also keeps a tally of their performed actions for the scoreboard of the game.
+
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+
+use super::types::{
+    actions::Actions,
+    board::GamePlan,
+    buildings::BuildingType,
+    player::Player,
+    troops::UnitType,
+    value_types::{Quantity, ResourceValue},
+};
+
+/// Tally of the actions a player has performed during the game
+#[derive(PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct PlayerStats {
+    /// (wood, gold) gained by harvesting
+    pub harvested: ResourceValue,
+    /// units trained, peasants upgraded into units included
+    pub trained: Quantity,
+    /// units sent to the battlefield
+    pub sent: Quantity,
+}
+
+impl PlayerStats {
+    /// Count a performed action
+    ///
+    /// Params
+    /// ---
+    /// - action: the performed action
+    /// - before: (wood, gold) of the player before the action
+    /// - after: (wood, gold) of the player after the action
+    pub fn record_action(&mut self, action: &Actions, before: ResourceValue, after: ResourceValue) {
+        match action {
+            Actions::Harvest => {
+                self.harvested.0 += after.0 - before.0;
+                self.harvested.1 += after.1 - before.1;
+            }
+            Actions::Train(_, quantity) | Actions::Recruit(_, quantity) => self.trained += quantity,
+            Actions::TrainArmy(template) => self.trained += template.quantity(),
+            Actions::Conquer(_, _, _, quantity) => self.sent += quantity,
+            _ => {}
+        }
+    }
+}
+
+/// State of a player at the end of a round
+pub struct RoundRecord {
+    pub round: usize,
+    pub nick: String,
+    pub wood: Quantity,
+    pub gold: Quantity,
+    pub peasants: Quantity,
+    /// available units, in the order of `UnitType::all()`
+    pub units: Vec<Quantity>,
+    /// units of all types, in the kingdom and on the battlefield
+    pub army: Quantity,
+    pub bases: Quantity,
+    pub farms: Quantity,
+    /// fields the player leads
+    pub fields_held: usize,
+}
+
+/// Records of all the rounds of a game
+#[derive(Default)]
+pub struct GameStats {
+    records: Vec<RoundRecord>,
+}
+
+impl GameStats {
+    /// Create stats with no rounds recorded
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the state of every player at the end of a round
+    ///
+    /// Params
+    /// ---
+    /// - round: round which has just ended
+    /// - players: all players of the game
+    /// - game_plan: battlefield of the game
+    pub fn record_round(&mut self, round: usize, players: &[Player], game_plan: &GamePlan) {
+        let standings = game_plan.standings();
+
+        self.records.extend(players.iter().map(|player| {
+            let (wood, gold) = player.supplies();
+
+            RoundRecord {
+                round,
+                nick: player.nick.clone(),
+                wood,
+                gold,
+                peasants: player.peasants(),
+                units: UnitType::all()
+                    .iter()
+                    .map(|unit_type| player.units(*unit_type))
+                    .collect(),
+                army: UnitType::all()
+                    .iter()
+                    .map(|unit_type| player.units_owned(game_plan, *unit_type))
+                    .sum(),
+                bases: player.number_of_buildings(BuildingType::Base),
+                farms: player.number_of_buildings(BuildingType::Farm),
+                fields_held: standings.get(&player.nick).copied().unwrap_or(0),
+            }
+        }));
+    }
+
+    /// All the recorded rounds, in the order they were played
+    pub fn records(&self) -> &[RoundRecord] {
+        &self.records
+    }
+
+    /// Format the records as CSV, one line per player and round
+    ///
+    /// Returns
+    /// ---
+    /// - String containing the header line and the records
+    pub fn to_csv(&self) -> String {
+        let mut header = vec![
+            String::from("round"),
+            String::from("player"),
+            String::from("wood"),
+            String::from("gold"),
+            String::from("peasants"),
+        ];
+        header.extend(
+            UnitType::all()
+                .iter()
+                .map(|unit_type| format!("{}s", unit_type).to_lowercase()),
+        );
+        header.extend([
+            String::from("bases"),
+            String::from("farms"),
+            String::from("fields_held"),
+        ]);
+
+        let mut lines = vec![header.join(",")];
+        lines.extend(self.records.iter().map(|record| {
+            let mut cells = vec![
+                record.round.to_string(),
+                csv_cell(&record.nick),
+                record.wood.to_string(),
+                record.gold.to_string(),
+                record.peasants.to_string(),
+            ];
+            cells.extend(record.units.iter().map(|quantity| quantity.to_string()));
+            cells.extend([
+                record.bases.to_string(),
+                record.farms.to_string(),
+                record.fields_held.to_string(),
+            ]);
+            cells.join(",")
+        }));
+
+        format!("{}\n", lines.join("\n"))
+    }
+
+    /// Export the records into a CSV file
+    ///
+    /// Params
+    /// ---
+    /// - path: path of the CSV file
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) containing a confirmation message
+    /// - Err(String) if the file could not be written
+    pub fn export_csv(&self, path: &str) -> Result<String, String> {
+        fs::write(path, self.to_csv())
+            .map_err(|error| format!("Cannot write to {}: {}", path, error))?;
+
+        Ok(format!(
+            "The statistics of the game have been exported to {}.",
+            path
+        ))
+    }
+}
+
+/// Quote a CSV cell if it contains a separator, a quote or a line break
+fn csv_cell(value: &str) -> String {
+    match value.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
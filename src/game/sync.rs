@@ -0,0 +1,127 @@
+// Delta synchronization of the served state.
+// Clients acknowledge the last state they have received by its sequence number
+// and get only the parts which have changed since then, on big battlefields
+// the fields nobody touched make most of the state. The whole state is sent
+// again every few states, so a client can never drift away from the game.
+
+use serde_json::{json, Map, Value};
+
+use std::collections::VecDeque;
+
+// every state with a sequence number divisible by this is sent whole,
+// older states are forgotten, as no diff is computed from them anymore
+const FULL_RESYNC_INTERVAL: u64 = 16;
+
+/// Recent states of the game, numbered by their sequence numbers
+pub struct StateHistory {
+    states: VecDeque<(u64, Value)>,
+}
+
+impl StateHistory {
+    pub fn new() -> StateHistory {
+        StateHistory {
+            states: VecDeque::new(),
+        }
+    }
+
+    /// Remember the current state, it only gets a new sequence number if it has changed
+    ///
+    /// Params
+    /// ---
+    /// - state: current public state of the game
+    ///
+    /// Returns
+    /// ---
+    /// - sequence number of the state
+    pub fn record(&mut self, state: Value) -> u64 {
+        match self.states.back() {
+            Some((sequence, last)) if *last == state => *sequence,
+            last => {
+                let sequence = last.map_or(1, |(sequence, _)| sequence + 1);
+                self.states.push_back((sequence, state));
+                if self.states.len() > FULL_RESYNC_INTERVAL as usize {
+                    self.states.pop_front();
+                }
+                sequence
+            }
+        }
+    }
+
+    /// Build the state update for a client
+    ///
+    /// Params
+    /// ---
+    /// - acknowledged: sequence number of the last state the client has received
+    ///
+    /// Returns
+    /// ---
+    /// - '{"seq": n, "full": true, "state": {...}}' with the whole state, if the client
+    ///   has no known state or a full resync is due
+    /// - '{"seq": n, "full": false, "changes": {...}}' with the changed parts otherwise
+    pub fn update(&self, acknowledged: u64) -> Value {
+        let Some((sequence, current)) = self.states.back() else {
+            return Value::Null;
+        };
+
+        let known = self
+            .states
+            .iter()
+            .find(|(number, _)| *number == acknowledged)
+            .map(|(_, state)| state);
+
+        let changes = known
+            .filter(|_| acknowledged / FULL_RESYNC_INTERVAL == sequence / FULL_RESYNC_INTERVAL)
+            .and_then(|known| diff(known, current));
+
+        match changes {
+            Some(changes) => json!({"seq": sequence, "full": false, "changes": changes}),
+            None => json!({"seq": sequence, "full": true, "state": current}),
+        }
+    }
+}
+
+/// Compute the top-level values of the state which have changed
+///
+/// Only the changed fields of the battlefield are listed,
+/// the client replaces them by their coordinates.
+///
+/// Params
+/// ---
+/// - old: state acknowledged by the client
+/// - new: current state
+///
+/// Returns
+/// ---
+/// - Some(object) with the changed values
+/// - None: if the states cannot be compared, f.e. the number of fields differs
+pub fn diff(old: &Value, new: &Value) -> Option<Value> {
+    let (old, new) = (old.as_object()?, new.as_object()?);
+
+    let mut changes = Map::new();
+    for (key, value) in new {
+        let previous = old.get(key);
+        if previous == Some(value) {
+            continue;
+        }
+
+        let changed = match (key.as_str(), previous.and_then(Value::as_array), value) {
+            ("fields", Some(previous), Value::Array(fields)) => {
+                if previous.len() != fields.len() {
+                    return None;
+                }
+                Value::Array(
+                    fields
+                        .iter()
+                        .zip(previous)
+                        .filter(|(field, previous)| field != previous)
+                        .map(|(field, _)| field.clone())
+                        .collect(),
+                )
+            }
+            _ => value.clone(),
+        };
+        changes.insert(key.clone(), changed);
+    }
+
+    Some(Value::Object(changes))
+}
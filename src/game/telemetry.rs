@@ -0,0 +1,328 @@
+// Opt-in balance telemetry.
+// With '--telemetry', a few anonymous numbers about every finished game are appended
+// to a local file, nothing is ever sent anywhere. 'wartycoon telemetry report'
+// summarizes them, so that the balance values can be tuned from real games.
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
+use super::settings::GameSettings;
+use super::types::{
+    board::GamePlan, player::Player, plurals::format_number, troops::UnitType,
+    value_types::Quantity,
+};
+use super::version::GameVersion;
+use super::victory::VictoryCondition;
+
+// file the metrics are appended to, one game per line
+pub const DEFAULT_TELEMETRY_FILE: &str = "wartycoon_telemetry.jsonl";
+
+/// Settings of the game the metrics come from
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+pub struct Variant {
+    pub width: usize,
+    pub height: usize,
+    pub players: usize,
+    pub rounds: usize,
+    pub conquest_round: usize,
+    pub fog_of_war: bool,
+    #[serde(default)]
+    pub victory: VictoryCondition,
+}
+
+/// Anonymous balance metrics of one finished game, without the nicks of the players
+#[derive(Serialize, Deserialize)]
+pub struct GameMetrics {
+    pub version: GameVersion,
+    pub variant: Variant,
+    // games can be quit or finished early
+    pub rounds_played: usize,
+    // units of every type owned at the end of the game, in the kingdoms and on the battlefield
+    pub unit_mix: HashMap<String, Quantity>,
+    // who won and how, f.e. ["HUMAN", "ARCHER ARMY"], None for a draw
+    pub winner_tags: Option<Vec<String>>,
+}
+
+impl GameMetrics {
+    /// Collect the metrics of a finished game
+    ///
+    /// Params
+    /// ---
+    /// - players: players of the game
+    /// - game_plan: battlefield at the end of the game
+    /// - settings: settings of the game
+    /// - rounds_played: number of the last round played
+    /// - victor: winner by the victory condition, if the game was not decided by the fields
+    pub fn collect(
+        players: &[Player],
+        game_plan: &GamePlan,
+        settings: &GameSettings,
+        rounds_played: usize,
+        victor: Option<&str>,
+    ) -> GameMetrics {
+        let unit_mix = UnitType::all()
+            .iter()
+            .map(|unit_type| {
+                let total = players
+                    .iter()
+                    .map(|player| player.units_owned(game_plan, *unit_type))
+                    .sum();
+                (unit_type.to_string(), total)
+            })
+            .collect();
+
+        let winner_tags = victor
+            .map(String::from)
+            .or_else(|| final_winner(game_plan))
+            .and_then(|nick| players.iter().find(|player| player.nick == nick))
+            .map(|winner| strategy_tags(winner, game_plan));
+
+        GameMetrics {
+            version: GameVersion::current(),
+            variant: Variant {
+                width: game_plan.width(),
+                height: game_plan.height(),
+                players: players.len(),
+                rounds: settings.rounds,
+                conquest_round: settings.conquest_round,
+                fog_of_war: settings.fog_of_war,
+                victory: settings.victory,
+            },
+            rounds_played,
+            unit_mix,
+            winner_tags,
+        }
+    }
+}
+
+/// Find the winner of a finished game, the same way the game is evaluated
+///
+/// Returns
+/// ---
+/// - Some(nick): if a single player won the most fields
+/// - None: if the game ended in a draw
+fn final_winner(game_plan: &GamePlan) -> Option<String> {
+    let mut wins: HashMap<String, usize> = HashMap::new();
+    for winner in (1..=game_plan.number_of_fields())
+        .filter_map(|number| game_plan.field_coordinates(number))
+        .filter_map(|(x, y)| game_plan.field(x, y))
+        .filter_map(|field| field.evaluate_field())
+    {
+        *wins.entry(winner).or_insert(0) += 1;
+    }
+
+    let highest = wins.values().copied().max()?;
+    let mut best = wins.into_iter().filter(|(_, won)| *won == highest);
+
+    match (best.next(), best.next()) {
+        (Some((winner, _)), None) => Some(winner),
+        _ => None,
+    }
+}
+
+/// Describe how a player played, without revealing who they are
+///
+/// Params
+/// ---
+/// - player: the described player
+/// - game_plan: battlefield at the end of the game
+///
+/// Returns
+/// ---
+/// - who controlled the player, f.e. 'HUMAN' or 'ECONOMIC BOT',
+///   and the unit type making most of their army, f.e. 'ARCHER ARMY'
+fn strategy_tags(player: &Player, game_plan: &GamePlan) -> Vec<String> {
+    let controller = match player.bot() {
+        Some(strategy) => format!("{} BOT", strategy),
+        None => String::from("HUMAN"),
+    };
+
+    let army = UnitType::all()
+        .iter()
+        .map(|unit_type| (player.units_owned(game_plan, *unit_type), *unit_type))
+        .filter(|(quantity, _)| *quantity > 0)
+        .max_by_key(|(quantity, _)| *quantity);
+
+    let army = match army {
+        Some((_, unit_type)) => format!("{} ARMY", unit_type),
+        None => String::from("NO ARMY"),
+    };
+
+    vec![controller, army]
+}
+
+/// Append the metrics of a game to the telemetry file
+///
+/// Params
+/// ---
+/// - metrics: metrics of the finished game
+/// - path: path of the telemetry file
+///
+/// Returns
+/// ---
+/// - Ok(String) containing a confirmation message
+/// - Err(String) if the file could not be written
+pub fn record_game(metrics: &GameMetrics, path: &str) -> Result<String, String> {
+    let line = serde_json::to_string(metrics)
+        .map_err(|error| format!("Cannot serialize the game metrics: {}", error))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|error| format!("Cannot open {}: {}", path, error))?;
+    writeln!(file, "{}", line).map_err(|error| format!("Cannot write to {}: {}", path, error))?;
+
+    Ok(format!(
+        "Anonymous balance metrics of the game have been added to {}.",
+        path
+    ))
+}
+
+/// Summarize the metrics of all the recorded games
+///
+/// Params
+/// ---
+/// - path: path of the telemetry file
+///
+/// Returns
+/// ---
+/// - Ok(Vec<String>) with the lines of the report
+/// - Err(String) if the file could not be read or contains no games
+pub fn telemetry_report(path: &str) -> Result<Vec<String>, String> {
+    let contents =
+        fs::read_to_string(path).map_err(|error| format!("Cannot read {}: {}", path, error))?;
+
+    let lines: Vec<&str> = contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let games: Vec<GameMetrics> = lines
+        .iter()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+
+    if games.is_empty() {
+        return Err(format!("{} does not contain any recorded games.", path));
+    }
+
+    let mut report = vec![format!(
+        "{} games recorded in {}",
+        format_number(games.len()),
+        path
+    )];
+    if lines.len() > games.len() {
+        report.push(format!(
+            "{} unreadable lines were left out",
+            format_number(lines.len() - games.len())
+        ));
+    }
+
+    let played: usize = games.iter().map(|game| game.rounds_played).sum();
+    let planned: usize = games.iter().map(|game| game.variant.rounds).sum();
+    report.push(format!(
+        "Average length: {:.1} rounds played of {:.1} planned",
+        played as f64 / games.len() as f64,
+        planned as f64 / games.len() as f64
+    ));
+
+    // wins of every tag, the most successful first
+    let decided: Vec<&Vec<String>> = games
+        .iter()
+        .filter_map(|game| game.winner_tags.as_ref())
+        .collect();
+    report.push(format!(
+        "Draws: {} ({:.0} %)",
+        format_number(games.len() - decided.len()),
+        percent(games.len() - decided.len(), games.len())
+    ));
+
+    let mut wins: Vec<(String, usize)> = Vec::new();
+    for tag in decided.iter().flat_map(|tags| tags.iter()) {
+        match wins.iter_mut().find(|(known, _)| known == tag) {
+            Some((_, count)) => *count += 1,
+            None => wins.push((tag.clone(), 1)),
+        }
+    }
+    wins.sort_by(|(first, first_wins), (second, second_wins)| {
+        second_wins.cmp(first_wins).then(first.cmp(second))
+    });
+
+    if !wins.is_empty() {
+        report.push(String::from("Winners by strategy:"));
+    }
+    report.extend(wins.iter().map(|(tag, count)| {
+        format!(
+            "  {}: {} ({:.0} % of the decided games)",
+            tag,
+            format_number(*count),
+            percent(*count, decided.len())
+        )
+    }));
+
+    // share of every unit type in all the armies
+    let units: Vec<(String, Quantity)> = UnitType::all()
+        .iter()
+        .map(|unit_type| {
+            let name = unit_type.to_string();
+            let total = games
+                .iter()
+                .filter_map(|game| game.unit_mix.get(&name))
+                .sum();
+            (name, total)
+        })
+        .collect();
+    let all_units: Quantity = units.iter().map(|(_, total)| total).sum();
+
+    report.push(String::from("Unit mix at the end of the games:"));
+    report.extend(units.iter().map(|(name, total)| {
+        format!(
+            "  {}: {:.0} %",
+            name,
+            percent(*total as usize, all_units as usize)
+        )
+    }));
+
+    // how often every variant was played
+    let mut variants: Vec<(&Variant, usize)> = Vec::new();
+    for game in &games {
+        match variants
+            .iter_mut()
+            .find(|(known, _)| **known == game.variant)
+        {
+            Some((_, count)) => *count += 1,
+            None => variants.push((&game.variant, 1)),
+        }
+    }
+
+    report.push(String::from("Variants played:"));
+    report.extend(variants.iter().map(|(variant, count)| {
+        format!(
+            "  {} x {}, {} players, {} rounds, conquest from round {}{}, {}: {} games",
+            variant.width,
+            variant.height,
+            variant.players,
+            variant.rounds,
+            variant.conquest_round,
+            match variant.fog_of_war {
+                true => ", fog of war",
+                false => "",
+            },
+            variant.victory,
+            format_number(*count)
+        )
+    }));
+
+    Ok(report)
+}
+
+/// Compute a share in percents, zero if there is nothing to share
+fn percent(part: usize, whole: usize) -> f64 {
+    match whole {
+        0 => 0.0,
+        whole => part as f64 * 100.0 / whole as f64,
+    }
+}
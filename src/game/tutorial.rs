@@ -0,0 +1,369 @@
+// Interactive tutorial for new players.
+// The tutorial is a short game on a tiny battlefield, driven over the engine. Every lesson
+// explains one action, offers only the commands it needs and moves on once the action
+// has been performed. The opponent is a training dummy following a fixed script,
+// so the first battle of every new player looks the same.
+
+use super::commands::{find_command, Command, COMMANDS};
+use super::engine::GameEngine;
+use super::notifications::{
+    print_action_error, print_battle_log, print_kingdom_news, print_lesson, print_round_action,
+    print_tutorial_over,
+};
+use super::player_action::{
+    confirm_action, get_build_action, get_conquer_action, get_target_field, get_train_action,
+};
+use super::rng::GameRng;
+use super::settings::{GameSettings, InputEnd};
+use super::sleep_intervals::Pacing;
+use super::types::{
+    actions::Actions,
+    board::GamePlan,
+    buildings::BuildingType,
+    limits::DEFAULT_BOT_TIME_LIMIT,
+    player::Player,
+    plurals::plural_suffix,
+    troops::UnitType,
+    value_types::{Quantity, ResourceValue},
+};
+use super::user_input::get_line;
+use super::victory::VictoryCondition;
+
+// nicks of the player and of their opponent
+const PLAYER_NICK: &str = "Commander";
+const DUMMY_NICK: &str = "Training Dummy";
+
+// (wood, gold) the player starts with, enough for a base and a few units
+const STARTING_SUPPLIES: ResourceValue = (300, 220);
+
+// the dummy never trains, it only pays the upkeep of its warriors
+const DUMMY_SUPPLIES: ResourceValue = (0, 50);
+const DUMMY_WARRIORS: Quantity = 5;
+
+// field the dummy occupies, the player is taught to attack it
+const DUMMY_FIELD: (usize, usize) = (1, 0);
+
+// the tutorial ends after its lessons, the rounds are only a limit for players who keep ending turns
+const TUTORIAL_ROUNDS: usize = 10;
+
+// the dummy does not draw anything, the seed only keeps the battles the same
+const TUTORIAL_SEED: u64 = 1;
+
+// commands offered in every lesson, next to the ones the lesson is about
+const ALWAYS_OFFERED: [Command; 4] = [Command::Stats, Command::Map, Command::End, Command::Quit];
+
+/// One lesson of the tutorial
+struct Lesson {
+    /// what the lesson teaches
+    title: &'static str,
+    /// lines explaining the lesson
+    explanation: &'static [&'static str],
+    /// commands the lesson is about
+    commands: &'static [Command],
+    /// whether a performed action completes the lesson
+    learned: fn(&Actions) -> bool,
+}
+
+// the lessons, in the order they are taught, they fit into the action points of two turns
+const LESSONS: [Lesson; 4] = [
+    Lesson {
+        title: "Build a base",
+        explanation: &[
+            "Every kingdom starts with a BASE, without one no units can be trained.",
+            "Your lord has sent you wood and gold, build one base with them.",
+            "Building takes 2 of the 3 action points of your turn.",
+        ],
+        commands: &[Command::Build],
+        learned: |action| matches!(action, Actions::Build(BuildingType::Base, _)),
+    },
+    Lesson {
+        title: "Train units",
+        explanation: &[
+            "Units fight for you on the battlefield and take up room in your bases.",
+            "Train 10 ARCHERS and keep the rest of the gold for their upkeep.",
+            "Training one unit type takes the last action point, the turn then ends.",
+        ],
+        commands: &[Command::Train],
+        learned: |action| matches!(action, Actions::Train(..)),
+    },
+    Lesson {
+        title: "Harvest resources",
+        explanation: &[
+            "Your kingdom needs more wood and gold to grow.",
+            "Harvesting brings both of them in and takes 2 action points.",
+            "Mind the news at the start of the turn: armies cost gold every turn.",
+        ],
+        commands: &[Command::Harvest],
+        learned: |action| matches!(action, Actions::Harvest),
+    },
+    Lesson {
+        title: "Conquer a field",
+        explanation: &[
+            "The Training Dummy has sent 5 WARRIORS to field (1,0).",
+            "Send your units there, at the end of the round the armies fight a battle.",
+            "The stronger army leads the field, the player leading most fields wins.",
+        ],
+        commands: &[Command::Conquer],
+        learned: |action| matches!(action, Actions::Conquer(..)),
+    },
+];
+
+/// Play the tutorial, teaching a new player the basic actions
+///
+/// Params
+/// ---
+/// - pacing: how long the game pauses after the lessons and notifications
+pub fn play_tutorial(pacing: Pacing) {
+    let mut player = Player::new(PLAYER_NICK);
+    player.receive_resources(STARTING_SUPPLIES);
+
+    let mut dummy = Player::new(DUMMY_NICK);
+    dummy.receive_resources(DUMMY_SUPPLIES);
+    dummy.receive_units(UnitType::Warrior, DUMMY_WARRIORS);
+
+    let settings = GameSettings {
+        rounds: TUTORIAL_ROUNDS,
+        conquest_round: 1,
+        fog_of_war: false,
+        privacy_screen: false,
+        bot_time_limit: DEFAULT_BOT_TIME_LIMIT,
+        turn_time_limit: None,
+        victory: VictoryCondition::MostFields,
+        on_input_end: InputEnd::default(),
+    };
+
+    let mut engine = GameEngine::new(
+        vec![player, dummy],
+        GamePlan::new(2, 1),
+        settings,
+        GameRng::new(TUTORIAL_SEED),
+    );
+    start_turn(&mut engine, pacing);
+
+    // round of the last lesson, the armies sent in it fight at its end
+    let mut lesson_round = engine.round();
+    for (index, lesson) in LESSONS.iter().enumerate() {
+        print_lesson(
+            index + 1,
+            LESSONS.len(),
+            lesson.title,
+            lesson.explanation,
+            pacing,
+        );
+
+        loop {
+            let action = match get_lesson_action(&engine, lesson) {
+                Some(action) => action,
+                None => {
+                    println!("\nThe tutorial has been left, it can be started again any time.\n");
+                    return;
+                }
+            };
+
+            lesson_round = engine.round();
+            let performed = perform(&mut engine, action.clone(), pacing);
+            play_dummy_turn(&mut engine, pacing);
+
+            if engine.is_over() {
+                println!(
+                    "\nThe tutorial has run out of rounds, start it again to finish the lessons.\n"
+                );
+                return;
+            }
+            if performed && (lesson.learned)(&action) {
+                break;
+            }
+        }
+    }
+
+    // the round is finished, so that the armies fight
+    while engine.round() == lesson_round && !engine.is_over() {
+        match engine.current_player().nick == DUMMY_NICK {
+            true => play_dummy_turn(&mut engine, pacing),
+            false => {
+                perform(&mut engine, Actions::EndTurn, pacing);
+            }
+        }
+    }
+
+    println!("\n{}", engine.game_plan().render_map());
+    print_tutorial_over(engine.winner().as_deref() == Some(PLAYER_NICK));
+}
+
+/// Get the action of the player, offering only the commands of the lesson
+///
+/// Params
+/// ---
+/// - engine: the tutorial game, it is the player's turn
+/// - lesson: the current lesson
+///
+/// Returns
+/// ---
+/// - Some(action): the confirmed action of the player
+/// - None: if the player chose to leave the tutorial
+fn get_lesson_action(engine: &GameEngine, lesson: &Lesson) -> Option<Actions> {
+    let player = engine.current_player();
+    let game_plan = engine.game_plan();
+
+    // the numbers of the commands are the same as in the game, so they are learned as well
+    let offered: Vec<String> = COMMANDS
+        .iter()
+        .filter(|binding| {
+            lesson.commands.contains(&binding.command) || ALWAYS_OFFERED.contains(&binding.command)
+        })
+        .map(|binding| {
+            // the longest lower case alias is the most readable one, f.e. 'quit' rather than 'q'
+            let name = binding
+                .aliases
+                .iter()
+                .filter(|alias| alias.chars().all(|letter| letter.is_lowercase()))
+                .max_by_key(|alias| alias.len())
+                .unwrap_or(&binding.aliases[0]);
+            format!("'{}' {}", binding.number, name)
+        })
+        .collect();
+
+    loop {
+        println!(
+            "\nRound {}, {} action point{} left. Available now: {}\n",
+            engine.round(),
+            engine.action_points(),
+            plural_suffix(engine.action_points(), "s"),
+            offered.join(", ")
+        );
+
+        let line = get_line();
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or("");
+        let arguments: Vec<&str> = words.collect();
+
+        let action = match find_command(command) {
+            Some(command)
+                if !lesson.commands.contains(&command) && !ALWAYS_OFFERED.contains(&command) =>
+            {
+                println!(
+                    "\nThat comes later, this lesson is about something else: {}.\n",
+                    lesson.title
+                );
+                None
+            }
+            Some(Command::Build) => get_build_action(player),
+            Some(Command::Train) => get_train_action(player),
+            Some(Command::Harvest) => Some(Actions::Harvest),
+            Some(Command::Conquer) => get_target_field(game_plan, &arguments, "conquer")
+                .and_then(|(x, y)| get_conquer_action(player, x, y)),
+            Some(Command::End) => Some(Actions::EndTurn),
+            Some(Command::Quit) => return None,
+            Some(Command::Map) => {
+                println!("\n{}", game_plan.render_map());
+                None
+            }
+            Some(Command::Stats) => {
+                println!("\n{}\n", player.status(engine.round(), game_plan, "during"));
+                None
+            }
+            Some(_) => None,
+            None => {
+                println!("\nUnknown command! Please, pick one of the available ones.\n");
+                None
+            }
+        };
+
+        if let Some(action) = action {
+            if confirm_action(&action) {
+                return Some(action);
+            }
+        }
+    }
+}
+
+/// Play the turns of the dummy until it is the player's turn again
+///
+/// The dummy sends all the units it has at home to its field, and ends its turn.
+///
+/// Params
+/// ---
+/// - engine: the tutorial game
+/// - pacing: how long the game pauses after the notifications
+fn play_dummy_turn(engine: &mut GameEngine, pacing: Pacing) {
+    while engine.current_player().nick == DUMMY_NICK && !engine.is_over() {
+        let dummy = engine.current_player();
+        let action = match dummy.has_fighters_available() {
+            true => {
+                let (x, y) = DUMMY_FIELD;
+                Actions::Conquer(
+                    x,
+                    y,
+                    UnitType::Warrior,
+                    dummy.send_max_units(UnitType::Warrior),
+                )
+            }
+            false => Actions::EndTurn,
+        };
+
+        perform(engine, action, pacing);
+    }
+}
+
+/// Perform an action, print its result and start the next turn, if the turn has passed
+///
+/// Params
+/// ---
+/// - engine: the tutorial game
+/// - action: action of the current player
+/// - pacing: how long the game pauses after the notifications
+///
+/// Returns
+/// ---
+/// - true: if the action has been performed
+/// - false: if it could not be performed
+fn perform(engine: &mut GameEngine, action: Actions, pacing: Pacing) -> bool {
+    let round = engine.round();
+    let nick = engine.current_player().nick.clone();
+    let ends_turn = action == Actions::EndTurn;
+
+    let result = engine.apply(action);
+    let player = engine
+        .players()
+        .iter()
+        .find(|player| player.nick == nick)
+        .unwrap_or(engine.current_player());
+    match &result {
+        // the news of the next turn show that the turn has ended
+        Ok(_) if ends_turn => {}
+        Ok(notification) => print_round_action(
+            notification,
+            player,
+            engine.game_plan(),
+            round,
+            false,
+            pacing,
+        ),
+        Err(error) => print_action_error(error, player, round, pacing),
+    }
+
+    if engine.round() != round {
+        print_battle_log(engine.battles(), round, pacing);
+    }
+    if !engine.is_over() && (round, nick) != (engine.round(), engine.current_player().nick.clone())
+    {
+        start_turn(engine, pacing);
+    }
+
+    result.is_ok()
+}
+
+/// Start the turn of the current player, printing the news of the player's kingdom
+///
+/// Params
+/// ---
+/// - engine: the tutorial game
+/// - pacing: how long the game pauses after the news
+fn start_turn(engine: &mut GameEngine, pacing: Pacing) {
+    let news = engine.start_turn();
+    let player = engine.current_player();
+
+    if let (Some(news), true) = (news, player.nick == PLAYER_NICK) {
+        print_kingdom_news(&news, player, engine.round(), pacing);
+    }
+}
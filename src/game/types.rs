@@ -1,9 +1,15 @@
-pub(super) mod actions;
-pub(super) mod board;
-pub(super) mod buildings;
-pub(super) mod limits;
+pub mod actions;
+pub mod board;
+pub mod buildings;
+pub mod errors;
+pub mod items;
+pub mod limits;
+pub mod modifiers;
 pub mod player;
-pub(super) mod properties;
-pub(super) mod resources;
-pub(super) mod troops;
-pub(super) mod value_types;
+pub mod plurals;
+pub mod properties;
+pub mod resources;
+pub mod rules;
+pub mod trade;
+pub mod troops;
+pub mod value_types;
@@ -1,36 +1,227 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
-use super::{buildings::Building, troops::UnitType, value_types::Quantity};
+use super::{
+    buildings::BuildingType,
+    items::ItemType,
+    limits,
+    plurals::plural_suffix,
+    resources::ResourceType,
+    trade::describe_resources,
+    troops::{ArmyTemplate, Stance, UnitType},
+    value_types::{Quantity, ResourceValue},
+};
 
 /// Actions that can be performed in one game round
-#[derive(PartialEq)]
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub enum Actions {
-    Build(Building),
+    Build(BuildingType, Quantity),
+    Upgrade(BuildingType), // upgrade the building of this type with the lowest level
     Harvest,
     Train(UnitType, Quantity),
+    Recruit(UnitType, Quantity), // upgrade peasants into units
+    TrainArmy(ArmyTemplate),     // train all the units of a saved template
     Conquer(usize, usize, UnitType, Quantity), // x coordinate, y coordinate, unit type, quantity
+    Stance(usize, usize, Stance), // x coordinate, y coordinate, new stance
+    Scout(usize, usize),         // x coordinate, y coordinate
+    Recall(usize, usize, UnitType, Quantity), // x coordinate, y coordinate, unit type, quantity
+    BuyItem(ItemType, Quantity),
+    UseItem(usize, usize, ItemType), // x coordinate, y coordinate, item type
+    Fortify(usize, usize),           // x coordinate, y coordinate
+    // offer resources to another player, (wood, gold) given and wanted in return
+    Trade {
+        to: String,
+        give: ResourceValue,
+        want: ResourceValue,
+    },
+    Exchange(ResourceType, Quantity), // sell a quantity of the resource on the market for the other one
+    // answer the trade offer of another player, at the start of the turn
+    AnswerTrade {
+        from: String,
+        accept: bool,
+    },
+    Pass,    // give up the whole turn for a small amount of resources
+    EndTurn, // leave the remaining action points unused
+    Undo,    // take back the last action of the turn
     Quit,
+    Save(String), // path of the save file
+}
+
+impl Actions {
+    /// Return how many action points the action costs
+    ///
+    /// Returns
+    /// ---
+    /// - number of action points, zero for actions which do not take up the turn
+    pub fn action_points(&self) -> Quantity {
+        match self {
+            Actions::Build(..) => limits::BUILD_ACTION_POINTS,
+            Actions::Upgrade(_) => limits::UPGRADE_ACTION_POINTS,
+            Actions::Harvest => limits::HARVEST_ACTION_POINTS,
+            Actions::Train(..) => limits::TRAIN_ACTION_POINTS,
+            Actions::Recruit(..) => limits::RECRUIT_ACTION_POINTS,
+            Actions::TrainArmy(_) => limits::TRAIN_ARMY_ACTION_POINTS,
+            Actions::Conquer(..) => limits::CONQUER_ACTION_POINTS,
+            Actions::Stance(..) => limits::STANCE_ACTION_POINTS,
+            Actions::Scout(..) => limits::SCOUT_ACTION_POINTS,
+            Actions::Recall(..) => limits::RECALL_ACTION_POINTS,
+            Actions::BuyItem(..) => limits::BUY_ITEM_ACTION_POINTS,
+            Actions::UseItem(..) => limits::USE_ITEM_ACTION_POINTS,
+            Actions::Fortify(..) => limits::FORTIFY_ACTION_POINTS,
+            Actions::Trade { .. } => limits::TRADE_ACTION_POINTS,
+            Actions::Exchange(..) => limits::EXCHANGE_ACTION_POINTS,
+            Actions::Pass => limits::PASS_ACTION_POINTS,
+            Actions::AnswerTrade { .. }
+            | Actions::EndTurn
+            | Actions::Undo
+            | Actions::Quit
+            | Actions::Save(_) => 0,
+        }
+    }
+
+    /// Return the field the action changes, if any
+    ///
+    /// Returns
+    /// ---
+    /// - Some((x, y)): coordinates of the field
+    /// - None: if the action only concerns the player's kingdom
+    pub fn target_field(&self) -> Option<(usize, usize)> {
+        match self {
+            Actions::Conquer(x, y, ..)
+            | Actions::Stance(x, y, _)
+            | Actions::Scout(x, y)
+            | Actions::Recall(x, y, ..)
+            | Actions::UseItem(x, y, _)
+            | Actions::Fortify(x, y) => Some((*x, *y)),
+            _ => None,
+        }
+    }
+
+    /// Write the action in a compact notation, f.e. 'C 1 0 A 10' for conquering field (1,0)
+    /// with 10 archers, types are abbreviated to their first letters
+    ///
+    /// Returns
+    /// ---
+    /// - String: the action in the compact notation
+    pub fn notation(&self) -> String {
+        match self {
+            Actions::Build(building, quantity) => format!("B {} {}", initial(building), quantity),
+            Actions::Upgrade(building) => format!("U {}", initial(building)),
+            Actions::Harvest => String::from("H"),
+            Actions::Train(unit, quantity) => format!("T {} {}", initial(unit), quantity),
+            Actions::Recruit(unit, quantity) => format!("R {} {}", initial(unit), quantity),
+            Actions::TrainArmy(template) => format!("TA {}", template.name),
+            Actions::Conquer(x, y, unit, quantity) => {
+                format!("C {} {} {} {}", x, y, initial(unit), quantity)
+            }
+            Actions::Stance(x, y, stance) => format!("S {} {} {}", x, y, initial(stance)),
+            Actions::Scout(x, y) => format!("SC {} {}", x, y),
+            Actions::Recall(x, y, unit, quantity) => {
+                format!("RC {} {} {} {}", x, y, initial(unit), quantity)
+            }
+            Actions::BuyItem(item, quantity) => format!("BI {} {}", initial(item), quantity),
+            Actions::UseItem(x, y, item) => format!("UI {} {} {}", x, y, initial(item)),
+            Actions::Fortify(x, y) => format!("F {} {}", x, y),
+            Actions::Trade { to, give, want } => {
+                format!("TR {} {} {} {} {}", to, give.0, give.1, want.0, want.1)
+            }
+            Actions::Exchange(resource, quantity) => {
+                format!("X {} {}", initial(resource), quantity)
+            }
+            Actions::AnswerTrade { from, accept } => match accept {
+                true => format!("AT {} Y", from),
+                false => format!("AT {} N", from),
+            },
+            Actions::Pass => String::from("P"),
+            Actions::EndTurn => String::from("E"),
+            Actions::Undo => String::from("UN"),
+            Actions::Quit => String::from("Q"),
+            Actions::Save(path) => format!("SV {}", path),
+        }
+    }
+}
+
+/// Return the first letter of a displayed type, its abbreviation in the compact notation
+fn initial(value: &impl Display) -> char {
+    value.to_string().chars().next().unwrap_or('?')
 }
 
 /// Used for displaying actions in strings
 impl Display for Actions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Actions::Build(building) => write!(f, "Build {}", building),
+            Actions::Build(building, quantity) => {
+                let plural = plural_suffix(*quantity, "S");
+                write!(f, "Build {} {}{}", quantity, building, plural)
+            }
             Actions::Conquer(x, y, unit, quantity) => {
-                let plural = if *quantity == 1 { "" } else { "S" };
+                let plural = plural_suffix(*quantity, "S");
                 write!(
                     f,
                     "Conquer field ({},{}) with {} {}{}",
                     x, y, quantity, unit, plural
                 )
             }
+            Actions::Upgrade(building) => write!(f, "Upgrade a {}", building),
             Actions::Harvest => write!(f, "Harvest resources"),
+            Actions::Pass => write!(f, "Pass the turn"),
+            Actions::EndTurn => write!(f, "End the turn"),
+            Actions::Undo => write!(f, "Undo the last action"),
             Actions::Quit => write!(f, "Quit game"),
+            Actions::Save(path) => write!(f, "Save the game to {}", path),
+            Actions::Recruit(unit, quantity) => {
+                let plural = plural_suffix(*quantity, "S");
+                write!(
+                    f,
+                    "Upgrade {} PEASANT{} into {}{}",
+                    quantity, plural, unit, plural
+                )
+            }
+            Actions::Stance(x, y, stance) => {
+                write!(
+                    f,
+                    "Set stance of units on field ({},{}) to {}",
+                    x, y, stance
+                )
+            }
+            Actions::Scout(x, y) => write!(f, "Scout field ({},{})", x, y),
+            Actions::Recall(x, y, unit, quantity) => {
+                let plural = plural_suffix(*quantity, "S");
+                write!(
+                    f,
+                    "Recall {} {}{} from field ({},{})",
+                    quantity, unit, plural, x, y
+                )
+            }
+            Actions::BuyItem(item, quantity) => {
+                let plural = plural_suffix(*quantity, "S");
+                write!(f, "Buy {} {}{}", quantity, item, plural)
+            }
+            Actions::UseItem(x, y, item) => {
+                write!(f, "Use a {} on field ({},{})", item, x, y)
+            }
+            Actions::Fortify(x, y) => write!(f, "Build a TOWER on field ({},{})", x, y),
+            Actions::Trade { to, give, want } => write!(
+                f,
+                "Offer {} {} for {}",
+                to,
+                describe_resources(*give),
+                describe_resources(*want)
+            ),
+            Actions::Exchange(resource, quantity) => {
+                write!(f, "Sell {} {} on the market", quantity, resource)
+            }
+            Actions::AnswerTrade { from, accept } => match accept {
+                true => write!(f, "Accept the trade offer of {}", from),
+                false => write!(f, "Decline the trade offer of {}", from),
+            },
             Actions::Train(unit, quantity) => {
-                let plural = if *quantity == 1 { "" } else { "S" };
+                let plural = plural_suffix(*quantity, "S");
                 write!(f, "Train {} {}{}", quantity, unit, plural)
             }
+            Actions::TrainArmy(template) => {
+                write!(f, "Train army '{}' of {}", template.name, template)
+            }
         }
     }
 }
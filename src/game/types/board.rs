@@ -1,28 +1,93 @@
+use super::super::colors::paint_map_cell;
+use super::super::economy::Economy;
+use super::super::rng::GameRng;
 use super::{
-    troops::{Unit, UnitType},
-    value_types::{FighterPower, Quantity},
+    items::ItemType,
+    limits::{
+        BATTLE_CASUALTY_RATE, MAP_CELL_WIDTH, MAP_NICK_LENGTH, MAX_FIELD_NAME_LENGTH,
+        POWER_PRECISION, REGIMENT_SIZE,
+    },
+    plurals::{ordinal, plural_suffix},
+    rules::rules,
+    troops::{Stance, Unit, UnitType},
+    value_types::{ExactPower, FighterPower, Quantity},
 };
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+// nicknames the regiments are given
+const REGIMENT_NICKNAMES: [&str; 12] = [
+    "Iron Wolves",
+    "Grey Ravens",
+    "Red Lions",
+    "Stone Bears",
+    "Black Hawks",
+    "Old Oaks",
+    "Silver Foxes",
+    "Storm Riders",
+    "Golden Stags",
+    "Night Owls",
+    "River Otters",
+    "Thunder Boars",
+];
 
 /// Game plan where the fields are stored
+#[derive(Clone, Serialize, Deserialize)]
 pub struct GamePlan {
     pub(super) fields: Vec<GameField>,
     pub(super) width: usize,
     pub(super) height: usize,
+    // market shared by all the players, saves from before it was added open a new one
+    #[serde(default)]
+    pub(super) economy: Economy,
+    // (owner, unit type, number) of the regiments every player has raised so far
+    #[serde(default)]
+    pub(super) regiments_raised: Vec<(String, UnitType, usize)>,
 }
 
 /// One game field which stores how many units have been sent to the field and its coordinates
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct GameField {
     pub(super) x: usize,
     pub(super) y: usize,
+    pub(super) name: Option<String>,
     pub(super) units_occupying: Vec<UnitInField>,
+    // items used on the field for the coming battle
+    #[serde(default)]
+    pub(super) boosts: Vec<FieldBoost>,
+    // owner of the tower built on the field, it stands as long as they keep units there
+    #[serde(default)]
+    pub(super) tower: Option<String>,
 }
 
 /// Struct which stores how many units have been sent to the field
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct UnitInField {
     pub owner: String,
     pub unit: Unit,
+    pub stance: Stance,
+    // name of a large stack, f.e. '3rd Archer Regiment "Iron Wolves"'
+    #[serde(default)]
+    pub regiment: Option<String>,
+}
+
+/// Item used on a field by a player, it helps their units until the end of the round
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct FieldBoost {
+    pub owner: String,
+    pub item: ItemType,
+}
+
+/// Units lost in a battle on one field
+#[derive(Clone)]
+pub struct BattleReport {
+    /// label of the field
+    pub field: String,
+    /// (owner, regiment, unit type, quantity) of the fallen units,
+    /// the losses of the stacks without a name are counted together
+    pub losses: Vec<(String, Option<String>, UnitType, Quantity)>,
+    /// nick of the strongest owner after the battle, if there is a unique one
+    pub victor: Option<String>,
 }
 
 impl GamePlan {
@@ -36,8 +101,8 @@ impl GamePlan {
         let mut fields_generated: Vec<GameField> = Vec::new();
 
         // generate plan with fields with coordinates
-        let _ = (0..width).for_each(|x| {
-            let _ = (0..height).for_each(|y| fields_generated.push(GameField::new(x, y)));
+        (0..width).for_each(|x| {
+            (0..height).for_each(|y| fields_generated.push(GameField::new(x, y)));
         });
 
         // return a new game plan with desired width and height
@@ -45,9 +110,21 @@ impl GamePlan {
             fields: fields_generated,
             width,
             height,
+            economy: Economy::default(),
+            regiments_raised: Vec::new(),
         }
     }
 
+    /// Market shared by all the players
+    pub fn economy(&self) -> &Economy {
+        &self.economy
+    }
+
+    /// Mutable reference to the market, to exchange resources there
+    pub fn economy_mut(&mut self) -> &mut Economy {
+        &mut self.economy
+    }
+
     /// Obtain mutable reference to a desired field on the battlefield,
     /// if the coordinates are within the battlefield dimensions
     ///
@@ -61,19 +138,345 @@ impl GamePlan {
     /// - Some(&mut field): mutable reference do desired field
     /// - None: if the field is not within range
     pub fn get_game_field(&mut self, x: usize, y: usize) -> Option<&mut GameField> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
         self.fields.get_mut(self.height * x + y)
     }
 
+    /// Obtain reference to a desired field on the battlefield,
+    /// if the coordinates are within the battlefield dimensions
+    ///
+    /// Params
+    /// ---
+    /// - x: x coordinate on the battlefield
+    /// - y: y coordinate on the battlefield
+    ///
+    /// Returns
+    /// ---
+    /// - Some(&field): reference to desired field
+    /// - None: if the field is not within range
+    pub fn field(&self, x: usize, y: usize) -> Option<&GameField> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.fields.get(self.height * x + y)
+    }
+
     /// Obtain dimensions of a field in a text format
     ///
     /// Returns
     /// ---
     /// - String with battlefield dimensions in text format
     pub fn get_dimensions(&self) -> String {
-        let plural = if self.height == 1 { "" } else { "s" };
+        let plural = plural_suffix(self.number_of_fields(), "s");
         format!("{} x {} field{}", self.width, self.height, plural)
     }
 
+    /// Width of the battlefield
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the battlefield
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Obtain coordinates of a field by its number
+    /// Fields are numbered from 1, row by row from the top left corner.
+    ///
+    /// Params
+    /// ---
+    /// - number: number of the field
+    ///
+    /// Returns
+    /// ---
+    /// - Some((x, y)): coordinates of the field
+    /// - None: if there is no field with this number
+    pub fn field_coordinates(&self, number: usize) -> Option<(usize, usize)> {
+        if number == 0 || number > self.number_of_fields() {
+            return None;
+        }
+
+        Some(((number - 1) % self.width, (number - 1) / self.width))
+    }
+
+    /// Give a field a custom name, or remove it
+    ///
+    /// Params
+    /// ---
+    /// - x: x coordinate on the battlefield
+    /// - y: y coordinate on the battlefield
+    /// - name: new name of the field, empty name removes the current one
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) containing the new label of the field
+    /// - Err(String) if the field does not exist or the name cannot be used
+    pub fn name_field(&mut self, x: usize, y: usize, name: &str) -> Result<String, String> {
+        let name = name.trim();
+
+        if name.chars().count() > MAX_FIELD_NAME_LENGTH {
+            return Err(format!(
+                "Field names can be at most {} characters long!",
+                MAX_FIELD_NAME_LENGTH
+            ));
+        }
+
+        // names have to identify fields uniquely
+        if !name.is_empty()
+            && self
+                .fields
+                .iter()
+                .any(|field| field.name.as_deref() == Some(name) && (field.x, field.y) != (x, y))
+        {
+            return Err(format!("Another field is already called '{}'!", name));
+        }
+
+        let field = self
+            .get_game_field(x, y)
+            .ok_or_else(|| String::from("Specified game field does not exist!"))?;
+
+        field.name = match name {
+            "" => None,
+            name => Some(name.to_string()),
+        };
+
+        Ok(field.label())
+    }
+
+    /// Return the number of fields on the battlefield
+    pub fn number_of_fields(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Compute current standings without printing anything
+    ///
+    /// Returns
+    /// ---
+    /// - map of player nicks to the number of fields they are currently leading
+    pub fn standings(&self) -> HashMap<String, usize> {
+        let mut standings: HashMap<String, usize> = HashMap::new();
+
+        for (leader, _) in self.fields.iter().filter_map(|field| field.leader()) {
+            *standings.entry(leader).or_insert(0) += 1;
+        }
+
+        standings
+    }
+
+    /// Return the only player leading the most fields
+    ///
+    /// Returns
+    /// ---
+    /// - Some(nick): if a single player leads the most fields
+    /// - None: if nobody leads a field or the best players are tied
+    pub fn sole_leader(&self) -> Option<String> {
+        let standings = self.standings();
+        let highest = standings.values().copied().max()?;
+
+        let mut leaders = standings
+            .into_iter()
+            .filter(|(_, fields)| *fields == highest);
+
+        match (leaders.next(), leaders.next()) {
+            (Some((leader, _)), None) => Some(leader),
+            _ => None,
+        }
+    }
+
+    /// Name the stacks on the battlefield which have grown into regiments
+    ///
+    /// Regiments of a player and unit type are numbered in the order they are raised,
+    /// their nicknames are drawn from the generator of the game.
+    ///
+    /// Params
+    /// ---
+    /// - rng: generator of the game
+    pub fn name_regiments(&mut self, rng: &mut GameRng) {
+        for field in self.fields.iter_mut() {
+            for stack in field.units_occupying.iter_mut() {
+                if stack.regiment.is_some() || stack.unit.quantity < REGIMENT_SIZE {
+                    continue;
+                }
+
+                let unit_type = stack.unit.unit_type;
+                let number =
+                    match self
+                        .regiments_raised
+                        .iter_mut()
+                        .find(|(owner, raised_type, _)| {
+                            *owner == stack.owner && *raised_type == unit_type
+                        }) {
+                        Some((_, _, raised)) => {
+                            *raised += 1;
+                            *raised
+                        }
+                        None => {
+                            self.regiments_raised
+                                .push((stack.owner.clone(), unit_type, 1));
+                            1
+                        }
+                    };
+
+                let formation = match unit_type {
+                    UnitType::Cavalry => "Squadron",
+                    _ => "Regiment",
+                };
+                let kind = unit_type.to_string().to_lowercase();
+                let kind = kind[..1].to_uppercase() + &kind[1..];
+                let nickname = REGIMENT_NICKNAMES[rng.below(REGIMENT_NICKNAMES.len())];
+
+                stack.regiment = Some(format!(
+                    "{} {} {} \"{}\"",
+                    ordinal(number),
+                    kind,
+                    formation,
+                    nickname
+                ));
+            }
+        }
+    }
+
+    /// Fight battles on all the contested fields, at the end of every round
+    ///
+    /// Returns
+    /// ---
+    /// - reports of the battles, one per contested field
+    pub fn resolve_battles(&mut self) -> Vec<BattleReport> {
+        let reports = self
+            .fields
+            .iter_mut()
+            .filter_map(|field| field.resolve_battle())
+            .collect();
+
+        // the items only last for one round, even on fields without a battle
+        for field in self.fields.iter_mut() {
+            field.boosts.clear();
+        }
+
+        reports
+    }
+
+    /// Compute which fields can no longer change their winner
+    ///
+    /// A field is locked when no other player is able to at least tie
+    /// its current leader, even after adding their maximal reinforcements.
+    /// Healing potions spare the units of their owner in battles, so a field is never locked
+    /// while an opponent with any power there can still use one.
+    ///
+    /// Params
+    /// ---
+    /// - reinforcements: map of player nicks to the highest power
+    ///   they can still send to a single field
+    /// - healed: nicks of the players who hold a healing potion or can still buy one
+    ///
+    /// Returns
+    /// ---
+    /// - map of player nicks to the number of fields locked in their favour
+    pub fn locked_fields(
+        &self,
+        reinforcements: &HashMap<String, FighterPower>,
+        healed: &HashSet<String>,
+    ) -> HashMap<String, usize> {
+        let potions_help = rules().healing_potion_casualties < 1.0;
+
+        let mut locked: HashMap<String, usize> = HashMap::new();
+
+        for field in self.fields.iter() {
+            // only fields with a unique leader can be locked
+            let (leader, leader_power) = match field.leader() {
+                Some(leader) => leader,
+                None => continue,
+            };
+
+            let power_chart = field.power_chart();
+            let leader_power = exact_power(leader_power);
+
+            // check whether any opponent could catch up with the leader, at least to a tie
+            // (their units could also be garrisoned or boosted by items, hence the bonuses)
+            let contestable = reinforcements
+                .iter()
+                .filter(|(nick, _)| **nick != leader)
+                .any(|(nick, extra_power)| {
+                    let power = power_chart.get(nick).copied().unwrap_or(0);
+                    let power = fighter_power(power) + extra_power;
+                    let boosted_power = exact_power(power * highest_bonus());
+                    leader_power - boosted_power <= tie_tolerance()
+                        || (potions_help && power > 0.0 && healed.contains(nick))
+                });
+
+            if !contestable {
+                *locked.entry(leader).or_insert(0) += 1;
+            }
+        }
+
+        locked
+    }
+
+    /// Render the battlefield as a grid of fields
+    ///
+    /// Every field shows its coordinates and its current leader with their power,
+    /// 'TIED' if nobody leads an occupied field, or '-' if the field is empty.
+    ///
+    /// Returns
+    /// ---
+    /// - String containing the map
+    pub fn render_map(&self) -> String {
+        self.render_map_for(GameField::map_owner)
+    }
+
+    /// Render the battlefield as an ASCII map, describing the fields in a custom way
+    ///
+    /// Params
+    /// ---
+    /// - describe: text shown below the coordinates of a field, f.e. hiding it in the fog of war
+    ///
+    /// Returns
+    /// ---
+    /// - String containing the map
+    pub fn render_map_for(&self, describe: impl Fn(&GameField) -> String) -> String {
+        let border = format!(
+            "+{}\n",
+            format!("{}+", "-".repeat(MAP_CELL_WIDTH)).repeat(self.width)
+        );
+
+        let rows: Vec<String> = (0..self.height)
+            .map(|y| {
+                let cells: Vec<&GameField> =
+                    (0..self.width).filter_map(|x| self.field(x, y)).collect();
+
+                let coordinates: String = cells
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            " {:<width$}|",
+                            format!("({},{})", field.x, field.y),
+                            width = MAP_CELL_WIDTH - 1
+                        )
+                    })
+                    .collect();
+
+                // fields are colored by the players shown in them
+                let owners: String = cells
+                    .iter()
+                    .map(|field| {
+                        let description = describe(field);
+                        let cell = format!(" {:<width$}", description, width = MAP_CELL_WIDTH - 1);
+                        format!("{}|", paint_map_cell(&cell, &description))
+                    })
+                    .collect();
+
+                format!("{}|{}\n|{}\n", border, coordinates, owners)
+            })
+            .collect();
+
+        format!("{}{}", rows.concat(), border)
+    }
+
     /// Evaluate current state of the battlefield
     ///
     /// If the game has a winner, print their name and
@@ -83,8 +486,7 @@ impl GamePlan {
         let evaluated_iterator = self
             .fields
             .iter()
-            .map(|field| field.evaluate_field())
-            .flatten();
+            .filter_map(|field| field.evaluate_field());
 
         // used to store the number of wins
         let mut winner_frequency: HashMap<String, usize> = HashMap::new();
@@ -95,11 +497,7 @@ impl GamePlan {
         }
 
         // get player with highest number of won fields
-        let highest_wins = winner_frequency
-            .clone()
-            .into_iter()
-            .map(|(_, wins)| wins)
-            .fold(0, |a, b| a.max(b));
+        let highest_wins = winner_frequency.values().copied().fold(0, |a, b| a.max(b));
 
         // find a possible winner
         let possible_winner = winner_frequency
@@ -150,7 +548,40 @@ impl GameField {
         Self {
             x,
             y,
+            name: None,
             units_occupying: Vec::new(),
+            boosts: Vec::new(),
+            tower: None,
+        }
+    }
+
+    /// Return a label of the field used in the game output,
+    /// f.e. 'The Ford (1,2)' for a named field or '(1,2)' otherwise
+    pub fn label(&self) -> String {
+        match &self.name {
+            Some(name) => format!("{} ({},{})", name, self.x, self.y),
+            None => format!("({},{})", self.x, self.y),
+        }
+    }
+
+    /// Return the coordinates of the field
+    pub fn coordinates(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    /// Describe the leader of the field for the battlefield map
+    ///
+    /// Returns
+    /// ---
+    /// - String: f.e. 'Alice 12.5', '-' for an empty field, 'TIED' when nobody leads
+    pub fn map_owner(&self) -> String {
+        match (self.leader(), self.units_occupying.is_empty()) {
+            (Some((nick, power)), _) => {
+                let nick: String = nick.chars().take(MAP_NICK_LENGTH).collect();
+                format!("{} {:.1}", nick, power)
+            }
+            (None, true) => String::from("-"),
+            (None, false) => String::from("TIED"),
         }
     }
 
@@ -163,6 +594,98 @@ impl GameField {
         self.units_occupying.push(units);
     }
 
+    /// Return the units occupying the field, in the order they arrived
+    pub fn units(&self) -> &[UnitInField] {
+        &self.units_occupying
+    }
+
+    /// Count the units of an owner of a certain type on the field
+    ///
+    /// Params
+    /// ---
+    /// - owner_nick: nick of the owner
+    /// - unit_type: which unit type to count
+    pub fn units_of(&self, owner_nick: &str, unit_type: UnitType) -> Quantity {
+        self.units_occupying
+            .iter()
+            .filter(|unit_in_field| {
+                unit_in_field.owner == owner_nick && unit_in_field.unit.unit_type == unit_type
+            })
+            .map(|unit_in_field| unit_in_field.unit.quantity)
+            .sum()
+    }
+
+    /// Describe the regiments on the field, f.e. '3rd Archer Regiment "Iron Wolves" of Alice: 25 ARCHERS'
+    ///
+    /// Returns
+    /// ---
+    /// - one line per named stack, in the order they arrived
+    pub fn regiments(&self) -> Vec<String> {
+        self.units_occupying
+            .iter()
+            .filter_map(|stack| {
+                stack.regiment.as_ref().map(|regiment| {
+                    format!(
+                        "{} of {}: {} {}{}",
+                        regiment,
+                        stack.owner,
+                        stack.unit.quantity,
+                        stack.unit.unit_type,
+                        plural_suffix(stack.unit.quantity, "S")
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Withdraw units of an owner from the field
+    ///
+    /// The units which arrived last leave first, so the defender of the field
+    /// keeps its position as long as possible. Empty stacks leave the field.
+    ///
+    /// Params
+    /// ---
+    /// - owner_nick: nick of the owner
+    /// - unit_type: which unit type to withdraw
+    /// - quantity: how many units to withdraw, at most `units_of` the owner
+    pub fn withdraw_units(&mut self, owner_nick: &str, unit_type: UnitType, quantity: Quantity) {
+        let mut remaining = quantity;
+
+        for unit_in_field in self.units_occupying.iter_mut().rev() {
+            if remaining == 0 {
+                break;
+            }
+            if unit_in_field.owner != owner_nick || unit_in_field.unit.unit_type != unit_type {
+                continue;
+            }
+
+            let withdrawn = remaining.min(unit_in_field.unit.quantity);
+            unit_in_field.unit.quantity -= withdrawn;
+            remaining -= withdrawn;
+        }
+
+        self.units_occupying
+            .retain(|unit_in_field| unit_in_field.unit.quantity > 0);
+        self.raze_abandoned_tower();
+    }
+
+    /// Return the nicks of all players with units on the field
+    ///
+    /// Returns
+    /// ---
+    /// - nicks of the occupants, in the order they arrived
+    pub fn occupants(&self) -> Vec<String> {
+        let mut occupants: Vec<String> = Vec::new();
+
+        for unit_in_field in &self.units_occupying {
+            if !occupants.contains(&unit_in_field.owner) {
+                occupants.push(unit_in_field.owner.clone());
+            }
+        }
+
+        occupants
+    }
+
     /// Return the quantity of a certain unit type that is currently
     /// occupying the field
     ///
@@ -181,73 +704,346 @@ impl GameField {
             .sum()
     }
 
-    /// Evaluate who from the conquerors won the field
+    /// Return the defender of the field, who is the first player that occupied it
+    fn defender(&self) -> Option<&str> {
+        self.units_occupying
+            .first()
+            .map(|unit_in_field| unit_in_field.owner.as_str())
+    }
+
+    /// Return fighting power of units on the field, including stance and item modifiers
+    ///
+    /// Params
+    /// ---
+    /// - unit_in_field: units occupying this field
+    fn stack_power(&self, unit_in_field: &UnitInField) -> FighterPower {
+        let power = unit_in_field.unit.fighting_power();
+        let defending = self.defender() == Some(unit_in_field.owner.as_str());
+
+        // garrisons only help when defending the field
+        let power = match unit_in_field.stance {
+            Stance::Garrison if defending => power * rules().garrison_defense_bonus,
+            _ => power,
+        };
+
+        // the tower only shelters the units of its owner
+        let power = match &self.tower {
+            Some(owner) if *owner == unit_in_field.owner => power * rules().tower_bonus,
+            _ => power,
+        };
+
+        // items used by the owner of the units
+        self.boosts
+            .iter()
+            .filter(|boost| boost.owner == unit_in_field.owner)
+            .fold(power, |power, boost| {
+                power
+                    * boost
+                        .item
+                        .power_bonus(unit_in_field.unit.unit_type, defending)
+            })
+    }
+
+    /// Use an item on the field for the rest of the round
+    ///
+    /// Params
+    /// ---
+    /// - owner_nick: nick of the player using the item
+    /// - item: the used item
     ///
     /// Returns
     /// ---
-    /// Some(name): if someone won the field
-    /// None: if the field was conquered (either no one contested it, or could not decide)
-    pub fn evaluate_field(&self) -> Option<String> {
+    /// - true: if the item was used
+    /// - false: if the owner has already used an item of this type on the field this round
+    pub fn add_boost(&mut self, owner_nick: &str, item: ItemType) -> bool {
+        if self.boosted_by(owner_nick, item) {
+            return false;
+        }
+
+        self.boosts.push(FieldBoost {
+            owner: owner_nick.to_string(),
+            item,
+        });
+        true
+    }
+
+    /// Check whether an owner has used an item of a certain type on the field this round
+    ///
+    /// Params
+    /// ---
+    /// - owner_nick: nick of the owner
+    /// - item: type of the item
+    pub fn boosted_by(&self, owner_nick: &str, item: ItemType) -> bool {
+        self.boosts
+            .iter()
+            .any(|boost| boost.owner == owner_nick && boost.item == item)
+    }
+
+    /// Return the items used on the field this round
+    pub fn boosts(&self) -> &[FieldBoost] {
+        &self.boosts
+    }
+
+    /// Build a tower on the field for an owner
+    ///
+    /// Params
+    /// ---
+    /// - owner_nick: nick of the owner of the tower
+    ///
+    /// Returns
+    /// ---
+    /// - true: if the tower was built
+    /// - false: if a tower already stands on the field
+    pub fn build_tower(&mut self, owner_nick: &str) -> bool {
+        if self.tower.is_some() {
+            return false;
+        }
+
+        self.tower = Some(owner_nick.to_string());
+        true
+    }
+
+    /// Return the owner of the tower on the field, if there is one
+    pub fn tower(&self) -> Option<&str> {
+        self.tower.as_deref()
+    }
+
+    /// Tear down the tower once its owner has no units left on the field
+    fn raze_abandoned_tower(&mut self) {
+        if let Some(owner) = &self.tower {
+            if !self.occupants().contains(owner) {
+                self.tower = None;
+            }
+        }
+    }
+
+    /// Set a stance of all units of an owner on the field
+    ///
+    /// Params
+    /// ---
+    /// - owner_nick: nick of the owner of the units
+    /// - stance: new stance of the units
+    ///
+    /// Returns
+    /// ---
+    /// - number of unit stacks whose stance has changed
+    pub fn set_stance(&mut self, owner_nick: &str, stance: Stance) -> usize {
+        let mut changed = 0;
+
+        for unit_in_field in self.units_occupying.iter_mut() {
+            if unit_in_field.owner == owner_nick && unit_in_field.stance != stance {
+                unit_in_field.stance = stance;
+                changed += 1;
+            }
+        }
+
+        changed
+    }
+
+    /// Sum the fighting power of every owner on the field
+    ///
+    /// Powers are summed in thousandths, so equal armies always end up with equal powers,
+    /// no matter in how many stacks they came to the field.
+    ///
+    /// Returns
+    /// ---
+    /// - map of owner nicks to their total exact fighting power on the field
+    fn power_chart(&self) -> HashMap<String, ExactPower> {
         // map the power of players
         let units_frequency = self.units_occupying.iter().map(|unit_in_field| {
             (
                 unit_in_field.owner.clone(),
-                unit_in_field.unit.fighting_power(),
+                exact_power(self.stack_power(unit_in_field)),
             )
         });
 
         // create a frequency storage
-        let mut power_chart: HashMap<String, FighterPower> = HashMap::new();
+        let mut power_chart: HashMap<String, ExactPower> = HashMap::new();
 
         // sum the power of players
         for (owner, power) in units_frequency {
-            *power_chart.entry(owner.clone()).or_insert(0.0) += power;
+            *power_chart.entry(owner).or_insert(0) += power;
         }
 
-        // find the highest power
-        let highest_power = power_chart
-            .clone()
+        power_chart
+    }
+
+    /// Return the total fighting power of an owner on the field
+    ///
+    /// Params
+    /// ---
+    /// - owner_nick: nick of the owner
+    pub fn power_of(&self, owner_nick: &str) -> FighterPower {
+        fighter_power(self.power_chart().get(owner_nick).copied().unwrap_or(0))
+    }
+
+    /// Find the strongest opponent of an owner on the field
+    ///
+    /// Params
+    /// ---
+    /// - owner_nick: nick of the owner whose opponents are searched
+    ///
+    /// Returns
+    /// ---
+    /// - Some((name, power)): the strongest other owner on the field
+    /// - None: if no one else occupies the field
+    pub fn strongest_opponent(&self, owner_nick: &str) -> Option<(String, FighterPower)> {
+        self.power_chart()
             .into_iter()
-            .map(|(_, power)| power)
-            .fold(std::f64::MIN, |a, b| a.max(b));
+            .filter(|(owner, _)| owner != owner_nick)
+            .fold(None, |strongest, (owner, power)| match strongest {
+                Some((_, highest)) if highest >= power => strongest,
+                _ => Some((owner, power)),
+            })
+            .map(|(owner, power)| (owner, fighter_power(power)))
+    }
 
-        // find the winner (find which owner has the highest power, then return their name)
-        let winner = power_chart
-            .clone()
+    /// Compute how much more power an owner needs to become the unique leader of the field
+    ///
+    /// Params
+    /// ---
+    /// - owner_nick: nick of the owner
+    ///
+    /// Returns
+    /// ---
+    /// - missing fighting power, 0 if the owner already leads the field
+    pub fn power_needed_to_lead(&self, owner_nick: &str) -> FighterPower {
+        let power_chart = self.power_chart();
+        let opponent_power = power_chart
+            .iter()
+            .filter(|(owner, _)| *owner != owner_nick)
+            .map(|(_, power)| *power)
+            .max()
+            .unwrap_or(0);
+        let power = power_chart.get(owner_nick).copied().unwrap_or(0);
+
+        // the smallest power above a tie with the opponent
+        fighter_power((opponent_power + tie_tolerance() + 1 - power).max(0))
+    }
+
+    /// Find the current leader of the field without printing anything
+    ///
+    /// Returns
+    /// ---
+    /// - Some((name, power)): if there is a unique strongest owner on the field
+    /// - None: if the field is empty or the strongest owners are tied
+    pub fn leader(&self) -> Option<(String, FighterPower)> {
+        let power_chart = self.power_chart();
+
+        // find the highest power
+        let highest_power = power_chart.values().copied().max()?;
+
+        // owners within the tie tolerance of the highest power share the lead
+        let mut leaders = power_chart
             .into_iter()
-            .find(|(_, power)| (*power - highest_power).abs() < 0.1);
+            .filter(|(_, power)| highest_power - power <= tie_tolerance());
 
-        // print winner of the field
-        if let Some((winner_name, power)) = &winner {
-            // check if the winner of the field is unique
-            let is_unique = power_chart
-                .into_iter()
-                .filter(|(_, power)| (*power - highest_power).abs() < 0.1);
-
-            // winner was not unique, field has no winner
-            if is_unique.count() != 1 {
-                return None;
-            }
+        // winner was not unique, field has no winner
+        match (leaders.next(), leaders.next()) {
+            (Some((winner, power)), None) => Some((winner, fighter_power(power))),
+            _ => None,
+        }
+    }
 
-            // Winner IS UNIQUE:
+    /// Fight a battle between the owners of units on the field
+    ///
+    /// Every owner loses a share of their units proportional to the power of their enemies,
+    /// so stronger armies suffer lighter losses, items of the owner can lower them further.
+    /// Stacks without units leave the field.
+    ///
+    /// Returns
+    /// ---
+    /// - Some(report): losses of the owners, if the field is contested
+    /// - None: if there are not at least two owners on the field
+    pub fn resolve_battle(&mut self) -> Option<BattleReport> {
+        let power_chart = self.power_chart();
+        let total_power: ExactPower = power_chart.values().sum();
 
-            // this will give us a field with ONLY desired player's units
-            let field = self.players_units(winner_name.clone());
+        if power_chart.len() < 2 || total_power <= 0 {
+            return None;
+        }
 
-            // get quantity of player's units
-            let archer_units: Quantity = field.get_units_by_type(UnitType::Archer);
-            let warrior_units: Quantity = field.get_units_by_type(UnitType::Warrior);
-            let archer_plural = if archer_units == 1 { "" } else { "S" };
-            let warrior_plural = if warrior_units == 1 { "" } else { "S" };
+        let mut losses: Vec<(String, Option<String>, UnitType, Quantity)> = Vec::new();
 
-            // print who won the field
-            println!("\nWinner of field ({}, {}) is {} with {} {}{}, {} {}{} and resulting fighting power of {:.2}\n",
-                field.x, field.y, winner_name, archer_units, UnitType::Archer, archer_plural, warrior_units,
-                UnitType::Warrior, warrior_plural, power
-            );
+        for unit_in_field in self.units_occupying.iter_mut() {
+            let enemy_power = total_power - power_chart[&unit_in_field.owner];
+            let casualty_modifier: FighterPower = self
+                .boosts
+                .iter()
+                .filter(|boost| boost.owner == unit_in_field.owner)
+                .map(|boost| boost.item.casualty_modifier())
+                .product();
+            let share = (BATTLE_CASUALTY_RATE * casualty_modifier * enemy_power as FighterPower
+                / total_power as FighterPower)
+                .min(1.0);
+            let fallen =
+                ((unit_in_field.unit.quantity as FighterPower) * share).round() as Quantity;
+
+            if fallen == 0 {
+                continue;
+            }
+            unit_in_field.unit.quantity -= fallen;
+
+            // unnamed stacks of the same owner and type are reported together
+            let unit_type = unit_in_field.unit.unit_type;
+            match losses.iter_mut().find(|(owner, regiment, lost_type, _)| {
+                *owner == unit_in_field.owner
+                    && *regiment == unit_in_field.regiment
+                    && *lost_type == unit_type
+            }) {
+                Some((_, _, _, quantity)) => *quantity += fallen,
+                None => losses.push((
+                    unit_in_field.owner.clone(),
+                    unit_in_field.regiment.clone(),
+                    unit_type,
+                    fallen,
+                )),
+            }
         }
 
-        winner.map(|(name, _)| name)
+        self.units_occupying
+            .retain(|unit_in_field| unit_in_field.unit.quantity > 0);
+        self.raze_abandoned_tower();
+
+        Some(BattleReport {
+            field: self.label(),
+            losses,
+            victor: self.leader().map(|(nick, _)| nick),
+        })
+    }
+
+    /// Evaluate who from the conquerors won the field
+    ///
+    /// Returns
+    /// ---
+    /// Some(name): if someone won the field
+    /// None: if the field was conquered (either no one contested it, or could not decide)
+    pub fn evaluate_field(&self) -> Option<String> {
+        // print winner of the field
+        let (winner_name, power) = self.leader()?;
+
+        // this will give us a field with ONLY desired player's units
+        let field = self.players_units(winner_name.clone());
+
+        // get quantity of player's units of every type
+        let units: Vec<String> = UnitType::all()
+            .iter()
+            .map(|unit_type| {
+                let quantity: Quantity = field.get_units_by_type(*unit_type);
+                format!("{} {}{}", quantity, unit_type, plural_suffix(quantity, "S"))
+            })
+            .collect();
+
+        // print who won the field
+        println!(
+            "\nWinner of field {} is {} with {} and resulting fighting power of {:.2}\n",
+            field.label(),
+            winner_name,
+            units.join(", "),
+            power
+        );
+
+        Some(winner_name)
     }
 
     /// Return a copy of a game field, however only with units
@@ -264,12 +1060,21 @@ impl GameField {
             .filter(|unit| unit.owner == owner_nick)
             .cloned()
             .collect();
+        let boosts: Vec<FieldBoost> = self
+            .boosts
+            .iter()
+            .filter(|boost| boost.owner == owner_nick)
+            .cloned()
+            .collect();
 
         // return filtered self
         GameField {
             x: self.x,
             y: self.y,
+            name: self.name.clone(),
             units_occupying: units,
+            boosts,
+            tower: self.tower.clone().filter(|owner| *owner == owner_nick),
         }
     }
 }
@@ -285,6 +1090,189 @@ impl UnitInField {
         Self {
             owner: unit_owner,
             unit,
+            stance: Stance::Raiding,
+            regiment: None,
         }
     }
 }
+
+/// Convert a fighting power into thousandths of a power point
+fn exact_power(power: FighterPower) -> ExactPower {
+    (power * POWER_PRECISION as FighterPower).round() as ExactPower
+}
+
+/// Convert thousandths of a power point back into a fighting power
+fn fighter_power(power: ExactPower) -> FighterPower {
+    power as FighterPower / POWER_PRECISION as FighterPower
+}
+
+/// Return the highest multiplier the power of a player on a field can reach,
+/// either the garrison of the defender or the siege ladder of an attacker, along with fire arrows,
+/// a war banner and a tower
+fn highest_bonus() -> FighterPower {
+    let rules = rules();
+
+    rules.garrison_defense_bonus.max(rules.siege_ladder_bonus)
+        * rules.fire_arrow_bonus.max(1.0)
+        * rules.war_banner_bonus.max(1.0)
+        * rules.tower_bonus.max(1.0)
+}
+
+/// Return the largest difference of powers which is still a tie, in thousandths
+fn tie_tolerance() -> ExactPower {
+    exact_power(rules().tie_tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rules::{set_rules, Rules};
+    use super::*;
+    use std::sync::{Mutex, MutexGuard};
+
+    // the rules are shared by the whole game, the tests changing them must not overlap
+    static RULES_LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock_rules() -> MutexGuard<'static, ()> {
+        RULES_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    // field with the given stacks, each as (owner, unit type, quantity)
+    fn field_with(stacks: &[(&str, UnitType, Quantity)]) -> GameField {
+        let mut field = GameField::new(0, 0);
+
+        for (owner, unit_type, quantity) in stacks {
+            let unit = Unit::unit_to_send(*unit_type, *quantity);
+            field.add_units(UnitInField::new(owner.to_string(), unit));
+        }
+
+        field
+    }
+
+    #[test]
+    fn equal_powers_tie() {
+        let _rules = lock_rules();
+        // the same army in one stack and in three stacks
+        let field = field_with(&[
+            ("Alice", UnitType::Warrior, 1),
+            ("Alice", UnitType::Warrior, 1),
+            ("Alice", UnitType::Warrior, 1),
+            ("Bob", UnitType::Warrior, 3),
+        ]);
+
+        assert!(field.leader().is_none());
+        assert_eq!(field.power_of("Alice"), field.power_of("Bob"));
+        assert!(field.power_needed_to_lead("Alice") > 0.0);
+    }
+
+    #[test]
+    fn powers_within_tie_tolerance_tie() {
+        let _rules = lock_rules();
+        let field = field_with(&[
+            ("Alice", UnitType::Archer, 2),
+            ("Bob", UnitType::Warrior, 3),
+        ]);
+        assert_eq!(
+            field.leader().map(|(nick, _)| nick),
+            Some(String::from("Alice"))
+        );
+
+        let default_rules = rules();
+        set_rules(Rules {
+            tie_tolerance: 0.5,
+            ..default_rules
+        });
+        let leader = field.leader();
+        let needed = field.power_needed_to_lead("Bob");
+        set_rules(default_rules);
+
+        assert!(leader.is_none());
+        assert!(needed > 0.5);
+    }
+
+    #[test]
+    fn mixed_unit_types_tie() {
+        let _rules = lock_rules();
+        // 7 warriors and a cavalry against 4 cavalry, both 11.2 power
+        let mut field = field_with(&[
+            ("Alice", UnitType::Warrior, 7),
+            ("Alice", UnitType::Cavalry, 1),
+            ("Bob", UnitType::Cavalry, 4),
+        ]);
+        assert!(field.leader().is_none());
+
+        field.add_units(UnitInField::new(
+            String::from("Alice"),
+            Unit::unit_to_send(UnitType::Warrior, 1),
+        ));
+        assert_eq!(
+            field.leader().map(|(nick, _)| nick),
+            Some(String::from("Alice"))
+        );
+    }
+
+    // one field plan with the given stacks, the reinforcements of both players are none
+    fn locked_with(
+        stacks: &[(&str, UnitType, Quantity)],
+        healed: &[&str],
+    ) -> HashMap<String, usize> {
+        let mut game_plan = GamePlan::new(1, 1);
+        game_plan.fields[0] = field_with(stacks);
+        let reinforcements =
+            HashMap::from([(String::from("Alice"), 0.0), (String::from("Bob"), 0.0)]);
+        let healed = healed.iter().map(|nick| nick.to_string()).collect();
+
+        game_plan.locked_fields(&reinforcements, &healed)
+    }
+
+    #[test]
+    fn war_banner_keeps_fields_contestable() {
+        let _rules = lock_rules();
+        // 60 power could catch up with 120 only with a war banner on top of the other bonuses
+        let locked = locked_with(
+            &[
+                ("Alice", UnitType::Warrior, 100),
+                ("Bob", UnitType::Warrior, 50),
+            ],
+            &[],
+        );
+        assert!(locked.is_empty());
+
+        let locked = locked_with(
+            &[
+                ("Alice", UnitType::Warrior, 100),
+                ("Bob", UnitType::Warrior, 40),
+            ],
+            &[],
+        );
+        assert_eq!(locked.get("Alice"), Some(&1));
+    }
+
+    #[test]
+    fn healing_potion_keeps_fields_contestable() {
+        let _rules = lock_rules();
+        let stacks = [
+            ("Alice", UnitType::Warrior, 100),
+            ("Bob", UnitType::Warrior, 40),
+        ];
+        assert_eq!(locked_with(&stacks, &[]).get("Alice"), Some(&1));
+        assert!(locked_with(&stacks, &["Bob"]).is_empty());
+
+        // the potion of the leader does not help anybody else
+        assert_eq!(locked_with(&stacks, &["Alice"]).get("Alice"), Some(&1));
+
+        // without any units a potion saves nothing
+        let locked = locked_with(&[("Alice", UnitType::Warrior, 100)], &["Bob"]);
+        assert_eq!(locked.get("Alice"), Some(&1));
+
+        let default_rules = rules();
+        set_rules(Rules {
+            healing_potion_casualties: 1.0,
+            ..default_rules
+        });
+        let locked = locked_with(&stacks, &["Bob"]);
+        set_rules(default_rules);
+        assert_eq!(locked.get("Alice"), Some(&1));
+    }
+}
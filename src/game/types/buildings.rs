@@ -1,39 +1,145 @@
-use super::limits::{BASE_CAPACITY, BASE_COST};
+use super::limits::{FARM_CAPACITY, FARM_LEVEL_CAPACITY, MARKET_CAPACITY, MARKET_LEVEL_CAPACITY};
+use super::modifiers::{cost, CostTarget};
 use super::properties::{HasCapacity, HasValue};
-use super::value_types::{Capacity, ResourceValue};
+use super::rules::rules;
+use super::value_types::{Capacity, Quantity, ResourceValue};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 /// Building types
-#[derive(PartialEq, Clone, Copy)]
-pub enum Building {
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum BuildingType {
     Base,
+    Farm,
+    Market,
 }
 
-/// Used for displaying the building
-impl Display for Building {
+/// Building of a player, which can be upgraded to higher levels
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(from = "SavedBuilding")]
+pub struct Building {
+    pub building_type: BuildingType,
+    pub level: Quantity,
+}
+
+/// Building as stored in a save file
+/// (saves from before the levels were added only contain the type)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedBuilding {
+    Leveled {
+        building_type: BuildingType,
+        level: Quantity,
+    },
+    Unleveled(BuildingType),
+}
+
+impl BuildingType {
+    /// Return every building type in the game
+    pub fn all() -> [BuildingType; 3] {
+        [BuildingType::Base, BuildingType::Farm, BuildingType::Market]
+    }
+
+    /// Return which buildings (and how many of them) have to be built
+    /// before a building of this type can be built
+    pub fn prerequisites(&self) -> Vec<(BuildingType, Quantity)> {
+        match self {
+            BuildingType::Base => Vec::new(),
+            // somebody has to work on the farm
+            BuildingType::Farm => vec![(BuildingType::Base, 1)],
+            // traders only come to a settled kingdom
+            BuildingType::Market => vec![(BuildingType::Base, 1)],
+        }
+    }
+}
+
+impl Building {
+    /// Create a newly built building of level 1
+    ///
+    /// Params
+    /// ---
+    /// - building_type: type of the building
+    pub fn new(building_type: BuildingType) -> Self {
+        Self {
+            building_type,
+            level: 1,
+        }
+    }
+}
+
+impl From<SavedBuilding> for Building {
+    fn from(saved: SavedBuilding) -> Self {
+        match saved {
+            SavedBuilding::Leveled {
+                building_type,
+                level,
+            } => Building {
+                building_type,
+                level,
+            },
+            SavedBuilding::Unleveled(building_type) => Building::new(building_type),
+        }
+    }
+}
+
+/// Used for displaying the building type
+impl Display for BuildingType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Building::Base => write!(f, "BASE"),
+            BuildingType::Base => write!(f, "BASE"),
+            BuildingType::Farm => write!(f, "FARM"),
+            BuildingType::Market => write!(f, "MARKET"),
         }
     }
 }
 
-/// Every Building has a certain capacity
-impl HasCapacity for Building {
+/// Used for displaying the building along with its level
+impl Display for Building {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (LEVEL {})", self.building_type, self.level)
+    }
+}
+
+/// Every building type has a certain capacity at level 1
+impl HasCapacity for BuildingType {
     /// Return how many people can a building fit
     fn capacity(&self) -> Capacity {
         match &self {
-            Self::Base => BASE_CAPACITY,
+            Self::Base => rules().base_capacity,
+            Self::Farm => FARM_CAPACITY,
+            Self::Market => MARKET_CAPACITY,
         }
     }
 }
 
+/// Every level above the first adds to the capacity of the building
+impl HasCapacity for Building {
+    /// Return how many people can the building fit at its level
+    fn capacity(&self) -> Capacity {
+        let level_capacity = match self.building_type {
+            BuildingType::Base => rules().base_level_capacity,
+            BuildingType::Farm => FARM_LEVEL_CAPACITY,
+            BuildingType::Market => MARKET_LEVEL_CAPACITY,
+        };
+
+        self.building_type.capacity() + level_capacity * (self.level - 1)
+    }
+}
+
 /// Every building can be purchased for a certain cost
-impl HasValue for Building {
+impl HasValue for BuildingType {
     /// Return how much a building costs
     fn value(&self) -> ResourceValue {
-        match &self {
-            Building::Base => BASE_COST,
-        }
+        cost(CostTarget::Building(*self))
+    }
+}
+
+/// Upgrading a building gets more expensive with every level
+impl HasValue for Building {
+    /// Return how much the upgrade of the building to the next level costs
+    fn value(&self) -> ResourceValue {
+        let (wood, gold) = cost(CostTarget::Upgrade(self.building_type));
+
+        (wood * self.level, gold * self.level)
     }
 }
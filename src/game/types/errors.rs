@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use super::{
+    buildings::BuildingType,
+    plurals::plural_suffix,
+    resources::ResourceType,
+    value_types::{Capacity, Quantity},
+};
+
+/// Errors of game actions carrying the data needed to remedy them
+#[derive(PartialEq, Clone)]
+pub enum GameError {
+    // the player lacks one or more resources
+    NotEnoughResources(Vec<Shortfall>),
+    // the player wants more fighters than fit into the bases
+    CapacityExceeded {
+        requested: Quantity,
+        remaining: Quantity,
+        total: Capacity,
+    },
+    // the building requires other buildings to be built first
+    MissingPrerequisites {
+        building: BuildingType,
+        missing: Vec<Prerequisite>,
+    },
+}
+
+/// Required building the player does not have enough of
+#[derive(PartialEq, Clone, Copy)]
+pub struct Prerequisite {
+    pub building: BuildingType,
+    pub required: Quantity,
+    pub built: Quantity,
+}
+
+/// Missing amount of a single resource
+#[derive(PartialEq, Clone, Copy)]
+pub struct Shortfall {
+    pub resource: ResourceType,
+    pub needed: Quantity,
+    pub available: Quantity,
+}
+
+impl Shortfall {
+    /// How much more of the resource would be needed
+    pub fn missing(&self) -> Quantity {
+        self.needed - self.available
+    }
+}
+
+/// Renders the error as lines of the action table
+impl Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::NotEnoughResources(shortfalls) => {
+                let lines: Vec<String> = shortfalls
+                    .iter()
+                    .map(|shortfall| {
+                        format!(
+                            "║{:^78}║\n║{:^78}║",
+                            format!(
+                                "You don't have enough {} to perform this operation",
+                                shortfall.resource
+                            ),
+                            format!(
+                                "need {} more {} ({} needed, {} available)",
+                                shortfall.missing(),
+                                shortfall.resource,
+                                shortfall.needed,
+                                shortfall.available
+                            ),
+                        )
+                    })
+                    .collect();
+
+                write!(f, "{}", lines.join("\n"))
+            }
+            GameError::CapacityExceeded {
+                requested,
+                remaining,
+                total,
+            } => write!(
+                f,
+                "║{:^78}║\n║{:^78}║\n║{:^78}║",
+                "Cannot train new fighters, you picked too many units over capacity.",
+                format!(
+                    "{} picked, {} remaining of {} total capacity.",
+                    requested, remaining, total
+                ),
+                "Consider building a new base instead!",
+            ),
+            GameError::MissingPrerequisites { building, missing } => {
+                let lines: Vec<String> = missing
+                    .iter()
+                    .map(|prerequisite| {
+                        format!(
+                            "║{:^78}║",
+                            format!(
+                                "{} {}{} required, {} built",
+                                prerequisite.required,
+                                prerequisite.building,
+                                plural_suffix(prerequisite.required, "S"),
+                                prerequisite.built
+                            )
+                        )
+                    })
+                    .collect();
+
+                write!(
+                    f,
+                    "║{:^78}║\n{}",
+                    format!(
+                        "Cannot build {} yet, other buildings are needed first.",
+                        building
+                    ),
+                    lines.join("\n")
+                )
+            }
+        }
+    }
+}
+
+/// Actions still report their errors as formatted strings
+impl From<GameError> for String {
+    fn from(error: GameError) -> Self {
+        error.to_string()
+    }
+}
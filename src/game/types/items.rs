@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+use super::{
+    board::BattleReport,
+    modifiers::{cost, CostTarget},
+    player::Player,
+    properties::HasValue,
+    rules::rules,
+    troops::UnitType,
+    value_types::{FighterPower, ResourceValue},
+};
+
+/// Consumable items kept in the inventory of a player and used on a field for one round
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum ItemType {
+    /// attackers climb the walls, stronger when their owner is not the defender of the field
+    SiegeLadder,
+    /// burning arrows make the owner's archers stronger
+    FireArrow,
+    /// healers tend the wounded, fewer of the owner's units fall in the battle
+    HealingPotion,
+    /// captured in a won battle, raised on a field it makes all the owner's units stronger
+    WarBanner,
+}
+
+impl ItemType {
+    /// Return every item type in the game
+    pub fn all() -> [ItemType; 4] {
+        [
+            ItemType::SiegeLadder,
+            ItemType::FireArrow,
+            ItemType::HealingPotion,
+            ItemType::WarBanner,
+        ]
+    }
+
+    /// Return the item types which can be bought (in the order they are offered to players)
+    pub fn purchasable() -> [ItemType; 3] {
+        [
+            ItemType::SiegeLadder,
+            ItemType::FireArrow,
+            ItemType::HealingPotion,
+        ]
+    }
+
+    /// Return the power multiplier the item gives to a stack of its owner
+    ///
+    /// Params
+    /// ---
+    /// - unit_type: type of the units in the stack
+    /// - defending: whether the owner is the defender of the field
+    ///
+    /// Returns
+    /// ---
+    /// - multiplier of the stack's power, 1.0 if the item does not help the stack
+    pub fn power_bonus(&self, unit_type: UnitType, defending: bool) -> FighterPower {
+        match self {
+            ItemType::SiegeLadder if !defending => rules().siege_ladder_bonus,
+            ItemType::FireArrow if unit_type == UnitType::Archer => rules().fire_arrow_bonus,
+            ItemType::WarBanner => rules().war_banner_bonus,
+            _ => 1.0,
+        }
+    }
+
+    /// Return the multiplier of the losses the item causes to the owner's units in a battle
+    ///
+    /// Returns
+    /// ---
+    /// - multiplier of the fallen units, 1.0 if the item does not affect the losses
+    pub fn casualty_modifier(&self) -> FighterPower {
+        match self {
+            ItemType::HealingPotion => rules().healing_potion_casualties,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Give every player who won a battle the spoils of war
+///
+/// Params
+/// ---
+/// - players: all players of the game
+/// - reports: reports of the battles fought at the end of the round
+pub fn award_spoils(players: &mut [Player], reports: &[BattleReport]) {
+    for victor in reports.iter().filter_map(|report| report.victor.as_ref()) {
+        if let Some(player) = players.iter_mut().find(|player| player.nick == *victor) {
+            player.grant_items(ItemType::WarBanner, rules().battle_spoils);
+        }
+    }
+}
+
+/// Every item can be purchased for a certain cost
+impl HasValue for ItemType {
+    /// Return how much an item costs
+    fn value(&self) -> ResourceValue {
+        cost(CostTarget::Item(*self))
+    }
+}
+
+/// for displaying item types
+impl Display for ItemType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemType::SiegeLadder => write!(f, "SIEGE LADDER"),
+            ItemType::FireArrow => write!(f, "FIRE ARROW"),
+            ItemType::HealingPotion => write!(f, "HEALING POTION"),
+            ItemType::WarBanner => write!(f, "WAR BANNER"),
+        }
+    }
+}
@@ -1,26 +1,156 @@
-use super::value_types::{Capacity, FighterPower, ResourceValue};
+use super::value_types::{Capacity, ExactPower, FighterPower, Quantity, ResourceValue};
 
 // Set of constants that define our game values
 
 // === BUILDING CAPACITIES ===
 pub const BASE_CAPACITY: Capacity = 200;
+// capacity every level above the first adds to a base
+pub const BASE_LEVEL_CAPACITY: Capacity = 150;
+// farms only grow crops, nobody lives there
+pub const FARM_CAPACITY: Capacity = 0;
+pub const FARM_LEVEL_CAPACITY: Capacity = 0;
+// traders come and go, nobody lives in a market
+pub const MARKET_CAPACITY: Capacity = 0;
+pub const MARKET_LEVEL_CAPACITY: Capacity = 0;
+// buildings cannot be upgraded above this level
+pub const MAX_BUILDING_LEVEL: Quantity = 3;
 // ===========================
 
 // === ITEM COSTS ===
 pub const BASE_COST: ResourceValue = (220, 100);
+pub const FARM_COST: ResourceValue = (150, 60);
+pub const MARKET_COST: ResourceValue = (200, 20);
+// upgrading a building costs this much times its current level
+pub const BASE_UPGRADE_COST: ResourceValue = (140, 70);
+pub const FARM_UPGRADE_COST: ResourceValue = (100, 50);
+pub const MARKET_UPGRADE_COST: ResourceValue = (120, 20);
 pub const ARCHER_COST: ResourceValue = (0, 10);
 pub const WARRIOR_COST: ResourceValue = (10, 5);
+pub const CAVALRY_COST: ResourceValue = (15, 20);
+// upgrading a peasant is cheaper than training a new unit
+pub const ARCHER_RECRUIT_COST: ResourceValue = (0, 5);
+pub const WARRIOR_RECRUIT_COST: ResourceValue = (5, 2);
+pub const CAVALRY_RECRUIT_COST: ResourceValue = (8, 10);
+// consumable items used on a field for one round
+pub const SIEGE_LADDER_COST: ResourceValue = (20, 30);
+pub const FIRE_ARROW_COST: ResourceValue = (0, 40);
+pub const HEALING_POTION_COST: ResourceValue = (10, 30);
+// a tower built on a field stays there as long as its owner keeps units on the field
+pub const TOWER_COST: ResourceValue = (150, 50);
 // ==================
 
 // === ACTION GAINS ===
 pub const HARVEST_GAIN: ResourceValue = (200, 120);
+// resources every farm yields per level at the start of its owner's turn
+pub const FARM_INCOME: ResourceValue = (40, 20);
+// resources gained by passing a whole turn, far less than a harvest
+pub const PASS_GAIN: ResourceValue = (20, 10);
+// war banners captured by the strongest player of a field after a battle there
+pub const BATTLE_SPOILS: Quantity = 1;
 // ====================
 
+// === UPKEEP ===
+// gold every batch of units of the type costs at the start of their owner's turn,
+// wherever the units are, started batches are paid in full
+pub const ARCHER_UPKEEP: Quantity = 1;
+pub const WARRIOR_UPKEEP: Quantity = 1;
+pub const CAVALRY_UPKEEP: Quantity = 2;
+pub const UPKEEP_BATCH: Quantity = 10;
+// ===============
+
+// === POPULATION GROWTH ===
+// free peasants every base produces at the start of its owner's turn
+pub const PEASANTS_PER_BASE: Quantity = 5;
+// =========================
+
 // === UNIT POWERS ====
 pub const ARCHER_POWER: FighterPower = 1.9;
 pub const WARRIOR_POWER: FighterPower = 1.2;
+pub const CAVALRY_POWER: FighterPower = 2.8;
+// power multiplier of garrisoned units of the field's defender
+pub const GARRISON_DEFENSE_BONUS: FighterPower = 1.25;
+// power multiplier of the units of a field's attackers using a siege ladder there
+pub const SIEGE_LADDER_BONUS: FighterPower = 1.2;
+// power multiplier of archers using fire arrows on a field
+pub const FIRE_ARROW_BONUS: FighterPower = 1.3;
+// power multiplier of all the units of a player raising a war banner on a field
+pub const WAR_BANNER_BONUS: FighterPower = 1.1;
+// power multiplier of all the units of the owner of a tower on its field
+pub const TOWER_BONUS: FighterPower = 1.15;
+// multiplier of the losses of units with a healing potion in a battle
+pub const HEALING_POTION_CASUALTIES: FighterPower = 0.5;
+// share of the enemies' part of the total power on a field, which is lost every round
+pub const BATTLE_CASUALTY_RATE: FighterPower = 0.5;
+// powers closer than this are considered equal, only exactly equal powers tie by default
+pub const POWER_TIE_TOLERANCE: FighterPower = 0.0;
+// fighting power of every stack is counted in thousandths of a power point
+pub const POWER_PRECISION: ExactPower = 1000;
 // ====================
 
+// === MARKET ===
+// wood and gold the market opens with, their ratio sets the first exchange rate
+pub const MARKET_RESERVES: ResourceValue = (2000, 1000);
+// percents of every exchange the market keeps, divided by the level of the player's best market
+pub const MARKET_FEE: Quantity = 10;
+// ================
+
+// === REVOLTS ===
+// fields every base keeps in order, players leading more fields risk revolts of the weakest ones
+pub const FIELDS_PER_BASE: Quantity = 2;
+// chance in percents that a field above the limit revolts at the end of a round
+pub const REVOLT_CHANCE: Quantity = 25;
+// ================
+
+// === VICTORY ===
+// gold a player has to hold at the end of a round to win an economic victory, unless another amount is picked
+pub const DEFAULT_ECONOMIC_VICTORY_GOLD: Quantity = 2000;
+// ===============
+
 // === DEFAULT GAME SIZE ====
 pub const DEFAULT_PLAN_WIDTH: usize = 1;
 pub const DEFAULT_PLAN_HEIGHT: usize = 1;
+// custom battlefields cannot be larger than this
+pub const MAX_PLAN_WIDTH: usize = 10;
+pub const MAX_PLAN_HEIGHT: usize = 10;
+
+// === BATTLEFIELD MAP ===
+// width of one field in the map, without the border
+pub const MAP_CELL_WIDTH: usize = 16;
+// nicks are shortened to fit into the map
+pub const MAP_NICK_LENGTH: usize = 8;
+
+// === FOG OF WAR ===
+// turns of its owner a scouted field stays revealed, including the turn of scouting
+pub const SCOUTING_TURNS: usize = 2;
+
+// === ACTION POINTS ===
+// points every player can spend on actions in their turn
+pub const ACTION_POINTS_PER_TURN: Quantity = 3;
+pub const BUILD_ACTION_POINTS: Quantity = 2;
+pub const UPGRADE_ACTION_POINTS: Quantity = 2;
+pub const HARVEST_ACTION_POINTS: Quantity = 2;
+pub const TRAIN_ACTION_POINTS: Quantity = 1;
+pub const RECRUIT_ACTION_POINTS: Quantity = 1;
+pub const TRAIN_ARMY_ACTION_POINTS: Quantity = 2;
+pub const CONQUER_ACTION_POINTS: Quantity = 1;
+pub const STANCE_ACTION_POINTS: Quantity = 1;
+pub const SCOUT_ACTION_POINTS: Quantity = 1;
+pub const RECALL_ACTION_POINTS: Quantity = 1;
+pub const BUY_ITEM_ACTION_POINTS: Quantity = 1;
+pub const USE_ITEM_ACTION_POINTS: Quantity = 1;
+pub const TRADE_ACTION_POINTS: Quantity = 1;
+pub const EXCHANGE_ACTION_POINTS: Quantity = 1;
+pub const FORTIFY_ACTION_POINTS: Quantity = 2;
+// passing takes up the whole turn, so it can only be chosen instead of other actions
+pub const PASS_ACTION_POINTS: Quantity = ACTION_POINTS_PER_TURN;
+
+// === BOTS ===
+// milliseconds a bot may think about its action before it harvests instead
+pub const DEFAULT_BOT_TIME_LIMIT: u64 = 2000;
+
+// === FIELD NAMES ===
+pub const MAX_FIELD_NAME_LENGTH: usize = 30;
+
+// === REGIMENTS ===
+// stacks of at least this many units deployed on a field get a name
+pub const REGIMENT_SIZE: Quantity = 20;
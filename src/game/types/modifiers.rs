@@ -0,0 +1,202 @@
+// Modifiers of the costs, powers and incomes of the game.
+// The rules give the base values, then every registered modifier may change them,
+// in the order they were registered. Systems changing the balance of the game
+// (f.e. technologies, morale, weather, terrain or mods) implement the Modifier trait
+// instead of looking the values up and adjusting them on their own.
+
+use std::sync::RwLock;
+
+use super::{
+    buildings::BuildingType,
+    items::ItemType,
+    rules::rules,
+    troops::UnitType,
+    value_types::{FighterPower, ResourceValue},
+};
+
+/// What a cost is paid for
+#[derive(PartialEq, Clone, Copy)]
+pub enum CostTarget {
+    /// a new building
+    Building(BuildingType),
+    /// an upgrade of a building, per its current level
+    Upgrade(BuildingType),
+    /// a newly trained unit
+    Unit(UnitType),
+    /// an upgrade of a peasant into a unit
+    Recruit(UnitType),
+    /// a consumable item
+    Item(ItemType),
+    /// a tower on a field
+    Tower,
+}
+
+/// Where an income comes from
+#[derive(PartialEq, Clone, Copy)]
+pub enum IncomeSource {
+    /// the harvest action
+    Harvest,
+    /// one level of a farm, at the start of its owner's turn
+    Farm,
+    /// a passed turn
+    Pass,
+}
+
+/// Change of the balance values of the game
+///
+/// Every method gets the value computed so far and returns the changed one,
+/// the default implementations leave the values as they are.
+/// Modifiers must not look up modified values themselves.
+pub trait Modifier: Send + Sync {
+    /// Return the name of the modifier, f.e. 'Rainy weather'
+    fn name(&self) -> String;
+
+    /// Change a cost
+    ///
+    /// Params
+    /// ---
+    /// - _target: what the cost is paid for
+    /// - cost: (wood, gold) computed so far
+    fn modify_cost(&self, _target: CostTarget, cost: ResourceValue) -> ResourceValue {
+        cost
+    }
+
+    /// Change the fighting power of one unit
+    ///
+    /// Params
+    /// ---
+    /// - _unit_type: type of the unit
+    /// - power: power computed so far
+    fn modify_power(&self, _unit_type: UnitType, power: FighterPower) -> FighterPower {
+        power
+    }
+
+    /// Change an income
+    ///
+    /// Params
+    /// ---
+    /// - _source: where the income comes from
+    /// - income: (wood, gold) computed so far
+    fn modify_income(&self, _source: IncomeSource, income: ResourceValue) -> ResourceValue {
+        income
+    }
+}
+
+// modifiers the game is played with, in the order they are applied
+static ACTIVE_MODIFIERS: RwLock<Vec<Box<dyn Modifier>>> = RwLock::new(Vec::new());
+
+/// Apply a modifier from now on, after the ones added before
+///
+/// Params
+/// ---
+/// - modifier: the new modifier
+pub fn add_modifier(modifier: Box<dyn Modifier>) {
+    match ACTIVE_MODIFIERS.write() {
+        Ok(mut active) => active.push(modifier),
+        Err(poisoned) => poisoned.into_inner().push(modifier),
+    }
+}
+
+/// Remove all the modifiers, only the rules apply from now on
+pub fn clear_modifiers() {
+    match ACTIVE_MODIFIERS.write() {
+        Ok(mut active) => active.clear(),
+        Err(poisoned) => poisoned.into_inner().clear(),
+    }
+}
+
+/// Return the names of the applied modifiers, in the order they are applied
+pub fn active_modifiers() -> Vec<String> {
+    with_modifiers(|modifiers| modifiers.iter().map(|modifier| modifier.name()).collect())
+}
+
+/// Compute a cost, the rules changed by all the modifiers
+///
+/// Params
+/// ---
+/// - target: what the cost is paid for
+///
+/// Returns
+/// ---
+/// - (wood, gold) to pay
+pub fn cost(target: CostTarget) -> ResourceValue {
+    let rules = rules();
+    let base = match target {
+        CostTarget::Building(BuildingType::Base) => rules.base_cost,
+        CostTarget::Building(BuildingType::Farm) => rules.farm_cost,
+        CostTarget::Building(BuildingType::Market) => rules.market_cost,
+        CostTarget::Upgrade(BuildingType::Base) => rules.base_upgrade_cost,
+        CostTarget::Upgrade(BuildingType::Farm) => rules.farm_upgrade_cost,
+        CostTarget::Upgrade(BuildingType::Market) => rules.market_upgrade_cost,
+        CostTarget::Unit(UnitType::Archer) => rules.archer_cost,
+        CostTarget::Unit(UnitType::Warrior) => rules.warrior_cost,
+        CostTarget::Unit(UnitType::Cavalry) => rules.cavalry_cost,
+        CostTarget::Recruit(UnitType::Archer) => rules.archer_recruit_cost,
+        CostTarget::Recruit(UnitType::Warrior) => rules.warrior_recruit_cost,
+        CostTarget::Recruit(UnitType::Cavalry) => rules.cavalry_recruit_cost,
+        CostTarget::Item(ItemType::SiegeLadder) => rules.siege_ladder_cost,
+        CostTarget::Item(ItemType::FireArrow) => rules.fire_arrow_cost,
+        CostTarget::Item(ItemType::HealingPotion) => rules.healing_potion_cost,
+        // banners are never sold, they are only captured
+        CostTarget::Item(ItemType::WarBanner) => (0, 0),
+        CostTarget::Tower => rules.tower_cost,
+    };
+
+    with_modifiers(|modifiers| {
+        modifiers
+            .iter()
+            .fold(base, |cost, modifier| modifier.modify_cost(target, cost))
+    })
+}
+
+/// Compute the fighting power of one unit, the rules changed by all the modifiers
+///
+/// Params
+/// ---
+/// - unit_type: type of the unit
+pub fn power(unit_type: UnitType) -> FighterPower {
+    let rules = rules();
+    let base = match unit_type {
+        UnitType::Archer => rules.archer_power,
+        UnitType::Warrior => rules.warrior_power,
+        UnitType::Cavalry => rules.cavalry_power,
+    };
+
+    with_modifiers(|modifiers| {
+        modifiers.iter().fold(base, |power, modifier| {
+            modifier.modify_power(unit_type, power)
+        })
+    })
+}
+
+/// Compute an income, the rules changed by all the modifiers
+///
+/// Params
+/// ---
+/// - source: where the income comes from
+///
+/// Returns
+/// ---
+/// - (wood, gold) gained
+pub fn income(source: IncomeSource) -> ResourceValue {
+    let rules = rules();
+    let base = match source {
+        IncomeSource::Harvest => rules.harvest_gain,
+        IncomeSource::Farm => rules.farm_income,
+        IncomeSource::Pass => rules.pass_gain,
+    };
+
+    with_modifiers(|modifiers| {
+        modifiers.iter().fold(base, |income, modifier| {
+            modifier.modify_income(source, income)
+        })
+    })
+}
+
+/// Run a computation with the active modifiers
+fn with_modifiers<T>(compute: impl FnOnce(&[Box<dyn Modifier>]) -> T) -> T {
+    match ACTIVE_MODIFIERS.read() {
+        Ok(modifiers) => compute(&modifiers),
+        Err(poisoned) => compute(&poisoned.into_inner()),
+    }
+}
@@ -1,26 +1,85 @@
+use super::super::ai::BotStrategy;
+use super::super::colors::paint_player;
+use super::super::economy::Economy;
+use super::super::layout::is_narrow_terminal;
+use super::super::stats::PlayerStats;
 use super::{
     actions::Actions,
     board::{GameField, GamePlan, UnitInField},
-    buildings::Building,
+    buildings::{Building, BuildingType},
+    errors::{GameError, Prerequisite, Shortfall},
+    items::ItemType,
     limits,
-    properties::{HasCapacity, HasValue},
+    modifiers::{cost, income, CostTarget, IncomeSource},
+    plurals::{format_number, plural_suffix, plural_word},
+    properties::{HasCapacity, HasPower, HasValue},
     resources::{
-        Resource,
+        Resource, ResourceType,
         ResourceType::{Gold, Wood},
     },
-    troops::{Unit, UnitType},
-    value_types::Quantity,
+    rules::rules,
+    trade::{describe_resources, TradeOffer},
+    troops::{ArmyTemplate, PeasantUpgrade, Stance, Unit, UnitType},
+    value_types::{FighterPower, Quantity, ResourceValue},
 };
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Player structure containing necessary information
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct Player {
     pub nick: String,
     buildings: Vec<Building>,
     archers: Unit,
     warriors: Unit,
+    // saves from before cavalry was added have no riders
+    #[serde(default = "no_cavalry")]
+    cavalry: Unit,
+    peasants: Quantity,
     wood: Resource,
     gold: Resource,
+    // strategy of a computer controlled player
+    #[serde(default)]
+    bot: Option<BotStrategy>,
+    // armies the player saved to train them in one action
+    #[serde(default)]
+    templates: Vec<ArmyTemplate>,
+    // fields revealed by scouting in the fog of war
+    #[serde(default)]
+    scouted: Vec<ScoutedField>,
+    // last known state of the fields the player has seen in the fog of war
+    #[serde(default)]
+    intel: Vec<FieldIntel>,
+    // the player has sent units to the battlefield, so they can be defeated
+    #[serde(default)]
+    went_to_war: bool,
+    // consumable items bought and not used yet
+    #[serde(default)]
+    inventory: HashMap<ItemType, Quantity>,
+    // trade offers made to other players, waiting for their answer
+    #[serde(default)]
+    offers: Vec<TradeOffer>,
+    // tally of the performed actions for the scoreboard
+    #[serde(default)]
+    stats: PlayerStats,
+}
+
+/// Last known state of a field the player has seen
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct FieldIntel {
+    /// round in which the field was seen
+    pub round: usize,
+    /// copy of the field at that time
+    pub field: GameField,
+}
+
+/// Field revealed by scouting
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+struct ScoutedField {
+    x: usize,
+    y: usize,
+    // turns of the player the field stays revealed
+    turns_left: usize,
 }
 
 impl Player {
@@ -39,11 +98,70 @@ impl Player {
             buildings: Vec::new(),
             archers: Unit::new(UnitType::Archer),
             warriors: Unit::new(UnitType::Warrior),
+            cavalry: Unit::new(UnitType::Cavalry),
+            peasants: 0,
             wood: Resource::new(Wood),
             gold: Resource::new(Gold),
+            bot: None,
+            templates: Vec::new(),
+            scouted: Vec::new(),
+            intel: Vec::new(),
+            went_to_war: false,
+            inventory: HashMap::new(),
+            offers: Vec::new(),
+            stats: PlayerStats::default(),
+        }
+    }
+
+    /// Create new computer controlled player structure
+    ///
+    /// Params
+    /// ---
+    /// - nick: Player's nickname
+    /// - strategy: strategy the bot follows
+    ///
+    /// Returns
+    /// ---
+    /// - New player instance
+    pub fn new_bot(nick: &str, strategy: BotStrategy) -> Self {
+        Player {
+            bot: Some(strategy),
+            ..Player::new(nick)
         }
     }
 
+    /// Create a fresh player for a rematch
+    ///
+    /// Returns
+    /// ---
+    /// - New player instance with the same nick, controlled the same way
+    pub fn rematch(&self) -> Self {
+        Player {
+            bot: self.bot,
+            templates: self.templates.clone(),
+            ..Player::new(&self.nick)
+        }
+    }
+
+    /// Let a bot play for the player from now on
+    ///
+    /// Params
+    /// ---
+    /// - strategy: strategy the bot follows
+    pub fn hand_to_bot(&mut self, strategy: BotStrategy) {
+        self.bot = Some(strategy);
+    }
+
+    /// Return the strategy of a computer controlled player
+    ///
+    /// Returns
+    /// ---
+    /// - Some(strategy): if the player is a bot
+    /// - None: if the player is a human
+    pub fn bot(&self) -> Option<BotStrategy> {
+        self.bot
+    }
+
     /// Pays for an item (Reduces amount of a resource)
     ///
     /// Params
@@ -54,12 +172,41 @@ impl Player {
     /// Returns
     /// ---
     /// - Ok(()) on successful payment
-    /// - Err(String) containing details of what error occurred
-    fn pay_for_item<T: HasValue>(&mut self, item: T, quantity: Quantity) -> Result<(), String> {
-        // get item value
-        let (wood, gold) = item.value();
-        // get value we need to subtract
-        let (wood, gold) = (wood * quantity, gold * quantity);
+    /// - Err(GameError) containing the missing resources
+    fn pay_for_item<T: HasValue>(&mut self, item: T, quantity: Quantity) -> Result<(), GameError> {
+        self.pay_for_bundle(&[(&item, quantity)])
+    }
+
+    /// Pays for a bundle of items at once, either all of them are paid or none
+    ///
+    /// The total cost of the bundle is validated before any resource is reduced,
+    /// items of different kinds (f.e. units and buildings) can be mixed.
+    ///
+    /// Params
+    /// ---
+    /// - bundle: items that we want to pay for, along with their quantities
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(()) on successful payment
+    /// - Err(GameError) containing every resource the whole bundle is short of
+    pub fn pay_for_bundle(
+        &mut self,
+        bundle: &[(&dyn HasValue, Quantity)],
+    ) -> Result<(), GameError> {
+        // get value we need to subtract for all the items together,
+        // a price out of the range of quantities cannot be paid by anybody
+        let price = |value: fn(ResourceValue) -> Quantity| {
+            bundle
+                .iter()
+                .try_fold(0 as Quantity, |total, (item, quantity)| {
+                    value(item.value())
+                        .checked_mul(*quantity)
+                        .and_then(|price| total.checked_add(price))
+                })
+                .unwrap_or(Quantity::MAX)
+        };
+        let (wood, gold) = (price(|(wood, _)| wood), price(|(_, gold)| gold));
 
         // check if the player can pay for the item
         match self.wood.can_pay(wood) && self.gold.can_pay(gold) {
@@ -73,27 +220,14 @@ impl Player {
             }
             // user cannot pay for the item
             false => {
-                // Get wood error message, if user doesn't have enough wood
-                let cannot_wood = match self.wood.can_pay(wood) {
-                    true => "".into(),
-                    false => self.wood.cannot_pay(),
-                };
-
-                // Get gold error message, if user doesn't have enough gold
-                let cannot_gold = match self.gold.can_pay(gold) {
-                    true => "".into(),
-                    false => self.gold.cannot_pay(),
-                };
-
-                // if the gold was sufficient, only wood error is displayed, hence
-                // the newline at the end of the message needs to be trimmed
-                let cannot_wood = match cannot_gold.as_str() {
-                    "" => cannot_wood.trim_end(),
-                    _ => cannot_wood.as_str(),
-                };
+                // list every resource the player is short of
+                let shortfalls: Vec<Shortfall> = [(self.wood, wood), (self.gold, gold)]
+                    .into_iter()
+                    .filter(|(resource, price)| !resource.can_pay(*price))
+                    .map(|(resource, price)| resource.shortfall(price))
+                    .collect();
 
-                // return formatted error
-                Err(format!("{}{}", cannot_wood, cannot_gold.trim_end()))
+                Err(GameError::NotEnoughResources(shortfalls))
             }
         }
     }
@@ -103,22 +237,43 @@ impl Player {
     /// Params
     /// ---
     /// - building_type: type of a building to be built
+    /// - quantity: how many buildings of this type are to be built
     ///
     /// Returns
     /// ---
     /// - Ok(String) if a building was built successfully
     /// - Err(String) containing details of error that occurred while building the building
-    fn build_a_building(&mut self, building_type: Building) -> Result<String, String> {
-        // Check if the user can afford to build a building
-        self.pay_for_item(building_type, 1)?;
+    fn build_a_building(
+        &mut self,
+        building_type: BuildingType,
+        quantity: Quantity,
+    ) -> Result<String, String> {
+        // all the required buildings have to be built first
+        let missing = self.missing_prerequisites(building_type);
+        if !missing.is_empty() {
+            return Err(GameError::MissingPrerequisites {
+                building: building_type,
+                missing,
+            }
+            .into());
+        }
 
-        // create a new building of a desired type
-        self.buildings.push(building_type);
+        // Check if the user can afford to build all the buildings at once
+        self.pay_for_item(building_type, quantity)?;
+
+        // create new buildings of a desired type
+        (0..quantity).for_each(|_| self.buildings.push(Building::new(building_type)));
 
         // success message
         Ok(format!(
             "║{:^78}║\n║{:^78}║",
-            format!("Building of type {} was successfully built!", building_type),
+            format!(
+                "{} {} of type {} {} successfully built!",
+                quantity,
+                plural_word(quantity, "building", "buildings"),
+                building_type,
+                plural_word(quantity, "was", "were"),
+            ),
             format!(
                 "You currently have {} buildings of type {}.",
                 self.number_of_buildings(building_type),
@@ -149,6 +304,7 @@ impl Player {
         let current_quantity = match unit_type {
             UnitType::Archer => self.archers.quantity,
             UnitType::Warrior => self.warriors.quantity,
+            UnitType::Cavalry => self.cavalry.quantity,
         };
 
         // check if user has enough units
@@ -156,8 +312,10 @@ impl Player {
             return Err(format!(
                 "║{:^78}║\n║{:^78}║",
                 format!(
-                    "Cannot send {} units of type {} to occupy field ({},{}).",
-                    quantity, unit_type, game_field.x, game_field.y,
+                    "Cannot send {} units of type {} to occupy field {}.",
+                    quantity,
+                    unit_type,
+                    game_field.label(),
                 ),
                 format!("Not enough units available ({}).", current_quantity,),
             ));
@@ -177,7 +335,7 @@ impl Player {
     /// Returns
     /// - Ok(String) if troops were sent successfully
     /// - Err(String) if troops could not be sent
-    ///               (field does not exist or user does not have enough units)
+    ///   (field does not exist or user does not have enough units)
     fn occupy_fields(
         &mut self,
         game_field: Option<&mut GameField>,
@@ -208,7 +366,9 @@ impl Player {
         match unit_type {
             UnitType::Archer => self.archers.send_occupy(quantity),
             UnitType::Warrior => self.warriors.send_occupy(quantity),
+            UnitType::Cavalry => self.cavalry.send_occupy(quantity),
         }
+        self.went_to_war = true;
 
         // Success string
         Ok(format!(
@@ -217,193 +377,1414 @@ impl Player {
                 "{} units of type {} were successfully sent",
                 quantity, unit_type,
             ),
-            format!("to occupy field ({},{})!", game_field.x, game_field.y,),
+            format!("to occupy field {}!", game_field.label()),
         ))
     }
 
-    /// Harvest crops from the surroundings of player's kingdom
+    /// Perform action -> recall units from a field back into player's bases
+    ///
+    /// Params
+    /// ---
+    /// - game_field: field to recall the units from
+    /// - unit_type: which unit type to recall
+    /// - quantity: how many units of said type to recall
     ///
     /// Returns
-    /// - `Ok(String)` that the harvest was successful
-    /// - Err(String) will never happen, the function is just compliant to the return type of other actions
-    fn harvest(&mut self) -> Result<String, String> {
-        // get the amount of gained crops
-        let (wood, gold) = limits::HARVEST_GAIN;
+    /// - Ok(String) if the units returned
+    /// - Err(String) if the units could not be recalled
+    ///   (field does not exist, there are not enough units or no room in the bases)
+    fn recall_units(
+        &mut self,
+        game_field: Option<&mut GameField>,
+        unit_type: UnitType,
+        quantity: Quantity,
+    ) -> Result<String, String> {
+        let game_field = match game_field {
+            Some(game_field) => game_field,
+            None => {
+                return Err(format!(
+                    "║{:^78}║\n",
+                    "Sorry. Specified game field does not exist!",
+                ))
+            }
+        };
 
-        // add resources
-        // this also will not fail, as we never get to add 0 resources to anything
-        self.wood.add(wood)?;
-        self.gold.add(gold)?;
+        let on_field = game_field.units_of(&self.nick, unit_type);
+        if quantity <= 0 || on_field < quantity {
+            return Err(format!(
+                "║{:^78}║",
+                format!(
+                    "You have only {} units of type {} on field {}.",
+                    on_field,
+                    unit_type,
+                    game_field.label()
+                ),
+            ));
+        }
+
+        // returning units need room in the bases
+        self.check_fighters_capacity(quantity)?;
+
+        game_field.withdraw_units(&self.nick, unit_type, quantity);
+        match unit_type {
+            UnitType::Archer => self.archers.train(quantity),
+            UnitType::Warrior => self.warriors.train(quantity),
+            UnitType::Cavalry => self.cavalry.train(quantity),
+        }
 
-        // return the formatted output
         Ok(format!(
-            "║{:^78}║\n║{:^78}║\n║{:^78}║",
-            "Harvest was a success!",
-            format!("Gained {} wood and {} gold!", wood, gold,),
+            "║{:^78}║\n║{:^78}║",
             format!(
-                "Current warehouse supplies are: {}, {}.",
-                self.wood, self.gold
-            )
+                "{} units of type {} were recalled from field {}",
+                quantity,
+                unit_type,
+                game_field.label()
+            ),
+            "and returned to your bases!",
         ))
     }
 
-    /// Get number of buildings of desired type
+    /// Perform action -> buy consumable items into the inventory
     ///
     /// Params
     /// ---
-    /// - building_type: type of a desired building
+    /// - item: type of the item
+    /// - quantity: how many items to buy
     ///
     /// Returns
-    /// ---
-    /// - number of buildings of said type
-    fn number_of_buildings(&self, building_type: Building) -> Quantity {
-        self.buildings
-            .iter()
-            .filter(|building| **building == building_type)
-            .map(|_| 1)
-            .sum()
-    }
+    /// - Ok(String) if the items were bought
+    /// - Err(String) if the player cannot afford them
+    fn buy_items(&mut self, item: ItemType, quantity: Quantity) -> Result<String, String> {
+        if quantity <= 0 {
+            return Err(format!("║{:^78}║", "Please buy at least one item."));
+        }
 
-    /// Get current fighters capacity
-    ///
-    /// Returns
-    /// ---
-    /// - current capacity to train fighters
-    pub fn current_fighters_capacity(&self) -> Quantity {
-        self.fighters_capacity() - self.archers.quantity - self.warriors.quantity
-    }
+        if !ItemType::purchasable().contains(&item) {
+            return Err(format!(
+                "║{:^78}║",
+                format!("{}S cannot be bought, they are captured in battles.", item),
+            ));
+        }
 
-    /// Return maximal capacity of warriors that can be stored in player's territory
-    ///
-    /// Returns
-    /// ---
-    /// - maximal fighters capacity
-    fn fighters_capacity(&self) -> Quantity {
-        self.buildings
-            .iter()
-            .filter(|building| **building == Building::Base)
-            .map(|base| base.capacity())
-            .sum()
-    }
+        self.pay_for_item(item, quantity)?;
+        self.grant_items(item, quantity);
 
-    /// Check if player has fighters available
-    ///
-    /// Returns
-    /// ---
-    /// - true: if there are some units available to send
-    /// - false: otherwise
-    pub fn has_fighters_available(&self) -> bool {
-        self.archers.quantity + self.warriors.quantity > 0
+        Ok(format!(
+            "║{:^78}║\n║{:^78}║",
+            format!(
+                "{} {}{} {} bought!",
+                quantity,
+                item,
+                plural_suffix(quantity, "S"),
+                plural_word(quantity, "was", "were"),
+            ),
+            format!(
+                "You currently have {} {}{} in your inventory.",
+                self.items(item),
+                item,
+                plural_suffix(self.items(item), "S"),
+            )
+        ))
     }
 
-    /// Check fighters total capacity in bases
+    /// Perform action -> use an item from the inventory on a field for the rest of the round
     ///
     /// Params
     /// ---
-    /// - new_quantity: how many more fighters does user want to train
+    /// - game_field: field to use the item on
+    /// - item: type of the item
     ///
     /// Returns
-    /// ---
-    /// - Ok(()) on correct capacity
-    /// - Err(String) containing details about the error that occurred
-    fn check_fighters_capacity(&mut self, new_quantity: Quantity) -> Result<(), String> {
-        // capacity exceeded
-        if self.current_fighters_capacity() < new_quantity {
+    /// - Ok(String) if the item was used
+    /// - Err(String) if the field does not exist, the player has no such item,
+    ///   no units on the field or has already used the same item there
+    fn use_item(
+        &mut self,
+        game_field: Option<&mut GameField>,
+        item: ItemType,
+    ) -> Result<String, String> {
+        let game_field = match game_field {
+            Some(game_field) => game_field,
+            None => {
+                return Err(format!(
+                    "║{:^78}║\n",
+                    "Sorry. Specified game field does not exist!",
+                ))
+            }
+        };
+
+        if self.items(item) == 0 {
+            return Err(format!(
+                "║{:^78}║",
+                format!("You have no {} in your inventory.", item),
+            ));
+        }
+
+        // only units on the field can make use of the item
+        if !game_field.occupants().contains(&self.nick) {
+            return Err(format!(
+                "║{:^78}║",
+                format!("You have no units on field {}.", game_field.label()),
+            ));
+        }
+
+        if !game_field.add_boost(&self.nick, item) {
             return Err(format!(
-                "║{:^78}║\n║{:^78}║\n║{:^78}║",
-                "Cannot train new fighters, you picked too many units over capacity.",
+                "║{:^78}║",
                 format!(
-                    "{} picked, {} is total capacity.",
-                    new_quantity,
-                    self.fighters_capacity()
+                    "You have already used a {} on field {} this round.",
+                    item,
+                    game_field.label()
                 ),
-                "Consider building a new base instead!",
             ));
         }
 
-        Ok(())
+        // the item is used up right away, even if the units leave the field
+        self.inventory
+            .entry(item)
+            .and_modify(|quantity| *quantity -= 1);
+
+        Ok(format!(
+            "║{:^78}║\n║{:^78}║",
+            format!("Your units on field {} use a {}!", game_field.label(), item),
+            "It helps them in the battle at the end of this round.",
+        ))
     }
 
-    /// Trains units if there is enough capacity and enough resources available for training
-    /// Only one type per round is permitted
+    /// Build a tower on a field with units of the player
     ///
     /// Params
     /// ---
-    /// - unit_type: type of the trained unit
-    /// - quantity: how many units are to be trained
+    /// - game_field: field to build the tower on
     ///
     /// Returns
     /// ---
-    /// - Ok(String) after successfully training the units
-    /// - Err(String) containing error message
-    fn train_units(&mut self, unit_type: UnitType, quantity: Quantity) -> Result<String, String> {
-        // compute whether we are within capacity
-        self.check_fighters_capacity(quantity)?;
+    /// - Ok(String) if the tower was built
+    /// - Err(String) containing details of error that occurred while building the tower
+    fn fortify(&mut self, game_field: Option<&mut GameField>) -> Result<String, String> {
+        let game_field = match game_field {
+            Some(game_field) => game_field,
+            None => {
+                return Err(format!(
+                    "║{:^78}║\n",
+                    "Sorry. Specified game field does not exist!",
+                ))
+            }
+        };
 
-        // try to pay for an item
-        self.pay_for_item(unit_type, quantity)?;
+        // the tower is built by the units on the field
+        if !game_field.occupants().contains(&self.nick) {
+            return Err(format!(
+                "║{:^78}║",
+                format!("You have no units on field {}.", game_field.label()),
+            ));
+        }
 
-        // train desired unit type
-        match unit_type {
-            UnitType::Archer => self.archers.train(quantity),
-            UnitType::Warrior => self.warriors.train(quantity),
+        if let Some(owner) = game_field.tower() {
+            return Err(format!(
+                "║{:^78}║",
+                format!(
+                    "A TOWER of {} already stands on field {}.",
+                    owner,
+                    game_field.label()
+                ),
+            ));
         }
 
-        // language differences for plurals
-        let quantity_string = if quantity == 1 { "unit" } else { "units" };
-        let plural = if quantity == 1 { "" } else { "S" };
+        self.pay_for_item(cost(CostTarget::Tower), 1)?;
+        game_field.build_tower(&self.nick);
 
-        // training was successful
         Ok(format!(
-            "║{:^78}║",
-            format!(
-                "Training of {} {} of {}{} was successful",
-                quantity, quantity_string, unit_type, plural
-            ),
+            "║{:^78}║\n║{:^78}║",
+            format!("Your units built a TOWER on field {}!", game_field.label()),
+            "It shelters them as long as they hold the field.",
         ))
     }
 
-    /// Performs a specified game action
+    /// Return how many items of given type the player has in the inventory
+    pub fn items(&self, item: ItemType) -> Quantity {
+        self.inventory.get(&item).copied().unwrap_or(0)
+    }
+
+    /// Add items to the inventory without paying for them, f.e. as spoils of war
     ///
     /// Params
     /// ---
-    /// - action: which action to perform
+    /// - item: type of the item
+    /// - quantity: how many items to add
+    pub fn grant_items(&mut self, item: ItemType, quantity: Quantity) {
+        if quantity > 0 {
+            *self.inventory.entry(item).or_insert(0) += quantity;
+        }
+    }
+
+    /// Perform action -> offer resources to another player in exchange for theirs
     ///
-    /// Returns
+    /// The offered resources are put aside until the other player answers the offer.
+    ///
+    /// Params
     /// ---
-    /// - Ok(String) to print when everything went well,
-    /// - Err(String) when an error occurred
-    pub fn perform_action(
+    /// - to: nick of the player the offer is made to
+    /// - give: (wood, gold) offered to the other player
+    /// - want: (wood, gold) wanted in return
+    ///
+    /// Returns
+    /// - Ok(String) if the offer was made
+    /// - Err(String) if the offer is empty, made to the player themselves,
+    ///   the player already has an offer for the other player or cannot afford it
+    fn offer_trade(
         &mut self,
-        action: Actions,
-        game_plan: &mut GamePlan,
+        to: String,
+        give: ResourceValue,
+        want: ResourceValue,
     ) -> Result<String, String> {
-        match action {
-            Actions::Build(building) => self.build_a_building(building),
-            Actions::Conquer(x, y, unit_type, quantity) => {
-                self.occupy_fields(game_plan.get_game_field(x, y), unit_type, quantity)
-            }
-            Actions::Harvest => self.harvest(),
-            Actions::Train(unit_type, quantity) => self.train_units(unit_type, quantity),
-            _ => Ok("Unreachable statement".into()),
+        if to == self.nick {
+            return Err(format!("║{:^78}║", "You cannot trade with yourself."));
+        }
+
+        if [give.0, give.1, want.0, want.1]
+            .iter()
+            .any(|value| *value < 0)
+        {
+            return Err(format!(
+                "║{:^78}║",
+                "Resources in a trade cannot be negative."
+            ));
+        }
+
+        if give == (0, 0) && want == (0, 0) {
+            return Err(format!("║{:^78}║", "Please offer or ask for something."));
         }
+
+        if self.offers.iter().any(|offer| offer.to == to) {
+            return Err(format!(
+                "║{:^78}║",
+                format!("{} has not answered your last offer yet.", to),
+            ));
+        }
+
+        self.pay_for_item(give, 1)?;
+
+        let notification = format!(
+            "║{:^78}║\n║{:^78}║",
+            format!(
+                "You offered {} {} for {}.",
+                to,
+                describe_resources(give),
+                describe_resources(want)
+            ),
+            format!("{} will answer at the start of their next turn.", to),
+        );
+
+        self.offers.push(TradeOffer {
+            from: self.nick.clone(),
+            to,
+            give,
+            want,
+        });
+
+        Ok(notification)
     }
 
-    /// Return formatted part of the table for player status
-    /// which contains all user's units that have been sent on the battlefield
+    /// Perform action -> sell a resource on the market for the other one
     ///
     /// Params
     /// ---
-    /// - fields: vector of fields containing only user's troops
+    /// - economy: market shared by all the players
+    /// - resource: type of the sold resource
+    /// - quantity: how much of it to sell
     ///
     /// Returns
-    /// ---
-    /// - formatted portion of user status' table
-    fn occupied_fields(&self, fields: Vec<GameField>) -> String {
-        // the header for the part of the table
-        let header_string = format!("│ {:<29}│{:^47}│\n", "FIELDS OCCUPIED:", "");
+    /// - Ok(String) if the resource was sold
+    /// - Err(String) if the player has no market, not enough of the resource
+    ///   or the market would pay nothing for it
+    fn exchange_resources(
+        &mut self,
+        economy: &mut Economy,
+        resource: ResourceType,
+        quantity: Quantity,
+    ) -> Result<String, String> {
+        if quantity <= 0 {
+            return Err(format!("║{:^78}║", "Please sell at least one unit."));
+        }
 
-        // format all fields containing user's troops
+        let Some(fee) = self.market_fee() else {
+            return Err(format!(
+                "║{:^78}║",
+                "You need a MARKET to exchange resources."
+            ));
+        };
+
+        if economy.quote(resource, quantity, fee) <= 0 {
+            return Err(format!(
+                "║{:^78}║",
+                format!(
+                    "The market would pay nothing for {} {}.",
+                    quantity, resource
+                ),
+            ));
+        }
+
+        let (sold, bought) = match resource {
+            ResourceType::Wood => (&mut self.wood, &mut self.gold),
+            ResourceType::Gold => (&mut self.gold, &mut self.wood),
+        };
+        sold.subtract(quantity)?;
+        let payment = economy.exchange(resource, quantity, fee);
+        bought.quantity += payment;
+
+        Ok(format!(
+            "║{:^78}║\n║{:^78}║",
+            format!(
+                "You sold {} {} for {} {} on the market.",
+                quantity, resource, payment, bought.resource_type
+            ),
+            format!(
+                "Current warehouse supplies are: {}, {}.",
+                self.wood, self.gold
+            )
+        ))
+    }
+
+    /// Return the fee the player pays on the market, their best market makes it lower
+    ///
+    /// Returns
+    /// ---
+    /// - Some(fee): percents of every exchange the market keeps
+    /// - None: if the player has no market
+    pub fn market_fee(&self) -> Option<Quantity> {
+        let level = self
+            .buildings
+            .iter()
+            .filter(|building| building.building_type == BuildingType::Market)
+            .map(|market| market.level)
+            .max()?;
+
+        Some(rules().market_fee / level.max(1))
+    }
+
+    /// Accept a trade offer made to the player, paying for it
+    ///
+    /// Params
+    /// ---
+    /// - offer: offer made to the player
+    ///
+    /// Returns
+    /// - Ok(String) if the trade was made
+    /// - Err(String) if the player cannot pay the wanted resources
+    pub fn accept_offer(&mut self, offer: &TradeOffer) -> Result<String, String> {
+        self.pay_for_item(offer.want, 1)?;
+        self.receive_resources(offer.give);
+
+        Ok(format!(
+            "║{:^78}║\n║{:^78}║",
+            format!(
+                "You traded {} with {} for {}.",
+                describe_resources(offer.want),
+                offer.from,
+                describe_resources(offer.give)
+            ),
+            format!(
+                "Current warehouse supplies are: {}, {}.",
+                self.wood, self.gold
+            )
+        ))
+    }
+
+    /// Return the trade offers the player made, waiting for an answer
+    pub fn offers(&self) -> &[TradeOffer] {
+        &self.offers
+    }
+
+    /// Take back the offer made to another player, the resources stay put aside
+    ///
+    /// Params
+    /// ---
+    /// - to: nick of the player the offer was made to
+    ///
+    /// Returns
+    /// ---
+    /// - Some(offer): if the player made an offer to the other player
+    /// - None: otherwise
+    pub fn withdraw_offer(&mut self, to: &str) -> Option<TradeOffer> {
+        let position = self.offers.iter().position(|offer| offer.to == to)?;
+        Some(self.offers.remove(position))
+    }
+
+    /// Put an offer which was not answered back among the player's offers
+    ///
+    /// Params
+    /// ---
+    /// - offer: offer the player made
+    pub fn return_offer(&mut self, offer: TradeOffer) {
+        self.offers.push(offer);
+    }
+
+    /// Add resources to the warehouse, f.e. from a trade
+    ///
+    /// Params
+    /// ---
+    /// - (wood, gold): resources to add
+    pub fn receive_resources(&mut self, (wood, gold): ResourceValue) {
+        self.wood.quantity += wood.max(0);
+        self.gold.quantity += gold.max(0);
+    }
+
+    /// Add units without training them, f.e. veterans carried over from a previous skirmish
+    ///
+    /// Params
+    /// ---
+    /// - unit_type: type of the units
+    /// - quantity: number of units to add
+    pub fn receive_units(&mut self, unit_type: UnitType, quantity: Quantity) {
+        let quantity = quantity.max(0);
+
+        match unit_type {
+            UnitType::Archer => self.archers.train(quantity),
+            UnitType::Warrior => self.warriors.train(quantity),
+            UnitType::Cavalry => self.cavalry.train(quantity),
+        }
+    }
+
+    /// Perform action -> reveal a field in the fog of war
+    ///
+    /// Params
+    /// ---
+    /// - game_field: field to scout
+    ///
+    /// Returns
+    /// - Ok(String) if the field was scouted
+    /// - Err(String) if the field does not exist
+    fn scout_field(&mut self, game_field: Option<&mut GameField>) -> Result<String, String> {
+        let game_field = match game_field {
+            Some(game_field) => game_field,
+            None => {
+                return Err(format!(
+                    "║{:^78}║\n",
+                    "Sorry. Specified game field does not exist!",
+                ))
+            }
+        };
+
+        let (x, y) = (game_field.x, game_field.y);
+        self.scouted.retain(|field| (field.x, field.y) != (x, y));
+        self.scouted.push(ScoutedField {
+            x,
+            y,
+            turns_left: limits::SCOUTING_TURNS,
+        });
+
+        Ok(format!(
+            "║{:^78}║\n║{:^78}║",
+            format!("Your scouts have revealed field {}.", game_field.label()),
+            "It stays visible until the end of your next turn.",
+        ))
+    }
+
+    /// Let the scouted fields fade back into the fog of war
+    ///
+    /// Used at the start of player's turn.
+    pub fn age_scouting(&mut self) {
+        for field in self.scouted.iter_mut() {
+            field.turns_left -= 1;
+        }
+        self.scouted.retain(|field| field.turns_left > 0);
+    }
+
+    /// Check whether the player can see the units on a field in the fog of war
+    ///
+    /// Params
+    /// ---
+    /// - field: field to check
+    ///
+    /// Returns
+    /// ---
+    /// - true: if the player has units on the field or has scouted it
+    /// - false: otherwise
+    pub fn sees(&self, field: &GameField) -> bool {
+        field.occupants().contains(&self.nick)
+            || self
+                .scouted
+                .iter()
+                .any(|scouted| (scouted.x, scouted.y) == (field.x, field.y))
+    }
+
+    /// Remember the current state of all the fields the player can see
+    ///
+    /// Params
+    /// ---
+    /// - game_plan: the real game plan
+    /// - round: which round it is
+    pub fn remember_visible(&mut self, game_plan: &GamePlan, round: usize) {
+        let visible: Vec<&GameField> = game_plan
+            .fields
+            .iter()
+            .filter(|field| self.sees(field))
+            .collect();
+
+        for field in visible {
+            let intel = FieldIntel {
+                round,
+                field: field.clone(),
+            };
+
+            match self
+                .intel
+                .iter_mut()
+                .find(|known| known.field.coordinates() == field.coordinates())
+            {
+                Some(known) => *known = intel,
+                None => self.intel.push(intel),
+            }
+        }
+    }
+
+    /// Return the last known state of a field
+    ///
+    /// Params
+    /// ---
+    /// - x: x coordinate of the field
+    /// - y: y coordinate of the field
+    ///
+    /// Returns
+    /// ---
+    /// - Some(intel): if the player has seen the field before
+    /// - None: otherwise
+    pub fn intel(&self, x: usize, y: usize) -> Option<&FieldIntel> {
+        self.intel
+            .iter()
+            .find(|known| known.field.coordinates() == (x, y))
+    }
+
+    /// Describe a field for the battlefield map as the player sees it in the fog of war
+    ///
+    /// Params
+    /// ---
+    /// - field: field of the real game plan
+    ///
+    /// Returns
+    /// ---
+    /// - String: the current leader of a visible field, the last known leader marked
+    ///   with '~' for a field seen before, 'FOG' otherwise
+    pub fn map_owner(&self, field: &GameField) -> String {
+        let (x, y) = field.coordinates();
+
+        match (self.sees(field), self.intel(x, y)) {
+            (true, _) => field.map_owner(),
+            (false, Some(known)) => format!("~{}", known.field.map_owner()),
+            (false, None) => String::from("FOG"),
+        }
+    }
+
+    /// Return the battlefield as the player sees it in the fog of war
+    ///
+    /// Params
+    /// ---
+    /// - game_plan: the real game plan
+    ///
+    /// Returns
+    /// ---
+    /// - copy of the game plan without opponents' units and items on the fields the player cannot see
+    pub fn visible_plan(&self, game_plan: &GamePlan) -> GamePlan {
+        let mut plan = game_plan.clone();
+
+        for field in plan.fields.iter_mut() {
+            if !self.sees(field) {
+                field
+                    .units_occupying
+                    .retain(|unit_in_field| unit_in_field.owner == self.nick);
+                field.boosts.retain(|boost| boost.owner == self.nick);
+            }
+        }
+
+        plan
+    }
+
+    /// Perform action -> change stance of player's units on a field
+    ///
+    /// Params
+    /// ---
+    /// - game_field: field where the units are
+    /// - stance: new stance of the units
+    ///
+    /// Returns
+    /// - Ok(String) if the stance was changed
+    /// - Err(String) if the field does not exist or player has no units
+    ///   in a different stance there
+    fn change_stance(
+        &mut self,
+        game_field: Option<&mut GameField>,
+        stance: Stance,
+    ) -> Result<String, String> {
+        // cannot access the game field
+        let game_field = match game_field {
+            Some(game_field) => game_field,
+            None => {
+                return Err(format!(
+                    "║{:^78}║\n",
+                    "Sorry. Specified game field does not exist!",
+                ))
+            }
+        };
+
+        // there have to be some units to change the stance of
+        if game_field
+            .players_units(self.nick.clone())
+            .units_occupying
+            .is_empty()
+        {
+            return Err(format!(
+                "║{:^78}║",
+                format!("You have no units on field {}.", game_field.label()),
+            ));
+        }
+
+        match game_field.set_stance(&self.nick, stance) {
+            0 => Err(format!(
+                "║{:^78}║",
+                format!(
+                    "Your units on field {} are already in stance {}.",
+                    game_field.label(),
+                    stance
+                ),
+            )),
+            _ => Ok(format!(
+                "║{:^78}║",
+                format!(
+                    "Your units on field {} took stance {}!",
+                    game_field.label(),
+                    stance
+                ),
+            )),
+        }
+    }
+
+    /// Harvest crops from the surroundings of player's kingdom
+    ///
+    /// Returns
+    /// - `Ok(String)` that the harvest was successful
+    /// - Err(String) will never happen, the function is just compliant to the return type of other actions
+    fn harvest(&mut self) -> Result<String, String> {
+        // get the amount of gained crops
+        let (wood, gold) = income(IncomeSource::Harvest);
+
+        // add resources
+        // this also will not fail, as we never get to add 0 resources to anything
+        self.wood.add(wood)?;
+        self.gold.add(gold)?;
+
+        // return the formatted output
+        Ok(format!(
+            "║{:^78}║\n║{:^78}║\n║{:^78}║",
+            "Harvest was a success!",
+            format!("Gained {} wood and {} gold!", wood, gold,),
+            format!(
+                "Current warehouse supplies are: {}, {}.",
+                self.wood, self.gold
+            )
+        ))
+    }
+
+    /// Pass the turn, gathering a few resources instead of acting
+    ///
+    /// Returns
+    /// - `Ok(String)` with the gathered resources
+    /// - Err(String) if the resources could not be added
+    fn pass(&mut self) -> Result<String, String> {
+        let (wood, gold) = income(IncomeSource::Pass);
+
+        // a zero gain can be configured, adding nothing is not an error here
+        if wood > 0 {
+            self.wood.add(wood)?;
+        }
+        if gold > 0 {
+            self.gold.add(gold)?;
+        }
+
+        Ok(format!(
+            "║{:^78}║\n║{:^78}║\n║{:^78}║",
+            "You let the turn pass.",
+            format!("Your peasants gathered {} wood and {} gold.", wood, gold),
+            format!(
+                "Current warehouse supplies are: {}, {}.",
+                self.wood, self.gold
+            )
+        ))
+    }
+
+    /// Get number of buildings of desired type
+    ///
+    /// Params
+    /// ---
+    /// - building_type: type of a desired building
+    ///
+    /// Returns
+    /// ---
+    /// - number of buildings of said type
+    pub fn number_of_buildings(&self, building_type: BuildingType) -> Quantity {
+        self.buildings
+            .iter()
+            .filter(|building| building.building_type == building_type)
+            .map(|_| 1)
+            .sum()
+    }
+
+    /// Describe the levels of player's buildings of desired type
+    ///
+    /// Params
+    /// ---
+    /// - building_type: type of a desired building
+    ///
+    /// Returns
+    /// ---
+    /// - String: f.e. "2 x LEVEL 1, 1 x LEVEL 3", empty if there are no such buildings
+    fn building_levels(&self, building_type: BuildingType) -> String {
+        let levels: Vec<String> = (1..=limits::MAX_BUILDING_LEVEL)
+            .map(|level| {
+                let count = self
+                    .buildings
+                    .iter()
+                    .filter(|building| {
+                        building.building_type == building_type && building.level == level
+                    })
+                    .count();
+                (level, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .map(|(level, count)| format!("{} x LEVEL {}", count, level))
+            .collect();
+
+        levels.join(", ")
+    }
+
+    /// Upgrade the building of desired type with the lowest level
+    ///
+    /// Params
+    /// ---
+    /// - building_type: type of the building to upgrade
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) if the building was upgraded
+    /// - Err(String) if there is no building to upgrade or the player cannot afford it
+    fn upgrade_building(&mut self, building_type: BuildingType) -> Result<String, String> {
+        let index = self
+            .buildings
+            .iter()
+            .enumerate()
+            .filter(|(_, building)| building.building_type == building_type)
+            .min_by_key(|(_, building)| building.level)
+            .map(|(index, _)| index);
+
+        let building = match index {
+            Some(index) => self.buildings[index],
+            None => {
+                return Err(format!(
+                    "║{:^78}║",
+                    format!("You have no {} to upgrade, build one first.", building_type),
+                ))
+            }
+        };
+
+        if building.level >= limits::MAX_BUILDING_LEVEL {
+            return Err(format!(
+                "║{:^78}║",
+                format!(
+                    "All your {}S already have the highest level {}.",
+                    building_type,
+                    limits::MAX_BUILDING_LEVEL
+                ),
+            ));
+        }
+
+        // the upgrade costs more with every level of the building
+        self.pay_for_item(building, 1)?;
+
+        let upgraded = Building {
+            level: building.level + 1,
+            ..building
+        };
+        if let Some(index) = index {
+            self.buildings[index] = upgraded;
+        }
+
+        Ok(format!(
+            "║{:^78}║\n║{:^78}║",
+            format!(
+                "A {} was upgraded to LEVEL {}!",
+                building_type, upgraded.level
+            ),
+            format!("It now has a capacity of {}.", upgraded.capacity()),
+        ))
+    }
+
+    /// Return the cost of the next upgrade of a building type
+    ///
+    /// Params
+    /// ---
+    /// - building_type: type of the building to upgrade
+    ///
+    /// Returns
+    /// ---
+    /// - Some((wood, gold)): cost of upgrading the lowest-level building
+    /// - None: if there is no building of the type below the highest level
+    pub fn upgrade_cost(&self, building_type: BuildingType) -> Option<ResourceValue> {
+        self.buildings
+            .iter()
+            .filter(|building| building.building_type == building_type)
+            .filter(|building| building.level < limits::MAX_BUILDING_LEVEL)
+            .min_by_key(|building| building.level)
+            .map(|building| building.value())
+    }
+
+    /// Get current fighters capacity
+    ///
+    /// Returns
+    /// ---
+    /// - current capacity to train fighters
+    pub fn current_fighters_capacity(&self) -> Quantity {
+        self.fighters_capacity()
+            - self.archers.quantity
+            - self.warriors.quantity
+            - self.cavalry.quantity
+            - self.peasants
+    }
+
+    /// Return maximal capacity of warriors that can be stored in player's territory
+    ///
+    /// Returns
+    /// ---
+    /// - maximal fighters capacity
+    fn fighters_capacity(&self) -> Quantity {
+        self.buildings
+            .iter()
+            .filter(|building| building.building_type == BuildingType::Base)
+            .map(|base| base.capacity())
+            .sum()
+    }
+
+    /// Check if player has fighters available
+    ///
+    /// Returns
+    /// ---
+    /// - true: if there are some units available to send
+    /// - false: otherwise
+    pub fn has_fighters_available(&self) -> bool {
+        self.archers.quantity + self.warriors.quantity + self.cavalry.quantity > 0
+    }
+
+    /// Check fighters total capacity in bases
+    ///
+    /// Params
+    /// ---
+    /// - new_quantity: how many more fighters does user want to train
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(()) on correct capacity
+    /// - Err(GameError) containing the requested and remaining capacity
+    fn check_fighters_capacity(&mut self, new_quantity: Quantity) -> Result<(), GameError> {
+        // capacity exceeded
+        if self.current_fighters_capacity() < new_quantity {
+            return Err(GameError::CapacityExceeded {
+                requested: new_quantity,
+                remaining: self.current_fighters_capacity(),
+                total: self.fighters_capacity(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Trains units if there is enough capacity and enough resources available for training
+    /// Only one type per round is permitted
+    ///
+    /// Params
+    /// ---
+    /// - unit_type: type of the trained unit
+    /// - quantity: how many units are to be trained
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) after successfully training the units
+    /// - Err(String) containing error message
+    fn train_units(&mut self, unit_type: UnitType, quantity: Quantity) -> Result<String, String> {
+        // compute whether we are within capacity
+        self.check_fighters_capacity(quantity)?;
+
+        // try to pay for an item
+        self.pay_for_item(unit_type, quantity)?;
+
+        // train desired unit type
+        match unit_type {
+            UnitType::Archer => self.archers.train(quantity),
+            UnitType::Warrior => self.warriors.train(quantity),
+            UnitType::Cavalry => self.cavalry.train(quantity),
+        }
+
+        // language differences for plurals
+        let quantity_string = plural_word(quantity, "unit", "units");
+        let plural = plural_suffix(quantity, "S");
+
+        // training was successful
+        Ok(format!(
+            "║{:^78}║",
+            format!(
+                "Training of {} {} of {}{} was successful",
+                quantity, quantity_string, unit_type, plural
+            ),
+        ))
+    }
+
+    /// Train all the units of an army template at once
+    ///
+    /// The capacity and the cost of the whole army are checked together,
+    /// so either the whole army is trained or nothing changes.
+    ///
+    /// Params
+    /// ---
+    /// - template: army to be trained
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) after successfully training the army
+    /// - Err(String) containing error message
+    fn train_army(&mut self, template: ArmyTemplate) -> Result<String, String> {
+        self.check_fighters_capacity(template.quantity())?;
+        let bundle: Vec<(&dyn HasValue, Quantity)> = template
+            .units
+            .iter()
+            .map(|(unit_type, quantity)| (unit_type as &dyn HasValue, *quantity))
+            .collect();
+        self.pay_for_bundle(&bundle)?;
+
+        for (unit_type, quantity) in &template.units {
+            match unit_type {
+                UnitType::Archer => self.archers.train(*quantity),
+                UnitType::Warrior => self.warriors.train(*quantity),
+                UnitType::Cavalry => self.cavalry.train(*quantity),
+            }
+        }
+
+        Ok(format!(
+            "║{:^78}║\n║{:^78}║",
+            format!("Army '{}' was successfully trained:", template.name),
+            template.to_string(),
+        ))
+    }
+
+    /// Return the army templates the player has saved
+    pub fn templates(&self) -> &[ArmyTemplate] {
+        &self.templates
+    }
+
+    /// Find a saved army template by its name
+    ///
+    /// Params
+    /// ---
+    /// - name: name of the template
+    pub fn template(&self, name: &str) -> Option<&ArmyTemplate> {
+        self.templates.iter().find(|template| template.name == name)
+    }
+
+    /// Save an army template, replacing a template of the same name
+    ///
+    /// Params
+    /// ---
+    /// - template: army template to save
+    pub fn save_template(&mut self, template: ArmyTemplate) {
+        match self
+            .templates
+            .iter_mut()
+            .find(|saved| saved.name == template.name)
+        {
+            Some(saved) => *saved = template,
+            None => self.templates.push(template),
+        }
+    }
+
+    /// Remove a saved army template
+    ///
+    /// Params
+    /// ---
+    /// - name: name of the template
+    ///
+    /// Returns
+    /// ---
+    /// - true: if the template was removed
+    /// - false: if there was no such template
+    pub fn remove_template(&mut self, name: &str) -> bool {
+        let count = self.templates.len();
+        self.templates.retain(|template| template.name != name);
+        self.templates.len() != count
+    }
+
+    /// Upgrade peasants into units of desired type for a reduced cost
+    ///
+    /// Params
+    /// ---
+    /// - unit_type: type of the recruited unit
+    /// - quantity: how many peasants are to be upgraded
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) after successfully upgrading the peasants
+    /// - Err(String) containing error message
+    fn recruit_peasants(
+        &mut self,
+        unit_type: UnitType,
+        quantity: Quantity,
+    ) -> Result<String, String> {
+        // there have to be enough peasants
+        if self.peasants < quantity {
+            return Err(format!(
+                "║{:^78}║\n║{:^78}║",
+                format!("Cannot upgrade {} peasants into {}S.", quantity, unit_type),
+                format!("Not enough peasants available ({}).", self.peasants),
+            ));
+        }
+
+        // try to pay for the upgrade
+        self.pay_for_item(PeasantUpgrade(unit_type), quantity)?;
+
+        // peasants already live in the bases, so the capacity does not change
+        self.peasants -= quantity;
+        match unit_type {
+            UnitType::Archer => self.archers.train(quantity),
+            UnitType::Warrior => self.warriors.train(quantity),
+            UnitType::Cavalry => self.cavalry.train(quantity),
+        }
+
+        let plural = plural_suffix(quantity, "S");
+
+        Ok(format!(
+            "║{:^78}║",
+            format!(
+                "{} PEASANT{} became {}{}!",
+                quantity, plural, unit_type, plural
+            ),
+        ))
+    }
+
+    /// Add free peasants produced by player's bases, up to the free capacity
+    ///
+    /// Used at the start of player's turn.
+    ///
+    /// Returns
+    /// ---
+    /// - Some(String) describing the growth of population
+    /// - None: if no peasants were added
+    pub fn grow_population(&mut self) -> Option<String> {
+        let growth = (self.number_of_buildings(BuildingType::Base) * rules().peasants_per_base)
+            .min(self.current_fighters_capacity());
+
+        if growth <= 0 {
+            return None;
+        }
+
+        self.peasants += growth;
+
+        let plural = plural_suffix(growth, "S");
+        Some(format!(
+            "║{:^78}║\n║{:^78}║",
+            format!("{} new PEASANT{} settled in your bases.", growth, plural),
+            format!(
+                "You have {} peasants ready to be upgraded into units.",
+                self.peasants
+            ),
+        ))
+    }
+
+    /// Collect the crops of player's farms
+    ///
+    /// Used at the start of player's turn.
+    ///
+    /// Returns
+    /// ---
+    /// - Some(String) describing the collected resources
+    /// - None: if the player has no farms
+    pub fn collect_farm_income(&mut self) -> Option<String> {
+        let levels = self.farm_levels();
+
+        if levels <= 0 {
+            return None;
+        }
+
+        let (wood, gold) = income(IncomeSource::Farm);
+        let (wood, gold) = (wood * levels, gold * levels);
+        self.wood.quantity += wood;
+        self.gold.quantity += gold;
+
+        let farms = self.number_of_buildings(BuildingType::Farm);
+        Some(format!(
+            "║{:^78}║",
+            format!(
+                "Your {} {} yielded {} wood and {} gold.",
+                farms,
+                plural_word(farms, "farm", "farms"),
+                wood,
+                gold
+            ),
+        ))
+    }
+
+    /// Pay the gold upkeep of the player's army, units which cannot be paid desert
+    ///
+    /// Used at the start of player's turn, after the crops of the farms are collected.
+    /// Units on the battlefield are paid too. When the gold does not suffice, all of it is paid
+    /// and every unit type loses the unpaid share of its units, those at home leave first.
+    ///
+    /// Params
+    /// ---
+    /// - game_plan: battlefield with the player's deployed units
+    ///
+    /// Returns
+    /// ---
+    /// - Some(String) describing the paid upkeep and the deserters
+    /// - None: if the player has no army to pay
+    pub fn pay_upkeep(&mut self, game_plan: &mut GamePlan) -> Option<String> {
+        let army: Vec<(UnitType, Quantity)> = UnitType::all()
+            .into_iter()
+            .map(|unit_type| (unit_type, self.units_owned(game_plan, unit_type)))
+            .filter(|(unit_type, quantity)| *quantity > 0 && unit_type.upkeep() > 0)
+            .collect();
+
+        // started batches are paid in full
+        let batched_upkeep: Quantity = army
+            .iter()
+            .map(|(unit_type, quantity)| quantity * unit_type.upkeep())
+            .sum();
+        let upkeep = (batched_upkeep + limits::UPKEEP_BATCH - 1) / limits::UPKEEP_BATCH;
+
+        if upkeep <= 0 {
+            return None;
+        }
+
+        let paid = upkeep.min(self.gold.quantity);
+        self.gold.quantity -= paid;
+
+        if paid == upkeep {
+            return Some(format!(
+                "║{:^78}║",
+                format!("Your army was paid {} gold of upkeep.", upkeep),
+            ));
+        }
+
+        // every unit type loses the unpaid share of its units
+        let unpaid = upkeep - paid;
+        let deserters: Vec<String> = army
+            .iter()
+            .map(|(unit_type, quantity)| {
+                let deserted = (quantity * unpaid + upkeep - 1) / upkeep;
+                self.desert(game_plan, *unit_type, deserted);
+                format!("{} {}{}", deserted, unit_type, plural_suffix(deserted, "S"))
+            })
+            .collect();
+
+        Some(format!(
+            "║{:^78}║\n║{:^78}║",
+            format!(
+                "Your treasury could only pay {} of {} gold of upkeep.",
+                paid, upkeep
+            ),
+            format!("Unpaid, {} deserted your army!", deserters.join(", ")),
+        ))
+    }
+
+    /// Remove deserting units, first from the kingdom, then from the battlefield
+    ///
+    /// Params
+    /// ---
+    /// - game_plan: battlefield with the player's deployed units
+    /// - unit_type: type of the deserting units
+    /// - quantity: how many units desert, at most `units_owned` of the type
+    fn desert(&mut self, game_plan: &mut GamePlan, unit_type: UnitType, quantity: Quantity) {
+        let unit = match unit_type {
+            UnitType::Archer => &mut self.archers,
+            UnitType::Warrior => &mut self.warriors,
+            UnitType::Cavalry => &mut self.cavalry,
+        };
+        let from_home = quantity.min(unit.quantity);
+        unit.send_occupy(from_home);
+
+        let mut remaining = quantity - from_home;
+        for field in game_plan.fields.iter_mut() {
+            if remaining <= 0 {
+                break;
+            }
+
+            let from_field = remaining.min(field.units_of(&self.nick, unit_type));
+            field.withdraw_units(&self.nick, unit_type, from_field);
+            remaining -= from_field;
+        }
+    }
+
+    /// Return how many peasants the player has
+    pub fn peasants(&self) -> Quantity {
+        self.peasants
+    }
+
+    /// Compute how many items of given type the player can afford
+    ///
+    /// Params
+    /// ---
+    /// - item: type of the item
+    pub fn buy_max_items(&self, item: ItemType) -> Quantity {
+        let (item_wood, item_gold) = item.value();
+
+        // only costs which are not free limit the purchase
+        [
+            (self.wood.quantity, item_wood),
+            (self.gold.quantity, item_gold),
+        ]
+        .iter()
+        .filter(|(_, cost)| *cost > 0)
+        .map(|(available, cost)| available / cost)
+        .min()
+        .unwrap_or(0)
+    }
+
+    /// Check whether the player has ever sent units to the battlefield
+    pub fn went_to_war(&self) -> bool {
+        self.went_to_war
+    }
+
+    /// Return how many units of given type the player has available
+    pub fn units(&self, unit_type: UnitType) -> Quantity {
+        match unit_type {
+            UnitType::Archer => self.archers.quantity,
+            UnitType::Warrior => self.warriors.quantity,
+            UnitType::Cavalry => self.cavalry.quantity,
+        }
+    }
+
+    /// Return the levels of all the player's farms added up, every level yields the farm income
+    fn farm_levels(&self) -> Quantity {
+        self.buildings
+            .iter()
+            .filter(|building| building.building_type == BuildingType::Farm)
+            .map(|farm| farm.level)
+            .sum()
+    }
+
+    /// Count the units of a type the player owns, in the kingdom and on the battlefield
+    ///
+    /// Params
+    /// ---
+    /// - game_plan: battlefield of the game
+    /// - unit_type: which units to count
+    pub fn units_owned(&self, game_plan: &GamePlan, unit_type: UnitType) -> Quantity {
+        let deployed: Quantity = (1..=game_plan.number_of_fields())
+            .filter_map(|number| game_plan.field_coordinates(number))
+            .filter_map(|(x, y)| game_plan.field(x, y))
+            .map(|field| field.units_of(&self.nick, unit_type))
+            .sum();
+
+        self.units(unit_type) + deployed
+    }
+
+    /// Return how much wood and gold the player has, in this order
+    pub fn supplies(&self) -> (Quantity, Quantity) {
+        (self.wood.quantity, self.gold.quantity)
+    }
+
+    /// Compute how many peasants can be upgraded into units of given type at most
+    ///
+    /// Params
+    /// ---
+    /// - unit_type: type of the unit
+    ///
+    /// Returns
+    /// ---
+    /// - maximal number of peasants the user can upgrade into given type
+    pub fn recruit_max_units(&self, unit_type: UnitType) -> Quantity {
+        let (unit_wood, unit_gold) = PeasantUpgrade(unit_type).value();
+
+        // only costs which are not free limit the upgrade
+        [
+            (self.wood.quantity, unit_wood),
+            (self.gold.quantity, unit_gold),
+        ]
+        .iter()
+        .filter(|(_, cost)| *cost > 0)
+        .map(|(available, cost)| available / cost)
+        .fold(self.peasants, Quantity::min)
+    }
+
+    /// Performs a specified game action
+    ///
+    /// Params
+    /// ---
+    /// - action: which action to perform
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(String) to print when everything went well,
+    /// - Err(String) when an error occurred
+    pub fn perform_action(
+        &mut self,
+        action: Actions,
+        game_plan: &mut GamePlan,
+    ) -> Result<String, String> {
+        let supplies = self.supplies();
+        let performed = action.clone();
+
+        let result = match action {
+            Actions::Build(building, quantity) => self.build_a_building(building, quantity),
+            Actions::Upgrade(building) => self.upgrade_building(building),
+            Actions::Conquer(x, y, unit_type, quantity) => {
+                self.occupy_fields(game_plan.get_game_field(x, y), unit_type, quantity)
+            }
+            Actions::Harvest => self.harvest(),
+            Actions::Pass => self.pass(),
+            Actions::Stance(x, y, stance) => {
+                self.change_stance(game_plan.get_game_field(x, y), stance)
+            }
+            Actions::Train(unit_type, quantity) => self.train_units(unit_type, quantity),
+            Actions::Recruit(unit_type, quantity) => self.recruit_peasants(unit_type, quantity),
+            Actions::TrainArmy(template) => self.train_army(template),
+            Actions::Scout(x, y) => self.scout_field(game_plan.get_game_field(x, y)),
+            Actions::Recall(x, y, unit_type, quantity) => {
+                self.recall_units(game_plan.get_game_field(x, y), unit_type, quantity)
+            }
+            Actions::BuyItem(item, quantity) => self.buy_items(item, quantity),
+            Actions::UseItem(x, y, item) => self.use_item(game_plan.get_game_field(x, y), item),
+            Actions::Fortify(x, y) => self.fortify(game_plan.get_game_field(x, y)),
+            Actions::Trade { to, give, want } => self.offer_trade(to, give, want),
+            Actions::Exchange(resource, quantity) => {
+                self.exchange_resources(game_plan.economy_mut(), resource, quantity)
+            }
+            _ => Ok("Unreachable statement".into()),
+        };
+
+        if result.is_ok() {
+            self.stats
+                .record_action(&performed, supplies, self.supplies());
+        }
+
+        result
+    }
+
+    /// Return the tally of the actions the player has performed
+    pub fn stats(&self) -> &PlayerStats {
+        &self.stats
+    }
+
+    /// Return formatted part of the table for player status
+    /// which contains all user's units that have been sent on the battlefield
+    ///
+    /// Params
+    /// ---
+    /// - fields: vector of fields containing only user's troops
+    ///
+    /// Returns
+    /// ---
+    /// - formatted portion of user status' table
+    fn occupied_fields(&self, fields: Vec<GameField>) -> String {
+        // the header for the part of the table
+        let header_string = format!("│ {:<29}│{:^47}│\n", "FIELDS OCCUPIED:", "");
+
+        // format all fields containing user's troops
         let fields_string: Vec<String> = fields
             .iter()
             .map(|field| {
@@ -411,27 +1792,40 @@ impl Player {
                     .units_occupying
                     .iter()
                     .map(|unit_in_field| {
-                        let plural = if unit_in_field.unit.quantity == 1 {
-                            ""
-                        } else {
-                            "S"
+                        let plural = plural_suffix(unit_in_field.unit.quantity, "S");
+
+                        // only the non-default stance is displayed
+                        let stance = match unit_in_field.stance {
+                            Stance::Raiding => String::new(),
+                            stance => format!(" ({})", stance),
                         };
 
                         format!(
                             "│{:^30}│{:>46} │",
                             "",
                             format!(
-                                "{} {}{}",
-                                unit_in_field.unit.quantity, unit_in_field.unit, plural
+                                "{} {}{}{}",
+                                format_number(unit_in_field.unit.quantity),
+                                unit_in_field.unit,
+                                plural,
+                                stance
                             )
                         )
                     })
+                    .chain(field.boosts().iter().map(|boost| {
+                        format!("│{:^30}│{:>46} │", "", format!("{} in use", boost.item))
+                    }))
+                    .chain(
+                        field
+                            .tower()
+                            .map(|_| format!("│{:^30}│{:>46} │", "", "TOWER")),
+                    )
                     .collect();
 
                 format!(
                     "│{:^30}│ {:<46}│\n{}\n",
                     "",
-                    format!("FIELD ({},{}):", field.x, field.y),
+                    format!("FIELD {}:", field.label()),
                     units_in_field.join("\n"),
                 )
             })
@@ -445,6 +1839,133 @@ impl Player {
         )
     }
 
+    /// Return player's status in a plain 'label: value' format,
+    /// used on terminals too narrow for the status table
+    ///
+    /// Params
+    /// ---
+    /// - round: which round it currently is
+    /// - fields: fields containing only user's troops
+    /// - time_period: when is the status relevant (f.e. at the end of the round)
+    ///
+    /// Returns
+    /// ---
+    /// - String containing the status, one value per line
+    fn status_compact(&self, round: usize, fields: &[GameField], time_period: &str) -> String {
+        let base_levels = match self.building_levels(BuildingType::Base) {
+            levels if levels.is_empty() => String::from("-"),
+            levels => levels,
+        };
+        let farm_levels = match self.building_levels(BuildingType::Farm) {
+            levels if levels.is_empty() => String::from("-"),
+            levels => levels,
+        };
+        let market_levels = match self.building_levels(BuildingType::Market) {
+            levels if levels.is_empty() => String::from("-"),
+            levels => levels,
+        };
+
+        let mut lines = vec![
+            paint_player(
+                &format!("{}'s statistics {} round {}", self.nick, time_period, round),
+                &self.nick,
+            ),
+            format!(
+                "Bases: {} (used {} / {})",
+                self.number_of_buildings(BuildingType::Base),
+                format_number(
+                    self.archers.quantity
+                        + self.warriors.quantity
+                        + self.cavalry.quantity
+                        + self.peasants
+                ),
+                format_number(self.fighters_capacity())
+            ),
+            format!("Base levels: {}", base_levels),
+            format!(
+                "Farms: {} (levels {})",
+                self.number_of_buildings(BuildingType::Farm),
+                farm_levels
+            ),
+            format!(
+                "Markets: {} (levels {})",
+                self.number_of_buildings(BuildingType::Market),
+                market_levels
+            ),
+            format!(
+                "{}S: {}",
+                self.archers,
+                format_number(self.archers.quantity)
+            ),
+            format!(
+                "{}S: {}",
+                self.warriors,
+                format_number(self.warriors.quantity)
+            ),
+            format!(
+                "{}S: {}",
+                self.cavalry,
+                format_number(self.cavalry.quantity)
+            ),
+            format!("PEASANTS: {}", format_number(self.peasants)),
+            format!("WOOD: {}", format_number(self.wood.quantity)),
+            format!("GOLD: {}", format_number(self.gold.quantity)),
+        ];
+        lines.extend(
+            ItemType::all()
+                .iter()
+                .map(|item| format!("{}S: {}", item, format_number(self.items(*item)))),
+        );
+
+        // one line per occupied field
+        lines.extend(fields.iter().map(|field| {
+            let units: Vec<String> = field
+                .units_occupying
+                .iter()
+                .map(|unit_in_field| {
+                    let plural = plural_suffix(unit_in_field.unit.quantity, "S");
+                    format!(
+                        "{} {}{}",
+                        format_number(unit_in_field.unit.quantity),
+                        unit_in_field.unit,
+                        plural
+                    )
+                })
+                .collect();
+
+            format!("Field {}: {}", field.label(), units.join(", "))
+        }));
+
+        format!("{}\n", lines.join("\n"))
+    }
+
+    /// Return formatted part of the table for player status
+    /// which contains the items in the player's inventory
+    fn inventory_rows(&self) -> String {
+        ItemType::all()
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let label = match index {
+                    0 => "ITEMS:",
+                    _ => "",
+                };
+                let quantity = self.items(*item);
+
+                format!(
+                    "│ {:<29}│{:^47}│\n",
+                    label,
+                    format!(
+                        "{} {}{}",
+                        format_number(quantity),
+                        item,
+                        plural_suffix(quantity, "S")
+                    ),
+                )
+            })
+            .collect()
+    }
+
     /// Print player's status
     /// Generates a nice table used at the end of player's turn / when player asks for it
     ///
@@ -453,7 +1974,7 @@ impl Player {
     /// - round: which round it currently is
     /// - game_plan: to gain data from the battlefield and print user's units
     /// - time_period: used in the header of the table to specify when
-    ///                is the table relevant (f.e. at the end of the round)
+    ///   is the table relevant (f.e. at the end of the round)
     ///
     /// Returns
     /// ---
@@ -467,10 +1988,24 @@ impl Player {
         let empty_left_cell = " ".repeat(30);
 
         // auxiliary variables
-        let plural_archers = if self.archers.quantity == 1 { "" } else { "S" };
-        let plural_warriors = if self.warriors.quantity == 1 { "" } else { "S" };
-        let plural_wood = if self.wood.quantity == 1 { "" } else { "S" };
-        let plural_gold = if self.gold.quantity == 1 { "" } else { "S" };
+        let plural_archers = plural_suffix(self.archers.quantity, "S");
+        let plural_warriors = plural_suffix(self.warriors.quantity, "S");
+        let plural_cavalry = plural_suffix(self.cavalry.quantity, "S");
+        let plural_peasants = plural_suffix(self.peasants, "S");
+        let plural_wood = plural_suffix(self.wood.quantity, "S");
+        let plural_gold = plural_suffix(self.gold.quantity, "S");
+        let base_levels = match self.building_levels(BuildingType::Base) {
+            levels if levels.is_empty() => String::from("No bases built yet"),
+            levels => format!("Levels: {}", levels),
+        };
+        let farm_levels = match self.building_levels(BuildingType::Farm) {
+            levels if levels.is_empty() => String::from("No farms built yet"),
+            levels => format!("Farm levels: {}", levels),
+        };
+        let market_levels = match self.building_levels(BuildingType::Market) {
+            levels if levels.is_empty() => String::from("No markets built yet"),
+            levels => format!("Market levels: {}", levels),
+        };
 
         // get player's fields
         let players_fields: Vec<GameField> = game_plan
@@ -481,36 +2016,70 @@ impl Player {
             .filter(|field| !field.units_occupying.is_empty())
             .collect();
 
+        // the table would break on narrow terminals
+        if is_narrow_terminal() {
+            return self.status_compact(round, &players_fields, time_period);
+        }
+
         // resulting string -> table of players current game status
-        format!(
-            "{}│{:^78}│\n{}{}{}{}{}{}{}{}{}{}{}{}",
+        [
             line_top,
+            // the title is colored by the player, so that their tables are told apart
             format!(
-                "{}'s current statistics {} round {}",
-                self.nick, time_period, round
+                "{}\n",
+                paint_player(
+                    &format!(
+                        "│{:^78}│",
+                        format!(
+                            "{}'s current statistics {} round {}",
+                            self.nick, time_period, round
+                        )
+                    ),
+                    &self.nick
+                )
             ),
             line_middle_top,
             format!(
                 "│ {:<29}│{:^47}│\n",
                 "BASE BUILDINGS:",
-                format!("{}", self.number_of_buildings(Building::Base),)
+                self.number_of_buildings(BuildingType::Base),
             ),
+            format!("│{}│{:^47}│\n", empty_left_cell, base_levels),
             format!(
                 "│{}│{:^47}│\n",
                 empty_left_cell,
                 format!(
                     "Currently used: {} / {} capacity",
-                    self.archers.quantity + self.warriors.quantity,
-                    self.fighters_capacity()
+                    format_number(
+                        self.archers.quantity
+                            + self.warriors.quantity
+                            + self.cavalry.quantity
+                            + self.peasants
+                    ),
+                    format_number(self.fighters_capacity())
                 ),
             ),
-            line_middle_center,
+            format!(
+                "│ {:<29}│{:^47}│\n",
+                "FARMS:",
+                self.number_of_buildings(BuildingType::Farm),
+            ),
+            format!("│{}│{:^47}│\n", empty_left_cell, farm_levels),
+            format!(
+                "│ {:<29}│{:^47}│\n",
+                "MARKETS:",
+                self.number_of_buildings(BuildingType::Market),
+            ),
+            format!("│{}│{:^47}│\n", empty_left_cell, market_levels),
+            line_middle_center.clone(),
             format!(
                 "│ {:<29}│{:^47}│\n",
                 "UNITS AVAILABLE:",
                 format!(
                     "{} {}{}",
-                    self.archers.quantity, self.archers, plural_archers,
+                    format_number(self.archers.quantity),
+                    self.archers,
+                    plural_archers,
                 ),
             ),
             format!(
@@ -518,24 +2087,106 @@ impl Player {
                 empty_left_cell,
                 format!(
                     "{} {}{}",
-                    self.warriors.quantity, self.warriors, plural_warriors,
+                    format_number(self.warriors.quantity),
+                    self.warriors,
+                    plural_warriors,
                 ),
             ),
-            line_middle_center,
+            format!(
+                "│{}│{:^47}│\n",
+                empty_left_cell,
+                format!(
+                    "{} {}{}",
+                    format_number(self.cavalry.quantity),
+                    self.cavalry,
+                    plural_cavalry,
+                ),
+            ),
+            format!(
+                "│{}│{:^47}│\n",
+                empty_left_cell,
+                format!(
+                    "{} PEASANT{}",
+                    format_number(self.peasants),
+                    plural_peasants
+                ),
+            ),
+            line_middle_center.clone(),
             format!(
                 "│ {:<29}│{:^47}│\n",
                 "RESOURCES:",
-                format!("{} WOODEN LOG{}", self.wood.quantity, plural_wood,),
+                format!(
+                    "{} WOODEN LOG{}",
+                    format_number(self.wood.quantity),
+                    plural_wood,
+                ),
             ),
             format!(
                 "│{}│{:^47}│\n",
                 empty_left_cell,
-                format!("{} GOLDEN NUGGET{}", self.gold.quantity, plural_gold),
+                format!(
+                    "{} GOLDEN NUGGET{}",
+                    format_number(self.gold.quantity),
+                    plural_gold
+                ),
             ),
+            line_middle_center.clone(),
+            self.inventory_rows(),
             line_middle_center,
             self.occupied_fields(players_fields),
-            line_bottom
-        )
+            line_bottom,
+        ]
+        .concat()
+    }
+
+    /// Find the prerequisites of a building the user does not fulfill yet
+    ///
+    /// Params
+    /// ---
+    /// - building_type: type of the building
+    ///
+    /// Returns
+    /// ---
+    /// - required buildings the user does not have enough of
+    pub fn missing_prerequisites(&self, building_type: BuildingType) -> Vec<Prerequisite> {
+        building_type
+            .prerequisites()
+            .into_iter()
+            .map(|(building, required)| Prerequisite {
+                building,
+                required,
+                built: self.number_of_buildings(building),
+            })
+            .filter(|prerequisite| prerequisite.built < prerequisite.required)
+            .collect()
+    }
+
+    /// Compute how many buildings of given type can user afford at most
+    ///
+    /// Params
+    /// ---
+    /// - building_type: type of the building
+    ///
+    /// Returns
+    /// ---
+    /// - maximal number of buildings the user can build of given type
+    pub fn build_max_buildings(&self, building_type: BuildingType) -> Quantity {
+        if !self.missing_prerequisites(building_type).is_empty() {
+            return 0;
+        }
+
+        let (building_wood, building_gold) = building_type.value();
+
+        // only the resources the building costs limit the quantity
+        [
+            (self.wood.quantity, building_wood),
+            (self.gold.quantity, building_gold),
+        ]
+        .into_iter()
+        .filter(|(_, price)| *price > 0)
+        .map(|(available, price)| available / price)
+        .min()
+        .unwrap_or(0)
     }
 
     /// Compute how many units of given type can user train at most
@@ -550,10 +2201,10 @@ impl Player {
     pub fn train_max_units(&self, unit_type: UnitType) -> Quantity {
         let (unit_wood, unit_gold) = unit_type.value();
 
-        // archers are only dependent on the gold
+        // archers are only dependent on the gold, the others need wood as well
         match unit_type {
             UnitType::Archer => (self.gold.quantity / unit_gold).min(self.fighters_capacity()),
-            UnitType::Warrior => (self.wood.quantity / unit_wood)
+            UnitType::Warrior | UnitType::Cavalry => (self.wood.quantity / unit_wood)
                 .min(self.gold.quantity / unit_gold)
                 .min(self.fighters_capacity()),
         }
@@ -572,6 +2223,156 @@ impl Player {
         match unit_type {
             UnitType::Archer => self.archers.quantity,
             UnitType::Warrior => self.warriors.quantity,
+            UnitType::Cavalry => self.cavalry.quantity,
+        }
+    }
+
+    /// Forecast the battle on a field, if the player sent all the units they have at home there
+    ///
+    /// Params
+    /// ---
+    /// - field: the field as the player knows it
+    ///
+    /// Returns
+    /// ---
+    /// - String: f.e. "you'd have 61.2 power vs visible 48.0 - likely win"
+    pub fn conquest_forecast(&self, field: &GameField) -> String {
+        let mut reinforced = field.clone();
+        for unit_type in UnitType::all() {
+            let quantity = self.send_max_units(unit_type);
+            if quantity > 0 {
+                reinforced.add_units(UnitInField::new(
+                    self.nick.clone(),
+                    Unit::unit_to_send(unit_type, quantity),
+                ));
+            }
+        }
+
+        let power = reinforced.power_of(&self.nick);
+        let opponent_power = match reinforced.strongest_opponent(&self.nick) {
+            Some((_, opponent_power)) => opponent_power,
+            None => return format!("you'd have {:.1} power, no enemies in sight", power),
+        };
+
+        // the battle is decided by the same comparison as the lead of the field
+        let outcome = match reinforced.leader() {
+            Some((leader, _)) if leader == self.nick => "likely win",
+            Some(_) => "likely loss",
+            None => "too close to call",
+        };
+
+        format!(
+            "you'd have {:.1} power vs visible {:.1} - {}",
+            power, opponent_power, outcome
+        )
+    }
+
+    /// Compute an upper bound of gold the player can still get by themselves
+    ///
+    /// The bound assumes every remaining round could be spent harvesting next to the farm income
+    /// and that every pending offer of the player comes back to them.
+    ///
+    /// Params
+    /// ---
+    /// - rounds_left: how many rounds remain to be played
+    ///
+    /// Returns
+    /// ---
+    /// - highest amount of gold the player could ever have
+    pub fn max_gold(&self, rounds_left: usize) -> Quantity {
+        // the farms yield at the start of every turn
+        let gold_per_turn =
+            income(IncomeSource::Harvest).1 + income(IncomeSource::Farm).1 * self.farm_levels();
+        let offered_gold: Quantity = self.offers.iter().map(|offer| offer.give.1).sum();
+
+        self.gold.quantity + offered_gold + gold_per_turn * rounds_left as Quantity
+    }
+
+    /// Compute an upper bound of fighting power the player can still send
+    /// to a single field within the remaining rounds
+    ///
+    /// The bound assumes that all the gold could be turned into the strongest units and that
+    /// every action point of the remaining turns could send a stack to the field,
+    /// so the real reachable power is never higher.
+    ///
+    /// Params
+    /// ---
+    /// - game_plan: battlefield, units there can be recalled and sent again
+    /// - gold_total: all the gold the player could ever have, see max_gold
+    /// - rounds_left: how many rounds remain to be played
+    ///
+    /// Returns
+    /// ---
+    /// - highest power the player could possibly add to one field
+    pub fn max_reinforcement_power(
+        &self,
+        game_plan: &GamePlan,
+        gold_total: Quantity,
+        rounds_left: usize,
+    ) -> FighterPower {
+        // no more actions can be taken
+        if rounds_left == 0 {
+            return 0.0;
+        }
+
+        let strongest_power = UnitType::all()
+            .iter()
+            .map(|unit_type| unit_type.power())
+            .fold(0.0, FighterPower::max);
+        // every new unit is either trained or upgraded from a peasant
+        let cheapest_gold = UnitType::all()
+            .iter()
+            .flat_map(|unit_type| [unit_type.value().1, PeasantUpgrade(*unit_type).value().1])
+            .min()
+            .unwrap_or(1)
+            .max(1);
+
+        // every conquer action sends one unit type, at most this many stacks reach the field
+        let sends =
+            (limits::ACTION_POINTS_PER_TURN / limits::CONQUER_ACTION_POINTS) as usize * rounds_left;
+        let mut stacks: Vec<FighterPower> = UnitType::all()
+            .iter()
+            .map(|unit_type| {
+                unit_type.power() * self.units_owned(game_plan, *unit_type) as FighterPower
+            })
+            .collect();
+        stacks.sort_by(|first, second| second.total_cmp(first));
+
+        // the new units may join any stack, so they are counted on top of the sent ones
+        stacks.iter().take(sends).sum::<FighterPower>()
+            + strongest_power * (gold_total / cheapest_gold) as FighterPower
+    }
+
+    /// Add resources without harvesting them, used by the developer console
+    ///
+    /// Params
+    /// ---
+    /// - wood: wood to add
+    /// - gold: gold to add
+    #[cfg(feature = "dev")]
+    pub fn cheat_resources(&mut self, wood: Quantity, gold: Quantity) {
+        self.wood.quantity += wood;
+        self.gold.quantity += gold;
+    }
+
+    /// Add units without paying for them or fitting them into bases,
+    /// used by the developer console
+    ///
+    /// Params
+    /// ---
+    /// - unit_type: type of the units
+    /// - quantity: number of units to add
+    #[cfg(feature = "dev")]
+    pub fn cheat_units(&mut self, unit_type: UnitType, quantity: Quantity) {
+        match unit_type {
+            UnitType::Archer => self.archers.train(quantity),
+            UnitType::Warrior => self.warriors.train(quantity),
+            UnitType::Cavalry => self.cavalry.train(quantity),
         }
     }
 }
+
+/// Cavalry of a player loaded from a save which did not know it
+fn no_cavalry() -> Unit {
+    Unit::new(UnitType::Cavalry)
+}
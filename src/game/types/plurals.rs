@@ -0,0 +1,134 @@
+// Pluralization of words and formatting of numbers shown in the game output.
+// Instead of comparing quantities with 1 everywhere, the plural rules
+// of the game language decide which form of a word to use, and large numbers
+// are written with the digit grouping of the language.
+
+use std::fmt::Display;
+
+/// Plural categories of words
+#[derive(PartialEq, Clone, Copy)]
+pub enum PluralCategory {
+    One,
+    Other,
+}
+
+/// Languages with known plural rules
+#[derive(PartialEq, Clone, Copy)]
+pub enum Language {
+    English,
+}
+
+// language of the game output
+pub const GAME_LANGUAGE: Language = Language::English;
+
+impl Language {
+    /// Return the plural category of a quantity in this language
+    ///
+    /// Params
+    /// ---
+    /// - quantity: counted quantity
+    pub fn plural_category(&self, quantity: i64) -> PluralCategory {
+        match self {
+            Language::English => match quantity {
+                1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+        }
+    }
+
+    /// Return the separator of the groups of thousands in this language, f.e. ',' in '1,200'
+    pub fn digit_separator(&self) -> char {
+        match self {
+            Language::English => ',',
+        }
+    }
+}
+
+/// Choose the form of a word fitting a quantity
+///
+/// Params
+/// ---
+/// - quantity: counted quantity
+/// - one: singular form of the word
+/// - other: plural form of the word
+///
+/// Returns
+/// ---
+/// - the form of the word for the quantity in the game language
+pub fn plural_word<'a, T: TryInto<i64>>(quantity: T, one: &'a str, other: &'a str) -> &'a str {
+    // quantities too large for i64 are definitely not singular
+    let quantity = quantity.try_into().unwrap_or(i64::MAX);
+
+    match GAME_LANGUAGE.plural_category(quantity) {
+        PluralCategory::One => one,
+        PluralCategory::Other => other,
+    }
+}
+
+/// Return the plural suffix of a word fitting a quantity
+///
+/// Used for words which are displayed by their types (f.e. unit types),
+/// where only the suffix is appended.
+///
+/// Params
+/// ---
+/// - quantity: counted quantity
+/// - suffix: suffix of the plural form, f.e. 'S'
+///
+/// Returns
+/// ---
+/// - empty string for singular, the suffix otherwise
+pub fn plural_suffix<T: TryInto<i64>>(quantity: T, suffix: &str) -> &str {
+    plural_word(quantity, "", suffix)
+}
+
+/// Write a number as an ordinal, f.e. '3rd'
+///
+/// Params
+/// ---
+/// - number: the counted number
+///
+/// Returns
+/// ---
+/// - the number with the ordinal suffix of the game language
+pub fn ordinal(number: usize) -> String {
+    match GAME_LANGUAGE {
+        Language::English => {
+            let suffix = match (number % 10, number % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            format!("{}{}", number, suffix)
+        }
+    }
+}
+
+/// Write a number with its digits grouped by thousands, f.e. '1,200'
+///
+/// Params
+/// ---
+/// - number: the written number, a whole one
+///
+/// Returns
+/// ---
+/// - the number with the digit grouping of the game language
+pub fn format_number<T: Display>(number: T) -> String {
+    let written = number.to_string();
+    let (sign, digits) = match written.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", written.as_str()),
+    };
+
+    let mut grouped = String::from(sign);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            grouped.push(GAME_LANGUAGE.digit_separator());
+        }
+        grouped.push(digit);
+    }
+
+    grouped
+}
@@ -1,16 +1,20 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
-use super::value_types::Quantity;
+use super::{
+    errors::{GameError, Shortfall},
+    value_types::Quantity,
+};
 
 /// Resource has a value (amount) and a type
-#[derive(PartialEq, Clone, Copy)]
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub struct Resource {
     pub(super) resource_type: ResourceType,
     pub(super) quantity: Quantity,
 }
 
 /// Resource types
-#[derive(PartialEq, Clone, Copy)]
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum ResourceType {
     Wood,
     Gold,
@@ -34,17 +38,25 @@ impl Resource {
     }
 
     pub fn can_pay(&self, quantity: Quantity) -> bool {
-        self.quantity - quantity >= 0
+        // negative prices would pay the player instead
+        (0..=self.quantity).contains(&quantity)
     }
 
-    pub fn cannot_pay(&self) -> String {
-        format!(
-            "║{:^78}║\n",
-            format!(
-                "You don't have enough {} to perform this operation",
-                &self.resource_type,
-            ),
-        )
+    /// Describe how much of the resource is missing to pay a quantity
+    ///
+    /// Params
+    /// ---
+    /// - quantity: value which should be paid
+    ///
+    /// Returns
+    /// ---
+    /// - shortfall of the resource
+    pub fn shortfall(&self, quantity: Quantity) -> Shortfall {
+        Shortfall {
+            resource: self.resource_type,
+            needed: quantity,
+            available: self.quantity,
+        }
     }
 
     /// Add a certain value to the resource
@@ -81,14 +93,16 @@ impl Resource {
     /// Returns
     /// ---
     /// - Ok(()) if the operation was successful
-    /// - Err(String) with error description
-    pub fn subtract(&mut self, quantity: Quantity) -> Result<(), String> {
+    /// - Err(GameError) with the missing amount
+    pub fn subtract(&mut self, quantity: Quantity) -> Result<(), GameError> {
         match self.can_pay(quantity) {
             true => {
                 self.quantity -= quantity;
                 Ok(())
             }
-            false => Err(self.cannot_pay()),
+            false => Err(GameError::NotEnoughResources(
+                vec![self.shortfall(quantity)],
+            )),
         }
     }
 }
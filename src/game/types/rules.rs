@@ -0,0 +1,257 @@
+// Balance values of the game which can be tuned without recompiling.
+// They start with the values from limits.rs and can be overridden by a configuration file.
+
+use std::sync::RwLock;
+
+use super::{
+    limits,
+    value_types::{Capacity, FighterPower, Quantity, ResourceValue},
+};
+
+/// Tunable values of the game
+#[derive(PartialEq, Clone, Copy)]
+pub struct Rules {
+    pub base_capacity: Capacity,
+    pub base_level_capacity: Capacity,
+    pub base_cost: ResourceValue,
+    pub farm_cost: ResourceValue,
+    pub market_cost: ResourceValue,
+    pub base_upgrade_cost: ResourceValue,
+    pub farm_upgrade_cost: ResourceValue,
+    pub market_upgrade_cost: ResourceValue,
+    pub archer_cost: ResourceValue,
+    pub warrior_cost: ResourceValue,
+    pub cavalry_cost: ResourceValue,
+    pub archer_recruit_cost: ResourceValue,
+    pub warrior_recruit_cost: ResourceValue,
+    pub cavalry_recruit_cost: ResourceValue,
+    pub siege_ladder_cost: ResourceValue,
+    pub fire_arrow_cost: ResourceValue,
+    pub healing_potion_cost: ResourceValue,
+    pub tower_cost: ResourceValue,
+    pub harvest_gain: ResourceValue,
+    pub farm_income: ResourceValue,
+    pub pass_gain: ResourceValue,
+    pub archer_upkeep: Quantity,
+    pub warrior_upkeep: Quantity,
+    pub cavalry_upkeep: Quantity,
+    pub market_reserves: ResourceValue,
+    pub market_fee: Quantity,
+    pub battle_spoils: Quantity,
+    pub peasants_per_base: Quantity,
+    pub fields_per_base: Quantity,
+    pub revolt_chance: Quantity,
+    pub archer_power: FighterPower,
+    pub warrior_power: FighterPower,
+    pub cavalry_power: FighterPower,
+    pub garrison_defense_bonus: FighterPower,
+    pub siege_ladder_bonus: FighterPower,
+    pub fire_arrow_bonus: FighterPower,
+    pub war_banner_bonus: FighterPower,
+    pub tower_bonus: FighterPower,
+    pub healing_potion_casualties: FighterPower,
+    pub tie_tolerance: FighterPower,
+}
+
+/// Mutable reference to one of the rules, used to look them up by their names
+pub enum RuleValue<'a> {
+    Capacity(&'a mut Capacity),
+    Cost(&'a mut ResourceValue),
+    Quantity(&'a mut Quantity),
+    Power(&'a mut FighterPower),
+    Tolerance(&'a mut FighterPower),
+}
+
+// rules the game is played with
+static ACTIVE_RULES: RwLock<Rules> = RwLock::new(Rules::DEFAULT);
+
+impl Rules {
+    /// Rules defined in limits.rs
+    pub const DEFAULT: Rules = Rules {
+        base_capacity: limits::BASE_CAPACITY,
+        base_level_capacity: limits::BASE_LEVEL_CAPACITY,
+        base_cost: limits::BASE_COST,
+        farm_cost: limits::FARM_COST,
+        market_cost: limits::MARKET_COST,
+        base_upgrade_cost: limits::BASE_UPGRADE_COST,
+        farm_upgrade_cost: limits::FARM_UPGRADE_COST,
+        market_upgrade_cost: limits::MARKET_UPGRADE_COST,
+        archer_cost: limits::ARCHER_COST,
+        warrior_cost: limits::WARRIOR_COST,
+        cavalry_cost: limits::CAVALRY_COST,
+        archer_recruit_cost: limits::ARCHER_RECRUIT_COST,
+        warrior_recruit_cost: limits::WARRIOR_RECRUIT_COST,
+        cavalry_recruit_cost: limits::CAVALRY_RECRUIT_COST,
+        siege_ladder_cost: limits::SIEGE_LADDER_COST,
+        fire_arrow_cost: limits::FIRE_ARROW_COST,
+        healing_potion_cost: limits::HEALING_POTION_COST,
+        tower_cost: limits::TOWER_COST,
+        harvest_gain: limits::HARVEST_GAIN,
+        farm_income: limits::FARM_INCOME,
+        pass_gain: limits::PASS_GAIN,
+        archer_upkeep: limits::ARCHER_UPKEEP,
+        warrior_upkeep: limits::WARRIOR_UPKEEP,
+        cavalry_upkeep: limits::CAVALRY_UPKEEP,
+        market_reserves: limits::MARKET_RESERVES,
+        market_fee: limits::MARKET_FEE,
+        battle_spoils: limits::BATTLE_SPOILS,
+        peasants_per_base: limits::PEASANTS_PER_BASE,
+        fields_per_base: limits::FIELDS_PER_BASE,
+        revolt_chance: limits::REVOLT_CHANCE,
+        archer_power: limits::ARCHER_POWER,
+        warrior_power: limits::WARRIOR_POWER,
+        cavalry_power: limits::CAVALRY_POWER,
+        garrison_defense_bonus: limits::GARRISON_DEFENSE_BONUS,
+        siege_ladder_bonus: limits::SIEGE_LADDER_BONUS,
+        fire_arrow_bonus: limits::FIRE_ARROW_BONUS,
+        war_banner_bonus: limits::WAR_BANNER_BONUS,
+        tower_bonus: limits::TOWER_BONUS,
+        healing_potion_casualties: limits::HEALING_POTION_CASUALTIES,
+        tie_tolerance: limits::POWER_TIE_TOLERANCE,
+    };
+
+    /// List the rules along with their names, in the order of the configuration file
+    ///
+    /// Returns
+    /// ---
+    /// - vector of (name, value) of every rule
+    pub fn entries(&mut self) -> Vec<(&'static str, RuleValue<'_>)> {
+        vec![
+            (
+                "base_capacity",
+                RuleValue::Capacity(&mut self.base_capacity),
+            ),
+            (
+                "base_level_capacity",
+                RuleValue::Capacity(&mut self.base_level_capacity),
+            ),
+            ("base_cost", RuleValue::Cost(&mut self.base_cost)),
+            ("farm_cost", RuleValue::Cost(&mut self.farm_cost)),
+            ("market_cost", RuleValue::Cost(&mut self.market_cost)),
+            (
+                "base_upgrade_cost",
+                RuleValue::Cost(&mut self.base_upgrade_cost),
+            ),
+            (
+                "farm_upgrade_cost",
+                RuleValue::Cost(&mut self.farm_upgrade_cost),
+            ),
+            (
+                "market_upgrade_cost",
+                RuleValue::Cost(&mut self.market_upgrade_cost),
+            ),
+            ("archer_cost", RuleValue::Cost(&mut self.archer_cost)),
+            ("warrior_cost", RuleValue::Cost(&mut self.warrior_cost)),
+            ("cavalry_cost", RuleValue::Cost(&mut self.cavalry_cost)),
+            (
+                "archer_recruit_cost",
+                RuleValue::Cost(&mut self.archer_recruit_cost),
+            ),
+            (
+                "warrior_recruit_cost",
+                RuleValue::Cost(&mut self.warrior_recruit_cost),
+            ),
+            (
+                "cavalry_recruit_cost",
+                RuleValue::Cost(&mut self.cavalry_recruit_cost),
+            ),
+            (
+                "siege_ladder_cost",
+                RuleValue::Cost(&mut self.siege_ladder_cost),
+            ),
+            (
+                "fire_arrow_cost",
+                RuleValue::Cost(&mut self.fire_arrow_cost),
+            ),
+            (
+                "healing_potion_cost",
+                RuleValue::Cost(&mut self.healing_potion_cost),
+            ),
+            ("tower_cost", RuleValue::Cost(&mut self.tower_cost)),
+            ("harvest_gain", RuleValue::Cost(&mut self.harvest_gain)),
+            ("farm_income", RuleValue::Cost(&mut self.farm_income)),
+            ("pass_gain", RuleValue::Cost(&mut self.pass_gain)),
+            (
+                "archer_upkeep",
+                RuleValue::Quantity(&mut self.archer_upkeep),
+            ),
+            (
+                "warrior_upkeep",
+                RuleValue::Quantity(&mut self.warrior_upkeep),
+            ),
+            (
+                "cavalry_upkeep",
+                RuleValue::Quantity(&mut self.cavalry_upkeep),
+            ),
+            (
+                "market_reserves",
+                RuleValue::Cost(&mut self.market_reserves),
+            ),
+            ("market_fee", RuleValue::Quantity(&mut self.market_fee)),
+            (
+                "battle_spoils",
+                RuleValue::Quantity(&mut self.battle_spoils),
+            ),
+            (
+                "peasants_per_base",
+                RuleValue::Quantity(&mut self.peasants_per_base),
+            ),
+            (
+                "fields_per_base",
+                RuleValue::Quantity(&mut self.fields_per_base),
+            ),
+            (
+                "revolt_chance",
+                RuleValue::Quantity(&mut self.revolt_chance),
+            ),
+            ("archer_power", RuleValue::Power(&mut self.archer_power)),
+            ("warrior_power", RuleValue::Power(&mut self.warrior_power)),
+            ("cavalry_power", RuleValue::Power(&mut self.cavalry_power)),
+            (
+                "garrison_defense_bonus",
+                RuleValue::Power(&mut self.garrison_defense_bonus),
+            ),
+            (
+                "siege_ladder_bonus",
+                RuleValue::Power(&mut self.siege_ladder_bonus),
+            ),
+            (
+                "fire_arrow_bonus",
+                RuleValue::Power(&mut self.fire_arrow_bonus),
+            ),
+            (
+                "war_banner_bonus",
+                RuleValue::Power(&mut self.war_banner_bonus),
+            ),
+            ("tower_bonus", RuleValue::Power(&mut self.tower_bonus)),
+            (
+                "healing_potion_casualties",
+                RuleValue::Power(&mut self.healing_potion_casualties),
+            ),
+            (
+                "tie_tolerance",
+                RuleValue::Tolerance(&mut self.tie_tolerance),
+            ),
+        ]
+    }
+}
+
+/// Return the rules the game is played with
+pub fn rules() -> Rules {
+    match ACTIVE_RULES.read() {
+        Ok(rules) => *rules,
+        Err(poisoned) => *poisoned.into_inner(),
+    }
+}
+
+/// Play the game with different rules from now on
+///
+/// Params
+/// ---
+/// - rules: new rules of the game
+pub fn set_rules(rules: Rules) {
+    match ACTIVE_RULES.write() {
+        Ok(mut active) => *active = rules,
+        Err(poisoned) => *poisoned.into_inner() = rules,
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+use super::{player::Player, properties::HasValue, value_types::ResourceValue};
+
+/// Offer of resources to another player, answered at the start of their next turn
+///
+/// The offered resources are taken from the offering player right away,
+/// they get them back if the offer is declined.
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+pub struct TradeOffer {
+    /// nick of the player who made the offer
+    pub from: String,
+    /// nick of the player the offer is made to
+    pub to: String,
+    /// (wood, gold) the offering player gives
+    pub give: ResourceValue,
+    /// (wood, gold) the offering player wants in return
+    pub want: ResourceValue,
+}
+
+impl TradeOffer {
+    /// Decide whether a bot accepts the offer
+    ///
+    /// Params
+    /// ---
+    /// - receiver: the player the offer is made to
+    ///
+    /// Returns
+    /// ---
+    /// - true: if the receiver can pay for the offer and gets at least as much as they give
+    pub fn favorable_for(&self, receiver: &Player) -> bool {
+        let (wood, gold) = receiver.supplies();
+
+        wood >= self.want.0
+            && gold >= self.want.1
+            && self.give.0 + self.give.1 >= self.want.0 + self.want.1
+    }
+}
+
+/// A bundle of resources is worth exactly what it contains
+impl HasValue for ResourceValue {
+    fn value(&self) -> ResourceValue {
+        *self
+    }
+}
+
+/// Describe a bundle of resources, f.e. '100 wood and 50 gold'
+pub fn describe_resources((wood, gold): ResourceValue) -> String {
+    format!("{} wood and {} gold", wood, gold)
+}
+
+/// Answer an offer made to a player, completing the trade on both sides at once
+///
+/// Params
+/// ---
+/// - players: all players of the game
+/// - from: nick of the player who made the offer
+/// - to: nick of the player answering the offer
+/// - accept: whether the offer is accepted
+///
+/// Returns
+/// ---
+/// - Ok(String) describing the trade
+/// - Err(String) if there is no such offer, or the receiving player cannot pay for it
+pub fn answer_offer(
+    players: &mut [Player],
+    from: &str,
+    to: &str,
+    accept: bool,
+) -> Result<String, String> {
+    let no_offer = format!(
+        "║{:^78}║",
+        format!("{} has no trade offer for {}.", from, to)
+    );
+
+    let mut giver = None;
+    let mut receiver = None;
+    for player in players.iter_mut() {
+        if player.nick == from {
+            giver = Some(player);
+        } else if player.nick == to {
+            receiver = Some(player);
+        }
+    }
+
+    let (Some(giver), Some(receiver)) = (giver, receiver) else {
+        return Err(no_offer);
+    };
+    let Some(offer) = giver.withdraw_offer(to) else {
+        return Err(no_offer);
+    };
+
+    match accept {
+        true => match receiver.accept_offer(&offer) {
+            Ok(notification) => {
+                giver.receive_resources(offer.want);
+                Ok(notification)
+            }
+            // the offer keeps waiting, it can still be declined
+            Err(error) => {
+                giver.return_offer(offer);
+                Err(error)
+            }
+        },
+        false => {
+            giver.receive_resources(offer.give);
+            Ok(format!(
+                "║{:^78}║",
+                format!("The trade offer of {} was declined.", from),
+            ))
+        }
+    }
+}
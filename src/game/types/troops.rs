@@ -1,22 +1,53 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 use super::{
-    limits,
+    modifiers::{self, CostTarget},
+    plurals::plural_suffix,
     properties::{HasPower, HasValue},
+    rules::rules,
     value_types::{FighterPower, Quantity, ResourceValue},
 };
 /// Unit which can store a value
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Unit {
     pub(super) unit_type: UnitType,
     pub(super) quantity: Quantity,
 }
 
 /// Unit types
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum UnitType {
     Warrior,
     Archer,
+    Cavalry,
+}
+
+/// Named set of units a player trains in a single action
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArmyTemplate {
+    pub name: String,
+    pub units: Vec<(UnitType, Quantity)>,
+}
+
+/// Upgrade of a peasant into a unit of certain type
+#[derive(Clone, Copy, PartialEq)]
+pub struct PeasantUpgrade(pub UnitType);
+
+/// Stance of units occupying a field
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Stance {
+    /// default stance, no modifiers
+    Raiding,
+    /// units defend the field, stronger when their owner is the defender of the field
+    Garrison,
+}
+
+impl Stance {
+    /// Return all the stances (in the order they are offered to players)
+    pub fn all() -> [Stance; 2] {
+        [Stance::Garrison, Stance::Raiding]
+    }
 }
 
 impl Unit {
@@ -77,6 +108,46 @@ impl Unit {
     }
 }
 
+impl UnitType {
+    /// Return every unit type in the game
+    pub fn all() -> [UnitType; 3] {
+        [UnitType::Archer, UnitType::Warrior, UnitType::Cavalry]
+    }
+
+    /// Compute how many units of this type are needed to reach a certain power
+    ///
+    /// Params
+    /// ---
+    /// - power: desired fighting power
+    ///
+    /// Returns
+    /// ---
+    /// - smallest number of units with at least the desired power
+    pub fn units_for_power(&self, power: FighterPower) -> Quantity {
+        // small epsilon so that exact multiples are not rounded up
+        ((power / self.power()) - 1e-9).ceil().max(0.0) as Quantity
+    }
+
+    /// Return the gold a batch of units of this type costs at the start of every turn
+    pub fn upkeep(&self) -> Quantity {
+        match self {
+            Self::Archer => rules().archer_upkeep,
+            Self::Warrior => rules().warrior_upkeep,
+            Self::Cavalry => rules().cavalry_upkeep,
+        }
+    }
+}
+
+impl ArmyTemplate {
+    /// Return the number of units the template trains
+    pub fn quantity(&self) -> Quantity {
+        // saturating, so that huge armies exceed every capacity instead of overflowing
+        self.units
+            .iter()
+            .fold(0, |total, (_, quantity)| total.saturating_add(*quantity))
+    }
+}
+
 /// Every unit has its value
 impl HasValue for Unit {
     /// Return value of a unit
@@ -89,10 +160,7 @@ impl HasValue for Unit {
 impl HasPower for UnitType {
     /// Return how much power a unit has
     fn power(&self) -> FighterPower {
-        match &self {
-            Self::Archer => limits::ARCHER_POWER,
-            Self::Warrior => limits::WARRIOR_POWER,
-        }
+        modifiers::power(*self)
     }
 }
 
@@ -100,10 +168,7 @@ impl HasPower for UnitType {
 impl HasValue for UnitType {
     /// Return how much a unit type costs
     fn value(&self) -> ResourceValue {
-        match &self {
-            Self::Archer => limits::ARCHER_COST,
-            Self::Warrior => limits::WARRIOR_COST,
-        }
+        modifiers::cost(CostTarget::Unit(*self))
     }
 }
 
@@ -117,6 +182,28 @@ impl Display for UnitType {
             UnitType::Warrior => {
                 write!(f, "WARRIOR")
             }
+            // displayed by its rider, so that its plural is formed like the others
+            UnitType::Cavalry => {
+                write!(f, "RIDER")
+            }
+        }
+    }
+}
+
+/// Upgrading a peasant costs less than training a new unit
+impl HasValue for PeasantUpgrade {
+    /// Return how much an upgrade of one peasant costs
+    fn value(&self) -> ResourceValue {
+        modifiers::cost(CostTarget::Recruit(self.0))
+    }
+}
+
+/// for displaying stances
+impl Display for Stance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stance::Raiding => write!(f, "RAIDING"),
+            Stance::Garrison => write!(f, "GARRISON"),
         }
     }
 }
@@ -127,3 +214,23 @@ impl Display for Unit {
         write!(f, "{}", self.unit_type)
     }
 }
+
+/// for displaying army templates, f.e. "20 ARCHERS + 10 WARRIORS"
+impl Display for ArmyTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let units: Vec<String> = self
+            .units
+            .iter()
+            .map(|(unit_type, quantity)| {
+                format!(
+                    "{} {}{}",
+                    quantity,
+                    unit_type,
+                    plural_suffix(*quantity, "S")
+                )
+            })
+            .collect();
+
+        write!(f, "{}", units.join(" + "))
+    }
+}
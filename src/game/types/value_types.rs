@@ -1,4 +1,5 @@
 pub type Capacity = i32; // f.e. how many units can fit into a building
 pub type FighterPower = f64; // how powerful a class of fighters is
+pub type ExactPower = i64; // fighting power in thousandths, adds up without rounding errors
 pub type Quantity = i32;
 pub type ResourceValue = (i32, i32); // (wood, gold)
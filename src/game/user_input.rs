@@ -1,19 +1,265 @@
-use std::io::stdin;
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::fs;
+use std::io::{stdin, IsTerminal};
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
 
-/// Retrieve a line from the stdin
+// lines which replace the stdin in the scripted mode
+static SCRIPT: Mutex<Option<VecDeque<String>>> = Mutex::new(None);
+
+// lines read from the stdin by a separate thread, so that waiting for them can time out
+static STDIN_LINES: OnceLock<Mutex<Receiver<String>>> = OnceLock::new();
+
+// moment the time limit of the current turn runs out, if the turn is limited
+static TURN_DEADLINE: Mutex<Option<Instant>> = Mutex::new(None);
+
+// the stdin has been closed, no more lines will come
+static INPUT_CLOSED: AtomicBool = AtomicBool::new(false);
+
+// the turns of the game deal with the end of the input, before they start it ends the program
+static INPUT_END_EXPECTED: AtomicBool = AtomicBool::new(false);
+
+/// Read the input lines from a script file instead of the stdin
+///
+/// Every line of the file answers one prompt, empty lines stand for pressing enter.
+/// Lines starting with '#' are comments and are left out.
+///
+/// Params
+/// ---
+/// - path: path of the script file
+///
+/// Returns
+/// ---
+/// - Ok(()) if the script was loaded
+/// - Err(String) if the file could not be read
+pub fn load_script(path: &str) -> Result<(), String> {
+    let contents =
+        fs::read_to_string(path).map_err(|error| format!("Cannot read {}: {}", path, error))?;
+
+    let lines: VecDeque<String> = contents
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| format!("{}\n", line))
+        .collect();
+
+    *SCRIPT.lock().unwrap_or_else(|error| error.into_inner()) = Some(lines);
+    Ok(())
+}
+
+/// Retrieve a line from the stdin, or from the script in the scripted mode
+///
+/// The game ends when the script runs out of lines. When the stdin is closed,
+/// an empty line is returned, or the game ends if it has not started yet.
 ///
 /// Returns
 /// ---
 /// - String: line from the input
 pub fn get_line() -> String {
-    let mut input_text = String::new();
+    if let Some(script) = SCRIPT
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .as_mut()
+    {
+        match script.pop_front() {
+            Some(line) => {
+                // echo the line, so that the output reads like a played game
+                print!("{}", line);
+                return line;
+            }
+            None => {
+                println!("\nThe script has run out of lines, the game cannot continue.\n");
+                process::exit(1);
+            }
+        }
+    }
+
+    let lines = stdin_lines();
+    let deadline = *TURN_DEADLINE
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+
+    // lines typed before the prompt appeared were not meant for it
+    if stdin().is_terminal() {
+        while lines.try_recv().is_ok() {}
+    }
+
+    // an empty line is returned when the turn runs out of time, or the stdin is closed
+    let line = match deadline {
+        Some(deadline) => {
+            match lines.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(line) => Some(line),
+                Err(RecvTimeoutError::Timeout) => Some(String::new()),
+                Err(RecvTimeoutError::Disconnected) => None,
+            }
+        }
+        None => lines.recv().ok(),
+    };
+
+    line.unwrap_or_else(|| {
+        INPUT_CLOSED.store(true, Ordering::Relaxed);
+        if !INPUT_END_EXPECTED.load(Ordering::Relaxed) {
+            println!("\nThe input has ended before the game could start.\n");
+            process::exit(1);
+        }
+        String::new()
+    })
+}
+
+/// Check whether the stdin has been closed, f.e. a piped script has run out of lines
+pub fn input_closed() -> bool {
+    INPUT_CLOSED.load(Ordering::Relaxed)
+}
+
+/// Let the game deal with the end of the input from now on, instead of ending right away
+pub fn expect_input_end() {
+    INPUT_END_EXPECTED.store(true, Ordering::Relaxed);
+}
+
+/// Wait for a while, pressing enter in a terminal cuts the wait short
+///
+/// The line ending the wait is thrown away, so that it does not answer the next prompt.
+/// Piped input is left for the prompts, it only waits.
+///
+/// Params
+/// ---
+/// - duration: how long to wait at most
+pub fn interruptible_sleep(duration: Duration) {
+    if !stdin().is_terminal() {
+        thread::sleep(duration);
+        return;
+    }
+
+    let start = Instant::now();
+
+    // a closed stdin cannot end the wait
+    if let Err(RecvTimeoutError::Disconnected) = stdin_lines().recv_timeout(duration) {
+        thread::sleep(duration.saturating_sub(start.elapsed()));
+    }
+}
+
+/// Return the lines read from the stdin, the reading starts with the first call
+fn stdin_lines() -> MutexGuard<'static, Receiver<String>> {
+    STDIN_LINES
+        .get_or_init(|| Mutex::new(spawn_stdin_reader()))
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+/// Read the stdin line by line in a separate thread
+///
+/// Returns
+/// ---
+/// - Receiver of the read lines, it is disconnected when the stdin is closed
+fn spawn_stdin_reader() -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || loop {
+        let mut input_text = String::new();
 
-    loop {
         match stdin().read_line(&mut input_text) {
-            Ok(_) => break,
+            Ok(0) => return,
+            Ok(_) => {
+                if sender.send(input_text).is_err() {
+                    return;
+                }
+            }
             Err(_) => println!("\nCannot read from command line, try again!\n"),
         }
+    });
+
+    receiver
+}
+
+/// Start measuring the time of a turn, the input stops waiting for the player when it runs out
+///
+/// Params
+/// ---
+/// - limit: time the player has for the whole turn, None for an unlimited turn
+pub fn start_turn_timer(limit: Option<Duration>) {
+    *TURN_DEADLINE
+        .lock()
+        .unwrap_or_else(|error| error.into_inner()) = limit.map(|limit| Instant::now() + limit);
+}
+
+/// Stop measuring the time of the turn, the input waits for the player again
+pub fn stop_turn_timer() {
+    start_turn_timer(None);
+}
+
+/// Check whether the time limit of the current turn has run out
+///
+/// Returns
+/// ---
+/// - true: if the turn is limited and its time is up
+/// - false: otherwise
+pub fn turn_expired() -> bool {
+    TURN_DEADLINE
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .is_some_and(|deadline| Instant::now() >= deadline)
+}
+
+/// Check whether the player cannot go on with the turn,
+/// either its time is up or the input has ended
+pub fn turn_interrupted() -> bool {
+    turn_expired() || input_closed()
+}
+
+/// Parse an answer to a yes/no question
+/// Digits can be used instead of words, 1 stands for yes and 2 for no.
+///
+/// Params
+/// ---
+/// - line: trimmed user input
+///
+/// Returns
+/// ---
+/// - Some(true): if the user agreed
+/// - Some(false): if the user declined
+/// - None: if the answer is neither
+pub fn parse_confirmation(line: &str) -> Option<bool> {
+    match line {
+        "YES" | "Yes" | "yes" | "Y" | "y" | "1" => Some(true),
+        "NO" | "No" | "no" | "N" | "n" | "2" => Some(false),
+        _ => None,
     }
+}
+
+/// List options of a prompt together with the numbers which select them
+///
+/// Params
+/// ---
+/// - options: options in the order of their numbers
+///
+/// Returns
+/// ---
+/// - String: f.e. "'1' or 'ARCHER', '2' or 'WARRIOR'"
+pub fn numbered_options<T: Display>(options: &[T]) -> String {
+    options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| format!("'{}' or '{}'", index + 1, option))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
 
-    input_text
+/// Pick an option by the number typed by the user (counted from 1)
+///
+/// Params
+/// ---
+/// - line: trimmed user input
+/// - options: options in the order of their numbers
+///
+/// Returns
+/// ---
+/// - Some(option): if the line is a number of one of the options
+/// - None: otherwise
+pub fn parse_numbered<T: Copy>(line: &str, options: &[T]) -> Option<T> {
+    let number = line.parse::<usize>().ok()?;
+    options.get(number.checked_sub(1)?).copied()
 }
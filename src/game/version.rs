@@ -0,0 +1,84 @@
+// Version of the game engine and of its rules.
+// It is stored in saved games and checked by the server, so a game is never
+// continued by a build which would play it differently without anyone noticing.
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::Display;
+
+// revision of the rules and of the saved state, raise it whenever a change
+// makes the same actions end differently or changes the format of saved games
+pub const RULES_VERSION: u32 = 5;
+
+/// Version of the build which made a saved game or sent a request
+#[derive(Serialize, Deserialize, PartialEq, Clone)]
+pub struct GameVersion {
+    pub engine: String,
+    pub rules: u32,
+}
+
+impl GameVersion {
+    /// Version of this build
+    pub fn current() -> GameVersion {
+        GameVersion {
+            engine: String::from(env!("CARGO_PKG_VERSION")),
+            rules: RULES_VERSION,
+        }
+    }
+
+    /// Parse a version written as 'engine/rules', f.e. '0.1.0/1'
+    ///
+    /// Params
+    /// ---
+    /// - text: the written version
+    ///
+    /// Returns
+    /// ---
+    /// - Some(version) if the text is a valid version
+    /// - None otherwise
+    pub fn parse(text: &str) -> Option<GameVersion> {
+        let (engine, rules) = text.trim().split_once('/')?;
+
+        Some(GameVersion {
+            engine: String::from(engine.trim()),
+            rules: rules.trim().parse().ok()?,
+        })
+    }
+
+    /// Check whether this build can continue a game made by a build of this version
+    ///
+    /// Params
+    /// ---
+    /// - origin: what the version belongs to, f.e. 'game.json' or 'The client'
+    ///
+    /// Returns
+    /// ---
+    /// - Ok(None) if the versions are the same
+    /// - Ok(Some(String)) with a warning, if only the engine versions differ
+    /// - Err(String) if the rules differ, the game would play differently
+    pub fn check(&self, origin: &str) -> Result<Option<String>, String> {
+        let current = GameVersion::current();
+
+        if self.rules != current.rules {
+            return Err(format!(
+                "{} uses WarTycoon {} with rules version {}, but this is WarTycoon {} with rules version {}.",
+                origin, self.engine, self.rules, current.engine, current.rules
+            ));
+        }
+
+        match self.engine == current.engine {
+            true => Ok(None),
+            false => Ok(Some(format!(
+                "{} uses WarTycoon {}, this is WarTycoon {}. The rules are the same.",
+                origin, self.engine, current.engine
+            ))),
+        }
+    }
+}
+
+/// written as 'engine/rules', the same way clients send it in the 'X-Game-Version' header
+impl Display for GameVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.engine, self.rules)
+    }
+}
@@ -0,0 +1,105 @@
+// Conditions deciding who wins the game.
+// The classic game is won by winning the most fields after the last round,
+// the other conditions are checked at the end of every round and can end the game early.
+// When nobody fulfils them until the last round, the fields decide after all.
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::Display;
+
+use super::types::{board::GamePlan, player::Player, troops::UnitType, value_types::Quantity};
+
+/// How the game is won
+#[derive(PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum VictoryCondition {
+    /// most fields won after the last round
+    #[default]
+    MostFields,
+    /// leading every field at the end of a round
+    Domination,
+    /// holding at least this much gold at the end of a round
+    Economic(Quantity),
+    /// being the only player who has not lost their army in the war
+    LastStanding,
+}
+
+impl VictoryCondition {
+    /// Check whether somebody has won the game at the end of a round
+    ///
+    /// Params
+    /// ---
+    /// - players: all players of the game
+    /// - game_plan: battlefield after the battles of the round
+    ///
+    /// Returns
+    /// ---
+    /// - Some(nick): the winner, the game ends right away
+    /// - None: if the game goes on, it is always the case for the most fields
+    pub fn round_winner(&self, players: &[Player], game_plan: &GamePlan) -> Option<String> {
+        match self {
+            VictoryCondition::MostFields => None,
+            VictoryCondition::Domination => {
+                let standings = game_plan.standings();
+                let (leader, fields) = standings.into_iter().next()?;
+                (fields == game_plan.number_of_fields()).then_some(leader)
+            }
+            VictoryCondition::Economic(gold) => {
+                let mut rich: Vec<&Player> = players
+                    .iter()
+                    .filter(|player| player.supplies().1 >= *gold)
+                    .collect();
+                rich.sort_by_key(|player| -player.supplies().1);
+
+                // players equally rich have to keep on gathering
+                match rich.as_slice() {
+                    [richest] => Some(richest.nick.clone()),
+                    [richest, second, ..] if richest.supplies().1 > second.supplies().1 => {
+                        Some(richest.nick.clone())
+                    }
+                    _ => None,
+                }
+            }
+            VictoryCondition::LastStanding => {
+                let mut standing = players
+                    .iter()
+                    .filter(|player| !is_defeated(player, game_plan));
+
+                match (standing.next(), standing.next()) {
+                    (Some(survivor), None) if players.len() > 1 => Some(survivor.nick.clone()),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// Check whether a player has gone to war and lost all the units,
+/// players who have never sent units to the battlefield cannot be defeated
+///
+/// Params
+/// ---
+/// - player: the checked player
+/// - game_plan: battlefield of the game
+fn is_defeated(player: &Player, game_plan: &GamePlan) -> bool {
+    let has_units = UnitType::all().iter().any(|unit_type| {
+        player.units(*unit_type) > 0
+            || (1..=game_plan.number_of_fields())
+                .filter_map(|number| game_plan.field_coordinates(number))
+                .filter_map(|(x, y)| game_plan.field(x, y))
+                .any(|field| field.units_of(&player.nick, *unit_type) > 0)
+    });
+
+    player.went_to_war() && !has_units
+}
+
+/// Used for displaying victory conditions in strings
+impl Display for VictoryCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VictoryCondition::MostFields => write!(f, "MOST FIELDS"),
+            VictoryCondition::Domination => write!(f, "DOMINATION"),
+            VictoryCondition::Economic(gold) => write!(f, "ECONOMIC ({} gold)", gold),
+            VictoryCondition::LastStanding => write!(f, "SURVIVAL"),
+        }
+    }
+}
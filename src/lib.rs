@@ -0,0 +1,4 @@
+// WarTycoon as a library, the command line game in main.rs is one of its frontends.
+// The game::engine module drives a game without any terminal input or output.
+
+pub mod game;
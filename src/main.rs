@@ -4,69 +4,919 @@
 //
 // It IS intentional, please keep that in mind.
 
-mod game;
-
 // Player structure
-use game::types::player::Player;
+use wartycoon::game::types::player::Player;
+
+// battlefield structure
+use wartycoon::game::types::board::GamePlan;
 
 // use public game interface
-use game::{create_players, evaluate_game, generate_game_plan, get_number_of_rounds, play_round};
+use wartycoon::game::{
+    create_players, evaluate_game, generate_game_plan, get_campaign_nick, get_conquest_round,
+    get_fog_of_war, get_number_of_rounds, get_plan_dimensions, get_privacy_screen,
+    get_victory_condition, offer_early_finish, offer_next_skirmish, offer_rematch, play_round,
+    saved_at_input_end, use_script,
+};
+
+// campaigns of generated skirmishes
+use wartycoon::game::campaign::{core_units, Profile, Skirmish, DEFAULT_PROFILE_FILE};
+
+// notable games
+use wartycoon::game::hall_of_fame::{HallOfFame, DEFAULT_HALL_OF_FAME_FILE};
+
+// randomness of the games
+use wartycoon::game::rng::{time_seed, GameRng};
+
+// saving and loading games
+use wartycoon::game::save::{load_game, SavedGame};
+
+// recording games and playing them back
+use wartycoon::game::replay::{load_replay, play_replay, ReplayRecorder, DEFAULT_REPLAY_FILE};
+
+// checksums of the state for comparing separately running games
+use wartycoon::game::audit::Auditor;
+
+// the tutorial for new players
+use wartycoon::game::tutorial::play_tutorial;
+
+// playing over HTTP
+use wartycoon::game::engine::GameEngine;
+use wartycoon::game::server::{serve, Access};
+
+use std::env;
+
+// game settings
+use wartycoon::game::config::{describe_rules, load_config, DEFAULT_CONFIG_FILE};
+use wartycoon::game::settings::{GameSettings, InputEnd};
+use wartycoon::game::types::limits::DEFAULT_BOT_TIME_LIMIT;
+use wartycoon::game::types::rules::set_rules;
+use wartycoon::game::victory::VictoryCondition;
 
 // use interval for round sleep
-use game::sleep_intervals::game_round_sleep;
+use wartycoon::game::sleep_intervals::Pacing;
+
+// decision time statistics
+use wartycoon::game::decision_timer::DecisionTimer;
+
+// per-round statistics for the CSV export
+use wartycoon::game::stats::GameStats;
+
+// machine-readable results of finished games
+use wartycoon::game::results::GameResults;
+
+// opt-in balance metrics
+use wartycoon::game::telemetry::{
+    record_game, telemetry_report, GameMetrics, DEFAULT_TELEMETRY_FILE,
+};
+
+// colored output
+use wartycoon::game::colors::{error_label, init_colors, register_players};
+
+// game observers
+use wartycoon::game::commentator::Commentator;
+use wartycoon::game::events::{broadcast, GameEvent, Observer};
+use wartycoon::game::history::HistoryRecorder;
+use wartycoon::game::inbox::Inbox;
 
 // use game notifications
-use game::notifications::{print_game_start, print_greeting};
+use wartycoon::game::notifications::{
+    print_active_rules, print_battle_log, print_campaign_over, print_campaign_start,
+    print_conquest_phase, print_decision_awards, print_game_start, print_greeting,
+    print_hall_of_fame_entries, print_revolts, print_scoreboard, print_seed, print_skirmish,
+    print_skirmish_won, print_victory,
+};
 
 // default number of players
 const DEFAULT_NUM_PLAYERS: usize = 2;
 
 fn main() {
+    let arguments: Vec<String> = env::args().collect();
+
+    // the output is colored, unless it is turned off with '--no-color' or the NO_COLOR variable
+    init_colors(arguments.iter().any(|argument| argument == "--no-color"));
+
+    // 'telemetry report [file]' summarizes the recorded balance metrics instead of playing
+    if arguments.get(1).map(String::as_str) == Some("telemetry") {
+        match arguments.get(2).map(String::as_str) {
+            Some("report") => {
+                let path = arguments
+                    .get(3)
+                    .map_or(DEFAULT_TELEMETRY_FILE, String::as_str);
+                match telemetry_report(path) {
+                    Ok(report) => println!("\n{}\n", report.join("\n")),
+                    Err(error) => println!("\n{} {}\n", error_label(), error),
+                }
+            }
+            _ => println!(
+                "\n{} Please use 'telemetry report', optionally followed by the telemetry file.\n",
+                error_label()
+            ),
+        }
+        return;
+    }
+
+    // 'hall-of-fame [file]' shows the most notable games instead of playing
+    if arguments.get(1).map(String::as_str) == Some("hall-of-fame") {
+        let path = arguments
+            .get(2)
+            .map_or(DEFAULT_HALL_OF_FAME_FILE, String::as_str);
+        match HallOfFame::load(path) {
+            Ok(hall_of_fame) => println!("\n{}\n", hall_of_fame.report().join("\n")),
+            Err(error) => println!("\n{} {}\n", error_label(), error),
+        }
+        return;
+    }
+
+    // 'tutorial' teaches the basic actions against a training dummy,
+    // with the default rules and pacing
+    if arguments.get(1).map(String::as_str) == Some("tutorial") {
+        print_greeting(Pacing::default());
+        play_tutorial(Pacing::default());
+        return;
+    }
+
+    // how long the game pauses between its notifications
+    let mut pacing = Pacing::default();
+
+    // the input can be read from a file with '--script <file>'
+    if let Some(index) = arguments.iter().position(|argument| argument == "--script") {
+        let result = match arguments.get(index + 1) {
+            Some(path) => use_script(path),
+            None => Err(String::from(
+                "Please put a path to the script after '--script'.",
+            )),
+        };
+
+        if let Err(error) = result {
+            println!("\n{} {}\n", error_label(), error);
+            return;
+        }
+
+        // nobody is at the keyboard to wait for
+        pacing = Pacing::Off;
+    }
+
+    // the pauses of the game can be shortened, turned off or lengthened with '--pacing <pacing>',
+    // a picked pacing applies to scripts as well
+    if let Some(index) = arguments.iter().position(|argument| argument == "--pacing") {
+        match arguments
+            .get(index + 1)
+            .and_then(|word| Pacing::parse(word))
+        {
+            Some(picked) => pacing = picked,
+            None => {
+                println!(
+                    "\n{} Please put 'off', 'fast', 'normal' or 'cinematic' after '--pacing'.\n",
+                    error_label()
+                );
+                return;
+            }
+        }
+    }
+
+    // the statistics of every round can be exported with '--csv <file>'
+    let csv_path = match arguments.iter().position(|argument| argument == "--csv") {
+        Some(index) => match arguments.get(index + 1) {
+            Some(path) => Some(path.as_str()),
+            None => {
+                println!(
+                    "\n{} Please put a path to the CSV file after '--csv'.\n",
+                    error_label()
+                );
+                return;
+            }
+        },
+        None => None,
+    };
+
+    // the results of finished games can be exported for league tables with '--export <file>'
+    let export_path = match arguments.iter().position(|argument| argument == "--export") {
+        Some(index) => match arguments.get(index + 1) {
+            Some(path) => Some(path.as_str()),
+            None => {
+                println!(
+                    "\n{} Please put a path to the results file after '--export'.\n",
+                    error_label()
+                );
+                return;
+            }
+        },
+        None => None,
+    };
+
+    // anonymous balance metrics of finished games are only collected with '--telemetry'
+    let telemetry_path = arguments
+        .iter()
+        .any(|argument| argument == "--telemetry")
+        .then_some(DEFAULT_TELEMETRY_FILE);
+
+    // '--audit' prints a checksum of the state after every action, to compare separately running games
+    let audit = arguments.iter().any(|argument| argument == "--audit");
+
+    // games are recorded into a replay file, '--record <file>' picks another one
+    let record_path = match arguments.iter().position(|argument| argument == "--record") {
+        Some(index) => match arguments.get(index + 1) {
+            Some(path) => path.as_str(),
+            None => {
+                println!(
+                    "\n{} Please put a path to the replay file after '--record'.\n",
+                    error_label()
+                );
+                return;
+            }
+        },
+        None => DEFAULT_REPLAY_FILE,
+    };
+
+    // a recorded game can be watched with '--replay <file>'
+    let replay_path = match arguments.iter().position(|argument| argument == "--replay") {
+        Some(index) => match arguments.get(index + 1) {
+            Some(path) => Some(path.as_str()),
+            None => {
+                println!(
+                    "\n{} Please put a path to the replay file after '--replay'.\n",
+                    error_label()
+                );
+                return;
+            }
+        },
+        None => None,
+    };
+
+    // bots can be given a different time to think with '--bot-time-limit <ms>'
+    let bot_time_limit = match arguments
+        .iter()
+        .position(|argument| argument == "--bot-time-limit")
+    {
+        Some(index) => {
+            match arguments.get(index + 1).map(|limit| limit.parse::<u64>()) {
+                Some(Ok(limit)) if limit > 0 => Some(limit),
+                _ => {
+                    println!("\n{} Please put a positive number of milliseconds after '--bot-time-limit'.\n", error_label());
+                    return;
+                }
+            }
+        }
+        None => None,
+    };
+
+    // human players can be given a time limit for their turns with '--turn-time-limit <seconds>'
+    let turn_time_limit = match arguments
+        .iter()
+        .position(|argument| argument == "--turn-time-limit")
+    {
+        Some(index) => match arguments.get(index + 1).map(|limit| limit.parse::<u64>()) {
+            Some(Ok(limit)) if limit > 0 => Some(limit),
+            _ => {
+                println!(
+                    "\n{} Please put a positive number of seconds after '--turn-time-limit'.\n",
+                    error_label()
+                );
+                return;
+            }
+        },
+        None => None,
+    };
+
+    // human players whose input ends (f.e. a piped script runs out) are saved with the game,
+    // or handed over to bots with '--on-input-end bot'
+    let on_input_end = match arguments
+        .iter()
+        .position(|argument| argument == "--on-input-end")
+    {
+        Some(index) => match arguments.get(index + 1).map(String::as_str) {
+            Some("save") => Some(InputEnd::Save),
+            Some("bot") => Some(InputEnd::Bot),
+            _ => {
+                println!(
+                    "\n{} Please put 'save' or 'bot' after '--on-input-end'.\n",
+                    error_label()
+                );
+                return;
+            }
+        },
+        None => None,
+    };
+
+    // a campaign of generated skirmishes is played with '--campaign [seed]'
+    let campaign_seed = match arguments
+        .iter()
+        .position(|argument| argument == "--campaign")
+    {
+        Some(index) => match arguments.get(index + 1) {
+            Some(seed) if !seed.starts_with("--") => match seed.parse::<u64>() {
+                Ok(seed) => Some(seed),
+                Err(_) => {
+                    println!(
+                        "\n{} Please put a whole number as the seed after '--campaign'.\n",
+                        error_label()
+                    );
+                    return;
+                }
+            },
+            // without a seed, every campaign leads somewhere else
+            _ => Some(time_seed()),
+        },
+        None => None,
+    };
+
+    // the randomness of the game follows a seed, '--seed <number>' picks it
+    let seed = match arguments.iter().position(|argument| argument == "--seed") {
+        Some(index) => match arguments.get(index + 1).map(|seed| seed.parse::<u64>()) {
+            Some(Ok(seed)) => Some(seed),
+            _ => {
+                println!(
+                    "\n{} Please put a whole number after '--seed'.\n",
+                    error_label()
+                );
+                return;
+            }
+        },
+        None => None,
+    };
+
+    // the progression of campaigns is kept in a profile, '--profile <file>' picks another one
+    let profile_path = match arguments
+        .iter()
+        .position(|argument| argument == "--profile")
+    {
+        Some(index) => match arguments.get(index + 1) {
+            Some(path) => path.as_str(),
+            None => {
+                println!(
+                    "\n{} Please put a path to the profile file after '--profile'.\n",
+                    error_label()
+                );
+                return;
+            }
+        },
+        None => DEFAULT_PROFILE_FILE,
+    };
+
+    // the game can be played over HTTP with '--serve <address>'
+    let serve_address = match arguments.iter().position(|argument| argument == "--serve") {
+        Some(index) => match arguments.get(index + 1) {
+            Some(address) => Some(address.as_str()),
+            None => {
+                println!("\n{} Please put an address to serve the game on after '--serve', f.e. '127.0.0.1:8080'.\n", error_label());
+                return;
+            }
+        },
+        None => None,
+    };
+
+    // served games can be protected with '--password <password>' for players
+    // and '--watch-password <password>' for observers
+    let mut access = Access::default();
+    for (flag, password) in [
+        ("--password", &mut access.player_password),
+        ("--watch-password", &mut access.observer_password),
+    ] {
+        if let Some(index) = arguments.iter().position(|argument| argument == flag) {
+            match arguments.get(index + 1) {
+                Some(value) if !value.is_empty() => *password = Some(value.clone()),
+                _ => {
+                    println!(
+                        "\n{} Please put a password after '{}'.\n",
+                        error_label(),
+                        flag
+                    );
+                    return;
+                }
+            }
+        }
+    }
+
+    // balance values can be tuned in a configuration file, '--config <file>' picks another one
+    let (config_path, config_required) =
+        match arguments.iter().position(|argument| argument == "--config") {
+            Some(index) => match arguments.get(index + 1) {
+                Some(path) => (path.as_str(), true),
+                None => {
+                    println!(
+                        "\n{} Please put a path to the configuration file after '--config'.\n",
+                        error_label()
+                    );
+                    return;
+                }
+            },
+            None => (DEFAULT_CONFIG_FILE, false),
+        };
+    let loaded_rules = match load_config(config_path, config_required) {
+        Ok(loaded_rules) => loaded_rules,
+        Err(error) => {
+            println!("\n{} {}\n", error_label(), error);
+            return;
+        }
+    };
+
     // print greeting
-    print_greeting();
+    print_greeting(pacing);
+
+    if let Some(rules) = loaded_rules {
+        set_rules(rules);
+        print_active_rules(config_path, &describe_rules(&rules), pacing);
+    }
+
+    // the replay brings its own rules and players, nothing is set up
+    if let Some(path) = replay_path {
+        let result = load_replay(path).and_then(|(replay, warning)| {
+            if let Some(warning) = warning {
+                println!("\nWARNING: {}\n", warning);
+            }
+            play_replay(replay, pacing)
+        });
 
-    // create a game plan
-    let mut game_plan = generate_game_plan(1, 1);
+        if let Err(error) = result {
+            println!("\n{} {}\n", error_label(), error);
+        }
+        return;
+    }
+
+    // the campaign sets up its own skirmishes
+    if let Some(seed) = campaign_seed {
+        play_campaign(
+            seed,
+            profile_path,
+            csv_path,
+            record_path,
+            telemetry_path,
+            export_path,
+            on_input_end.unwrap_or_default(),
+            audit,
+            pacing,
+        );
+        return;
+    }
+
+    // a saved game can be continued with '--load <file>'
+    let saved_game = match arguments.iter().position(|argument| argument == "--load") {
+        Some(index) => match arguments.get(index + 1).map(|path| load_game(path)) {
+            Some(Ok((saved_game, warning))) => {
+                if let Some(warning) = warning {
+                    println!("\nWARNING: {}\n", warning);
+                }
+                Some(saved_game)
+            }
+            Some(Err(error)) => {
+                println!("\n{} {}\n", error_label(), error);
+                return;
+            }
+            None => {
+                println!(
+                    "\n{} Please put a path to the saved game after '--load'.\n",
+                    error_label()
+                );
+                return;
+            }
+        },
+        None => None,
+    };
+
+    let (mut game_plan, mut players, mut settings, mut first_round, mut first_player, mut rng) =
+        match saved_game {
+            Some(saved_game) => {
+                println!(
+                    "\nThe saved game continues in round {} with {}'s turn.\n",
+                    saved_game.round, saved_game.players[saved_game.next_player].nick
+                );
+                (
+                    saved_game.game_plan,
+                    saved_game.players,
+                    saved_game.settings,
+                    saved_game.round,
+                    saved_game.next_player,
+                    saved_game.rng,
+                )
+            }
+            None => {
+                // create a game plan of desired dimensions
+                let (width, height) = get_plan_dimensions();
+                let game_plan = generate_game_plan(width, height, pacing);
 
-    // create a specified number of players
-    // also could be implemented for more than two players,
-    // this is a setup for implementing it later
-    // if I choose to do so
-    let mut players: Vec<Player> = create_players(DEFAULT_NUM_PLAYERS);
+                // create a specified number of players
+                // also could be implemented for more than two players,
+                // this is a setup for implementing it later
+                // if I choose to do so
+                let players: Vec<Player> = create_players(DEFAULT_NUM_PLAYERS, pacing);
 
-    // obtain number of rounds to play
-    let rounds: usize = get_number_of_rounds();
-    let number_of_players = players.len();
+                // obtain number of rounds to play
+                let rounds: usize = get_number_of_rounds();
+
+                // obtain the round in which the exploration phase ends
+                let settings = GameSettings {
+                    rounds,
+                    conquest_round: get_conquest_round(rounds),
+                    fog_of_war: get_fog_of_war(),
+                    victory: get_victory_condition(),
+                    privacy_screen: get_privacy_screen(&players),
+                    bot_time_limit: DEFAULT_BOT_TIME_LIMIT,
+                    turn_time_limit: None,
+                    on_input_end: InputEnd::default(),
+                };
+
+                (game_plan, players, settings, 1, 0, GameRng::from_time())
+            }
+        };
+
+    // a time limit given on the command line overrides the one of a loaded game
+    if let Some(limit) = bot_time_limit {
+        settings.bot_time_limit = limit;
+    }
+    if turn_time_limit.is_some() {
+        settings.turn_time_limit = turn_time_limit;
+    }
+    if let Some(on_input_end) = on_input_end {
+        settings.on_input_end = on_input_end;
+    }
+
+    // so does the seed, the loaded game continues with the draws of the new one
+    if let Some(seed) = seed {
+        rng = GameRng::new(seed);
+    }
+
+    // the engine plays the game, its players send their actions over HTTP
+    if let Some(address) = serve_address {
+        let engine = GameEngine::from_saved(SavedGame {
+            players,
+            game_plan,
+            settings,
+            round: first_round,
+            next_player: first_player,
+            rng,
+        });
+
+        if let Err(error) = serve(address, engine, access) {
+            println!("\n{} {}\n", error_label(), error);
+        }
+        return;
+    }
+
+    loop {
+        play_game(
+            &mut game_plan,
+            &mut players,
+            &settings,
+            &mut rng,
+            first_round,
+            first_player,
+            csv_path,
+            record_path,
+            telemetry_path,
+            export_path,
+            audit,
+            pacing,
+        );
+
+        // the game saved at the end of the input is continued later
+        if saved_at_input_end(&settings) {
+            break;
+        }
+
+        // a rematch skips the setup, reusing the players, battlefield size and settings
+        players = match offer_rematch(&players) {
+            Some(players) => players,
+            None => break,
+        };
+        game_plan = generate_game_plan(game_plan.width(), game_plan.height(), pacing);
+        first_round = 1;
+        first_player = 0;
+        // the rematch gets a seed of its own, so it can be played again on its own too
+        rng = GameRng::new(rng.next_u64());
+    }
+}
+
+/// Play a campaign of generated skirmishes until the player loses or stops
+///
+/// Params
+/// ---
+/// - seed: seed the skirmishes are generated from
+/// - profile_path: file keeping the progression of the campaigns
+/// - csv_path: file to export the statistics of every round to, if any
+/// - record_path: file to record every skirmish into
+/// - telemetry_path: file to add the balance metrics of the skirmishes to, if the player opted in
+/// - on_input_end: what happens to the player once the input ends
+/// - audit: whether to print a checksum of the state after every action
+/// - pacing: how long the game pauses between its notifications
+#[allow(clippy::too_many_arguments)]
+fn play_campaign(
+    seed: u64,
+    profile_path: &str,
+    csv_path: Option<&str>,
+    record_path: &str,
+    telemetry_path: Option<&str>,
+    export_path: Option<&str>,
+    on_input_end: InputEnd,
+    audit: bool,
+    pacing: Pacing,
+) {
+    let mut profile = match Profile::load(profile_path) {
+        Ok(profile) => profile,
+        Err(error) => {
+            println!("\n{} {}\n", error_label(), error);
+            return;
+        }
+    };
+
+    let nick = get_campaign_nick();
+    print_campaign_start(
+        seed,
+        &nick,
+        profile.record(seed, &nick).map(|record| record.best_stage),
+    );
+    profile.start_campaign(seed, &nick);
+
+    // units which survived the previous skirmish
+    let mut core = Vec::new();
+    let mut stage = 1;
+
+    loop {
+        let mut skirmish = Skirmish::generate(seed, stage);
+        skirmish.settings.on_input_end = on_input_end;
+        print_skirmish(stage, &skirmish.describe(), pacing);
+
+        let mut game_plan = generate_game_plan(skirmish.width, skirmish.height, pacing);
+        let mut players = skirmish.players(&nick, &core);
+
+        let winner = play_game(
+            &mut game_plan,
+            &mut players,
+            &skirmish.settings,
+            &mut GameRng::new(skirmish.seed),
+            1,
+            0,
+            csv_path,
+            record_path,
+            telemetry_path,
+            export_path,
+            audit,
+            pacing,
+        );
+
+        if winner.as_deref() != Some(nick.as_str()) {
+            break;
+        }
+
+        core = core_units(&players[0], &game_plan);
+        let new_best = profile.record_stage(seed, &nick, stage);
+        print_skirmish_won(stage, &core, new_best);
+
+        // the progress is kept even if the campaign is not continued
+        if let Err(error) = profile.save(profile_path) {
+            println!("\n{} {}\n", error_label(), error);
+        }
+
+        stage += 1;
+        if !offer_next_skirmish(stage) {
+            break;
+        }
+    }
+
+    print_campaign_over(
+        stage - 1,
+        profile
+            .record(seed, &nick)
+            .map_or(0, |record| record.best_stage),
+    );
+    match profile.save(profile_path) {
+        Ok(notification) => println!("\n{}\n", notification),
+        Err(error) => println!("\n{} {}\n", error_label(), error),
+    }
+}
+
+/// Play a game from a certain turn until its end, then evaluate it
+///
+/// Params
+/// ---
+/// - game_plan: battlefield of the game
+/// - players: players of the game
+/// - settings: settings of the game
+/// - rng: generator of the game
+/// - first_round: round in which the game continues
+/// - first_player: index of the player whose turn comes first
+/// - csv_path: file to export the statistics of every round to, if any
+/// - record_path: file to record the game into
+/// - telemetry_path: file to add the balance metrics of the game to, if the player opted in
+/// - audit: whether to print a checksum of the state after every action
+/// - pacing: how long the game pauses between its notifications
+///
+/// Returns
+/// ---
+/// - Some(nick): the player who fulfilled the victory condition, or leads the most fields alone
+/// - None: if the game ended in a tie
+#[allow(clippy::too_many_arguments)]
+fn play_game(
+    game_plan: &mut GamePlan,
+    players: &mut [Player],
+    settings: &GameSettings,
+    rng: &mut GameRng,
+    first_round: usize,
+    first_player: usize,
+    csv_path: Option<&str>,
+    record_path: &str,
+    telemetry_path: Option<&str>,
+    export_path: Option<&str>,
+    audit: bool,
+    pacing: Pacing,
+) -> Option<String> {
+    let nicks: Vec<String> = players.iter().map(|player| player.nick.clone()).collect();
+    register_players(&nicks);
+
+    // the engine plays the game, the players only choose their actions
+    let mut engine = GameEngine::from_saved(SavedGame {
+        players: players.to_vec(),
+        game_plan: game_plan.clone(),
+        settings: settings.clone(),
+        round: first_round,
+        next_player: first_player,
+        rng: rng.clone(),
+    });
+
+    // the game is recorded from the turn it continues in
+    let recorder = ReplayRecorder::new(record_path, engine.save_point());
+
+    // observers commenting on the game, keeping the inboxes of the players, recording it
+    // and logging the actions for the 'history' command
+    let mut observers: Vec<Box<dyn Observer>> = vec![
+        Box::new(Commentator::new()),
+        Box::new(Inbox::new()),
+        Box::new(recorder),
+        Box::new(HistoryRecorder::new(first_round)),
+    ];
+    if audit {
+        observers.push(Box::new(Auditor::new()));
+    }
+
+    // how long players take to decide
+    let mut decision_timer = DecisionTimer::new();
+
+    // state of the players at the end of every round
+    let mut stats = GameStats::new();
+
+    // players are only asked once whether to end a decided game early
+    let mut early_finish_declined = false;
+
+    // the game can end before its last round
+    let mut rounds_played = first_round.saturating_sub(1);
+
+    // player who has fulfilled the victory condition before the last round
+    let mut victor: Option<String> = None;
 
     // print successful start of the game
-    print_game_start();
+    print_game_start(pacing);
+    print_seed(rng.seed());
 
     // play desired number of rounds
-    for current_round in 1..rounds + 1 {
+    for current_round in first_round..settings.rounds + 1 {
         let mut continue_game = true;
 
-        // every player gets to play each round
-        for (player_number, player) in players.iter_mut().enumerate() {
+        broadcast(
+            &mut observers,
+            GameEvent::RoundStarted {
+                round: current_round,
+                rounds_total: settings.rounds,
+            },
+        );
+
+        // announce the end of the exploration phase
+        if current_round == settings.conquest_round && current_round > 1 {
+            print_conquest_phase(pacing);
+        }
+
+        // every player gets to play each round, a loaded game continues
+        // with the player whose turn it was, the engine passes the turns
+        while engine.round() == current_round && !engine.is_over() {
             // if a player decides to quit, this gets set to false
-            let player_exit = play_round(player, &mut game_plan, current_round);
+            let player_exit = play_round(&mut engine, &mut observers, &mut decision_timer, pacing);
+
+            // the game has been saved at the end of the input, it is not evaluated
+            if saved_at_input_end(settings) {
+                return None;
+            }
 
             // check whether to play another round
             continue_game &= player_exit;
 
             // next player announcement only appears if another round is to be played
-            if player_number != number_of_players - 1 {
+            if engine.round() == current_round {
                 println!("Next player will begin shortly.\n\n");
             }
 
-            game_round_sleep();
+            pacing.round_pause();
+        }
+
+        // armies met on the fields have fought at the end of the round, the victors took spoils,
+        // and overextended empires have lost their weakest fields
+        print_battle_log(engine.battles(), current_round, pacing);
+        print_revolts(engine.revolts(), current_round, pacing);
+
+        stats.record_round(current_round, engine.players(), engine.game_plan());
+        rounds_played = current_round;
+
+        // the game ends as soon as somebody fulfils the victory condition
+        if let Some(winner) = engine.victor() {
+            print_victory(winner, &settings.victory);
+            victor = Some(winner.to_string());
+            break;
         }
 
         // after the round is over, if someone requested for the end of the game, it ends
         if !continue_game {
             break;
         }
+
+        // the game can end early when its outcome cannot change anymore,
+        // other victory conditions can still overturn the fields
+        if !early_finish_declined
+            && settings.victory == VictoryCondition::MostFields
+            && current_round < settings.rounds
+        {
+            match offer_early_finish(
+                engine.players(),
+                engine.game_plan(),
+                settings.rounds - current_round,
+            ) {
+                Some(true) => break,
+                Some(false) => early_finish_declined = true,
+                None => {}
+            }
+        }
+    }
+
+    // the rest of the program goes on with the state the game ended in
+    let end = engine.save_point();
+    players.clone_from_slice(&end.players);
+    *game_plan = end.game_plan;
+    *rng = end.rng;
+
+    // evaluate the game, the fields decide when nobody has fulfilled the victory condition
+    if victor.is_none() {
+        if settings.victory != VictoryCondition::MostFields {
+            println!(
+                "\nNobody has won by {}, the conquered fields decide.\n",
+                settings.victory
+            );
+        }
+        evaluate_game(game_plan, players);
+    } else {
+        // the winner has been announced already, only the scoreboard is left
+        print_scoreboard(players, game_plan);
+    }
+
+    // hand out the awards for the thinking speed
+    print_decision_awards(&decision_timer.averages());
+
+    if let Some(path) = csv_path {
+        match stats.export_csv(path) {
+            Ok(notification) => println!("\n{}\n", notification),
+            Err(error) => println!("\n{} {}\n", error_label(), error),
+        }
+    }
+
+    if let Some(path) = telemetry_path {
+        let metrics = GameMetrics::collect(
+            players,
+            game_plan,
+            settings,
+            rounds_played,
+            victor.as_deref(),
+        );
+        match record_game(&metrics, path) {
+            Ok(notification) => println!("\n{}\n", notification),
+            Err(error) => println!("\n{} {}\n", error_label(), error),
+        }
+    }
+
+    let winner = victor.or_else(|| game_plan.sole_leader());
+
+    if let Some(path) = export_path {
+        let results = GameResults::collect(
+            players,
+            game_plan,
+            settings,
+            rng.seed(),
+            rounds_played,
+            winner.as_deref(),
+        );
+        match results.export(path) {
+            Ok(notification) => println!("\n{}\n", notification),
+            Err(error) => println!("\n{} {}\n", error_label(), error),
+        }
+    }
+
+    // notable feats of the game are kept in the hall of fame
+    let result = HallOfFame::load(DEFAULT_HALL_OF_FAME_FILE).and_then(|mut hall_of_fame| {
+        let feats = hall_of_fame.consider(&stats, game_plan, winner.as_deref());
+        if feats.is_empty() {
+            return Ok(());
+        }
+
+        print_hall_of_fame_entries(&feats);
+        hall_of_fame.save(DEFAULT_HALL_OF_FAME_FILE).map(|_| ())
+    });
+    if let Err(error) = result {
+        println!("\n{} {}\n", error_label(), error);
     }
 
-    // evaluate the game
-    evaluate_game(&game_plan);
+    winner
 }
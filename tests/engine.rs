@@ -0,0 +1,299 @@
+// Games driven over the engine alone, the way the terminal, the server and the replays drive it,
+// without any input or output.
+
+use wartycoon::game::ai::BotStrategy;
+use wartycoon::game::engine::GameEngine;
+use wartycoon::game::rng::GameRng;
+use wartycoon::game::settings::{GameSettings, InputEnd};
+use wartycoon::game::types::{
+    actions::Actions,
+    board::GamePlan,
+    buildings::BuildingType,
+    items::ItemType,
+    limits::{ACTION_POINTS_PER_TURN, HARVEST_ACTION_POINTS},
+    player::Player,
+    troops::{ArmyTemplate, UnitType},
+    value_types::Quantity,
+};
+use wartycoon::game::victory::VictoryCondition;
+
+const ROUNDS: usize = 10;
+
+// bots get plenty of time, so that they never fall back to harvesting on a busy machine
+const BOT_TIME_LIMIT: u64 = 60_000;
+
+fn settings(rounds: usize) -> GameSettings {
+    GameSettings {
+        rounds,
+        conquest_round: 1,
+        fog_of_war: false,
+        privacy_screen: false,
+        bot_time_limit: BOT_TIME_LIMIT,
+        turn_time_limit: None,
+        victory: VictoryCondition::MostFields,
+        on_input_end: InputEnd::default(),
+    }
+}
+
+fn bot_game(seed: u64) -> GameEngine {
+    let players = vec![
+        Player::new_bot("BOT 1", BotStrategy::Economic),
+        Player::new_bot("BOT 2", BotStrategy::Aggressive),
+    ];
+
+    GameEngine::new(
+        players,
+        GamePlan::new(2, 1),
+        settings(ROUNDS),
+        GameRng::new(seed),
+    )
+}
+
+fn human_game(rounds: usize) -> GameEngine {
+    let players = ["Alice", "Bob"]
+        .into_iter()
+        .map(|nick| {
+            let mut player = Player::new(nick);
+            player.receive_resources((100, 100));
+            player
+        })
+        .collect();
+    let mut engine = GameEngine::new(
+        players,
+        GamePlan::new(2, 1),
+        settings(rounds),
+        GameRng::new(7),
+    );
+    engine.start_turn();
+
+    engine
+}
+
+// plays the bots until the game is over, starting every turn once
+fn play_out(engine: &mut GameEngine) {
+    let mut turn = None;
+
+    while !engine.is_over() {
+        let current = (engine.round(), engine.current_player().nick.clone());
+        if turn.as_ref() != Some(&current) {
+            engine.start_turn();
+            turn = Some(current);
+        }
+
+        let action = engine.bot_action().expect("only bots play the game");
+        if engine.apply(action).is_err() {
+            engine
+                .apply(Actions::EndTurn)
+                .expect("the turn can always be ended");
+        }
+    }
+}
+
+fn state(engine: &GameEngine) -> String {
+    serde_json::to_string(&engine.save_point()).expect("the game can be serialized")
+}
+
+#[test]
+fn bots_play_a_game_to_its_end() {
+    let mut engine = bot_game(42);
+    play_out(&mut engine);
+
+    assert!(engine.is_over());
+    assert!(engine.round() == ROUNDS + 1 || engine.victor().is_some());
+    assert!(engine.apply(Actions::Harvest).is_err());
+}
+
+#[test]
+fn the_same_seed_plays_the_same_game() {
+    let mut first = bot_game(42);
+    let mut second = bot_game(42);
+    play_out(&mut first);
+    play_out(&mut second);
+
+    assert_eq!(state(&first), state(&second));
+    assert_eq!(first.winner(), second.winner());
+}
+
+#[test]
+fn the_turn_passes_once_the_action_points_run_out() {
+    let mut engine = human_game(ROUNDS);
+
+    engine
+        .apply(Actions::Harvest)
+        .expect("harvesting is allowed");
+    assert_eq!(
+        engine.action_points(),
+        ACTION_POINTS_PER_TURN - HARVEST_ACTION_POINTS
+    );
+    assert_eq!(engine.current_player().nick, "Alice");
+
+    // failed actions cost nothing
+    assert!(engine.apply(Actions::Harvest).is_err());
+    assert_eq!(engine.current_player().nick, "Alice");
+
+    engine
+        .apply(Actions::EndTurn)
+        .expect("the turn can be ended");
+    assert_eq!(engine.current_player().nick, "Bob");
+    assert_eq!(engine.action_points(), ACTION_POINTS_PER_TURN);
+}
+
+#[test]
+fn the_round_ends_after_the_last_player() {
+    let mut engine = human_game(ROUNDS);
+
+    engine
+        .apply(Actions::EndTurn)
+        .expect("the turn can be ended");
+    engine.start_turn();
+    engine
+        .apply(Actions::EndTurn)
+        .expect("the turn can be ended");
+
+    assert_eq!(engine.round(), 2);
+    assert_eq!(engine.current_player().nick, "Alice");
+}
+
+#[test]
+fn the_game_is_over_after_its_last_round() {
+    let mut engine = human_game(1);
+
+    engine
+        .apply(Actions::EndTurn)
+        .expect("the turn can be ended");
+    engine.start_turn();
+    engine
+        .apply(Actions::EndTurn)
+        .expect("the turn can be ended");
+
+    assert!(engine.is_over());
+    assert!(engine.apply(Actions::EndTurn).is_err());
+}
+
+#[test]
+fn the_last_action_is_taken_back_once_per_turn() {
+    let mut engine = human_game(ROUNDS);
+    let before = state(&engine);
+
+    assert!(engine.apply(Actions::Undo).is_err());
+
+    engine
+        .apply(Actions::Harvest)
+        .expect("harvesting is allowed");
+    assert_ne!(state(&engine), before);
+
+    engine
+        .apply(Actions::Undo)
+        .expect("the harvest can be taken back");
+    assert_eq!(state(&engine), before);
+    assert_eq!(engine.action_points(), ACTION_POINTS_PER_TURN);
+
+    engine
+        .apply(Actions::Harvest)
+        .expect("harvesting is allowed");
+    assert!(engine.apply(Actions::Undo).is_err());
+}
+
+#[test]
+fn quitting_and_saving_are_left_to_the_frontend() {
+    let mut engine = human_game(ROUNDS);
+
+    assert!(engine.apply(Actions::Quit).is_err());
+    assert!(engine
+        .apply(Actions::Save(String::from("game.json")))
+        .is_err());
+    assert_eq!(engine.action_points(), ACTION_POINTS_PER_TURN);
+}
+
+#[test]
+fn an_accepted_trade_pays_both_players() {
+    let mut engine = human_game(ROUNDS);
+    let (alice_wood, alice_gold) = engine.current_player().supplies();
+
+    engine
+        .apply(Actions::Trade {
+            to: String::from("Bob"),
+            give: (10, 0),
+            want: (0, 5),
+        })
+        .expect("the offer can be made");
+    engine
+        .apply(Actions::EndTurn)
+        .expect("the turn can be ended");
+    engine.start_turn();
+
+    let (bob_wood, bob_gold) = engine.current_player().supplies();
+    engine
+        .apply(Actions::AnswerTrade {
+            from: String::from("Alice"),
+            accept: true,
+        })
+        .expect("the offer can be accepted");
+
+    let alice = &engine.players()[0];
+    assert_eq!(alice.supplies(), (alice_wood - 10, alice_gold + 5));
+    assert_eq!(
+        engine.current_player().supplies(),
+        (bob_wood + 10, bob_gold - 5)
+    );
+    assert!(alice.offers().is_empty());
+}
+
+#[test]
+fn actions_with_non_positive_quantities_are_rejected() {
+    let mut engine = human_game(ROUNDS);
+    let before = state(&engine);
+
+    for quantity in [0, -5, -100_000] {
+        let actions = [
+            Actions::Build(BuildingType::Base, quantity),
+            Actions::Train(UnitType::Archer, quantity),
+            Actions::Recruit(UnitType::Warrior, quantity),
+            Actions::Conquer(0, 0, UnitType::Cavalry, quantity),
+            Actions::Recall(0, 0, UnitType::Archer, quantity),
+            Actions::BuyItem(ItemType::SiegeLadder, quantity),
+            Actions::TrainArmy(ArmyTemplate {
+                name: String::from("army"),
+                units: vec![(UnitType::Archer, 1), (UnitType::Warrior, quantity)],
+            }),
+        ];
+
+        for action in actions {
+            assert!(engine.apply(action).is_err());
+            assert_eq!(state(&engine), before);
+        }
+    }
+
+    let empty_army = Actions::TrainArmy(ArmyTemplate {
+        name: String::from("army"),
+        units: Vec::new(),
+    });
+    assert!(engine.apply(empty_army).is_err());
+    assert_eq!(state(&engine), before);
+    assert_eq!(engine.action_points(), ACTION_POINTS_PER_TURN);
+}
+
+#[test]
+fn actions_with_overflowing_quantities_are_rejected() {
+    let mut engine = human_game(ROUNDS);
+    let before = state(&engine);
+
+    for quantity in [Quantity::MAX, Quantity::MAX / 2, Quantity::MAX / 100] {
+        let actions = [
+            Actions::Build(BuildingType::Base, quantity),
+            Actions::Train(UnitType::Cavalry, quantity),
+            Actions::Recruit(UnitType::Warrior, quantity),
+            Actions::Conquer(0, 0, UnitType::Archer, quantity),
+            Actions::BuyItem(ItemType::FireArrow, quantity),
+            Actions::TrainArmy(ArmyTemplate {
+                name: String::from("army"),
+                units: vec![(UnitType::Archer, quantity), (UnitType::Warrior, quantity)],
+            }),
+        ];
+
+        for action in actions {
+            assert!(engine.apply(action).is_err());
+            assert_eq!(state(&engine), before);
+        }
+    }
+}